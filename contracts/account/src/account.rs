@@ -1,8 +1,9 @@
 use crate::errors::ContractError;
 use crate::events::{
     publish_account_initialized_event, publish_account_restricted_event,
-    publish_account_verified_event, publish_refund_processed_event, publish_token_added_event,
-    publish_withdrawal_to_event,
+    publish_account_verified_event, publish_destination_added_event,
+    publish_destination_removed_event, publish_min_withdrawal_set_event,
+    publish_refund_processed_event, publish_token_added_event, publish_withdrawal_to_event,
 };
 use crate::interface::MerchantAccountTrait;
 use crate::types::{AccountInfo, DataKey, TokenBalance};
@@ -25,6 +26,37 @@ fn get_tracked_tokens(env: &Env) -> Vec<Address> {
         .unwrap_or_else(|| Vec::new(env))
 }
 
+fn get_merchant_address(env: &Env) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Merchant)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::NotInitialized))
+}
+
+fn get_withdrawal_destinations(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::WithdrawalDestinations)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn get_min_withdrawal(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MinWithdrawal(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Transfers out of the account and checks the recipient received exactly
+/// `amount`, rejecting fee-on-transfer or misreporting tokens.
+fn transfer_checked(env: &Env, token_client: &token::TokenClient, to: &Address, amount: i128) {
+    let balance_before = token_client.balance(to);
+    token_client.transfer(&env.current_contract_address(), to, &amount);
+    if token_client.balance(to) != balance_before + amount {
+        panic_with_error!(env, ContractError::TransferMismatch);
+    }
+}
+
 fn is_restricted_account(env: &Env) -> bool {
     env.storage()
         .persistent()
@@ -68,10 +100,7 @@ impl MerchantAccountTrait for MerchantAccount {
         );
     }
     fn get_merchant(env: Env) -> Address {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Merchant)
-            .unwrap_or_else(|| panic_with_error!(&env, ContractError::NotInitialized))
+        get_merchant_address(&env)
     }
 
     fn add_token(env: Env, token: Address) {
@@ -98,9 +127,8 @@ impl MerchantAccountTrait for MerchantAccount {
             panic_with_error!(&env, ContractError::AccountRestricted);
         }
 
-        let contract_address = env.current_contract_address();
         let token_client = token::TokenClient::new(&env, &token);
-        token_client.transfer(&contract_address, &to, &amount);
+        transfer_checked(&env, &token_client, &to, amount);
 
         publish_refund_processed_event(&env, token, amount, to, env.ledger().timestamp());
     }
@@ -172,8 +200,71 @@ impl MerchantAccountTrait for MerchantAccount {
             panic_with_error!(&env, ContractError::InsufficientBalance);
         }
 
-        token_client.transfer(&env.current_contract_address(), &recipient, &amount);
+        // Emptying the account is always allowed so merchants can fully exit.
+        if amount < current_balance && amount < get_min_withdrawal(&env, &token) {
+            panic_with_error!(&env, ContractError::BelowMinWithdrawal);
+        }
+
+        // An empty allowlist means any destination is allowed.
+        let destinations = get_withdrawal_destinations(&env);
+        if !destinations.is_empty() && !destinations.contains(&recipient) {
+            panic_with_error!(&env, ContractError::DestinationNotAllowed);
+        }
+
+        transfer_checked(&env, &token_client, &recipient, amount);
 
         publish_withdrawal_to_event(&env, token, recipient, amount, env.ledger().timestamp());
     }
+
+    fn add_withdrawal_destination(env: Env, destination: Address) {
+        let merchant = get_merchant_address(&env);
+        merchant.require_auth();
+
+        let mut destinations = get_withdrawal_destinations(&env);
+        if destinations.contains(&destination) {
+            return;
+        }
+
+        destinations.push_back(destination.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::WithdrawalDestinations, &destinations);
+        publish_destination_added_event(&env, destination, env.ledger().timestamp());
+    }
+
+    fn remove_withdrawal_destination(env: Env, destination: Address) {
+        let merchant = get_merchant_address(&env);
+        merchant.require_auth();
+
+        let mut destinations = get_withdrawal_destinations(&env);
+        if let Some(index) = destinations.first_index_of(&destination) {
+            destinations.remove(index);
+            env.storage()
+                .persistent()
+                .set(&DataKey::WithdrawalDestinations, &destinations);
+            publish_destination_removed_event(&env, destination, env.ledger().timestamp());
+        }
+    }
+
+    fn get_withdrawal_destinations(env: Env) -> Vec<Address> {
+        get_withdrawal_destinations(&env)
+    }
+
+    fn set_min_withdrawal(env: Env, token: Address, min: i128) {
+        let manager = get_manager(&env);
+        manager.require_auth();
+
+        if min < 0 {
+            panic_with_error!(&env, ContractError::InvalidAmount);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::MinWithdrawal(token.clone()), &min);
+        publish_min_withdrawal_set_event(&env, token, min, env.ledger().timestamp());
+    }
+
+    fn get_min_withdrawal(env: Env, token: Address) -> i128 {
+        get_min_withdrawal(&env, &token)
+    }
 }
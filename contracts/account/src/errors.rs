@@ -9,4 +9,8 @@ pub enum ContractError {
     NotAuthorized = 3,
     InsufficientBalance = 4,
     AccountRestricted = 5,
+    DestinationNotAllowed = 6,
+    BelowMinWithdrawal = 7,
+    InvalidAmount = 8,
+    TransferMismatch = 9,
 }
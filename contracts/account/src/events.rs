@@ -77,6 +77,50 @@ pub fn publish_token_added_event(env: &Env, token: Address, timestamp: u64) {
     TokenAddedEvent { token, timestamp }.publish(env);
 }
 
+#[contractevent]
+pub struct DestinationAddedEvent {
+    pub destination: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_destination_added_event(env: &Env, destination: Address, timestamp: u64) {
+    DestinationAddedEvent {
+        destination,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct DestinationRemovedEvent {
+    pub destination: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_destination_removed_event(env: &Env, destination: Address, timestamp: u64) {
+    DestinationRemovedEvent {
+        destination,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct MinWithdrawalSetEvent {
+    pub token: Address,
+    pub min: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_min_withdrawal_set_event(env: &Env, token: Address, min: i128, timestamp: u64) {
+    MinWithdrawalSetEvent {
+        token,
+        min,
+        timestamp,
+    }
+    .publish(env);
+}
+
 #[contractevent]
 pub struct WithdrawalToEvent {
     pub token: Address,
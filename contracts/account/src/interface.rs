@@ -15,4 +15,9 @@ pub trait MerchantAccountTrait {
     fn restrict_account(env: Env, status: bool);
     fn is_restricted_account(env: Env) -> bool;
     fn withdraw_to(env: Env, token: Address, amount: i128, recipient: Address);
+    fn add_withdrawal_destination(env: Env, destination: Address);
+    fn remove_withdrawal_destination(env: Env, destination: Address);
+    fn get_withdrawal_destinations(env: Env) -> Vec<Address>;
+    fn set_min_withdrawal(env: Env, token: Address, min: i128);
+    fn get_min_withdrawal(env: Env, token: Address) -> i128;
 }
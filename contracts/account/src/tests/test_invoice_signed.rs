@@ -39,7 +39,7 @@ fn generate_signature(env: &Env) -> BytesN<64> {
 /// Sets up a registered merchant with a key stored
 fn setup_merchant_with_key(env: &Env, client: &ShadeClient) -> (Address, BytesN<32>) {
     let merchant = Address::generate(env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let key_bytes: [u8; 32] = [9u8; 32];
     let key = BytesN::from_array(env, &key_bytes);
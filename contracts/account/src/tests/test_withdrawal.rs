@@ -3,7 +3,7 @@
 use crate::account::MerchantAccount;
 use crate::account::MerchantAccountClient;
 use soroban_sdk::testutils::{Address as _, Events as _};
-use soroban_sdk::{token, Address, Env};
+use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env};
 
 fn setup_initialized_account(env: &Env) -> (Address, MerchantAccountClient<'_>, Address) {
     let contract_id = env.register(MerchantAccount, ());
@@ -259,3 +259,189 @@ fn test_withdraw_to_emits_event() {
     let events = env.events().all();
     assert!(!events.is_empty(), "Withdrawal event should be emitted");
 }
+
+#[test]
+fn test_withdraw_to_allowed_destination() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _merchant) = setup_initialized_account(&env);
+    let token = create_test_token(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&contract_id, &5000);
+
+    let treasury = Address::generate(&env);
+    client.add_withdrawal_destination(&treasury);
+    client.add_withdrawal_destination(&treasury);
+    assert_eq!(client.get_withdrawal_destinations().len(), 1);
+
+    client.withdraw_to(&token, &3000, &treasury);
+    assert_eq!(
+        token::TokenClient::new(&env, &token).balance(&treasury),
+        3000
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_withdraw_to_disallowed_destination_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _merchant) = setup_initialized_account(&env);
+    let token = create_test_token(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&contract_id, &5000);
+
+    client.add_withdrawal_destination(&Address::generate(&env));
+    client.withdraw_to(&token, &3000, &Address::generate(&env));
+}
+
+#[test]
+fn test_removing_last_destination_allows_any_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _merchant) = setup_initialized_account(&env);
+    let token = create_test_token(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&contract_id, &5000);
+
+    let treasury = Address::generate(&env);
+    client.add_withdrawal_destination(&treasury);
+    client.remove_withdrawal_destination(&treasury);
+    assert_eq!(client.get_withdrawal_destinations().len(), 0);
+
+    let recipient = Address::generate(&env);
+    client.withdraw_to(&token, &3000, &recipient);
+    assert_eq!(
+        token::TokenClient::new(&env, &token).balance(&recipient),
+        3000
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_add_withdrawal_destination_requires_merchant_auth() {
+    let env = Env::default();
+    let (_contract_id, client, _merchant) = setup_initialized_account(&env);
+
+    client.add_withdrawal_destination(&Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_withdraw_below_minimum_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _merchant) = setup_initialized_account(&env);
+    let token = create_test_token(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&contract_id, &5000);
+
+    client.set_min_withdrawal(&token, &1000);
+    assert_eq!(client.get_min_withdrawal(&token), 1000);
+
+    client.withdraw_to(&token, &999, &Address::generate(&env));
+}
+
+#[test]
+fn test_full_balance_withdrawal_ignores_minimum() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _merchant) = setup_initialized_account(&env);
+    let token = create_test_token(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&contract_id, &500);
+
+    client.set_min_withdrawal(&token, &1000);
+
+    let recipient = Address::generate(&env);
+    client.withdraw_to(&token, &500, &recipient);
+    assert_eq!(client.get_balance(&token), 0);
+    assert_eq!(
+        token::TokenClient::new(&env, &token).balance(&recipient),
+        500
+    );
+}
+
+#[test]
+fn test_min_withdrawal_defaults_to_zero() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _merchant) = setup_initialized_account(&env);
+    let token = create_test_token(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&contract_id, &5000);
+
+    assert_eq!(client.get_min_withdrawal(&token), 0);
+    client.withdraw_to(&token, &1, &Address::generate(&env));
+    assert_eq!(client.get_balance(&token), 4999);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_set_min_withdrawal_requires_manager_auth() {
+    let env = Env::default();
+    let (_contract_id, client, _merchant) = setup_initialized_account(&env);
+
+    client.set_min_withdrawal(&create_test_token(&env), &1000);
+}
+
+#[contracttype]
+enum LossyKey {
+    Balance(Address),
+}
+
+/// Reports a successful transfer but only delivers `amount - 1`.
+#[contract]
+pub struct LossyToken;
+
+#[contractimpl]
+impl LossyToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .persistent()
+            .set(&LossyKey::Balance(to), &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&LossyKey::Balance(id))
+            .unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let from_balance = Self::balance(env.clone(), from.clone());
+        env.storage()
+            .persistent()
+            .set(&LossyKey::Balance(from), &(from_balance - amount));
+        Self::mint(env, to, amount - 1);
+    }
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+fn test_withdraw_to_under_delivering_token_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _merchant) = setup_initialized_account(&env);
+    let token = env.register(LossyToken, ());
+    LossyTokenClient::new(&env, &token).mint(&contract_id, &5000);
+
+    client.withdraw_to(&token, &3000, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+fn test_refund_under_delivering_token_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client, _merchant) = setup_initialized_account(&env);
+    let token = env.register(LossyToken, ());
+    LossyTokenClient::new(&env, &token).mint(&contract_id, &5000);
+
+    client.refund(&token, &1000, &Address::generate(&env));
+}
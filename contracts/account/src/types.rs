@@ -8,6 +8,8 @@ pub enum DataKey {
     Restricted,
     AccountInfo,
     TrackedTokens,
+    WithdrawalDestinations,
+    MinWithdrawal(Address),
 }
 
 #[contracttype]
@@ -1,7 +1,13 @@
 use crate::components::core;
+use crate::errors::ContractError;
 use crate::events;
-use crate::types::{DataKey, Role};
-use soroban_sdk::{Address, Env};
+use crate::types::{CallerPermissions, DataKey, Role};
+use soroban_sdk::{panic_with_error, vec, Address, Env, Vec};
+
+// Identifiers for the role-gated entrypoints that `require_permission` guards.
+pub const FN_CREATE_INVOICE_SIGNED: u32 = 1;
+pub const FN_RESTRICT_MERCHANT_ACCOUNT: u32 = 2;
+pub const FN_REFUND_INVOICE: u32 = 3;
 
 pub fn grant_role(env: &Env, admin: &Address, user: &Address, role: Role) {
     core::assert_admin(env, admin);
@@ -49,8 +55,106 @@ pub fn has_role(env: &Env, user: &Address, role: Role) -> bool {
 pub fn assert_has_role(env: &Env, user: &Address, role: Role) {
     user.require_auth();
     if !has_role(env, user, role) {
-        use crate::errors::ContractError;
-        use soroban_sdk::panic_with_error;
         panic_with_error!(env, ContractError::NotAuthorized);
     }
 }
+
+pub fn set_role_permission(
+    env: &Env,
+    admin: &Address,
+    role: Role,
+    function_id: u32,
+    allowed: bool,
+) {
+    core::assert_admin(env, admin);
+
+    env.storage().persistent().set(
+        &DataKey::RolePermission(role.clone(), function_id),
+        &allowed,
+    );
+
+    events::publish_role_permission_set_event(
+        env,
+        admin.clone(),
+        role,
+        function_id,
+        allowed,
+        env.ledger().timestamp(),
+    );
+}
+
+/// Returns whether `role` may call `function_id`, falling back to the
+/// built-in defaults when no override has been stored.
+pub fn get_role_permission(env: &Env, role: Role, function_id: u32) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RolePermission(role.clone(), function_id))
+        .unwrap_or_else(|| default_role_permission(&role, function_id))
+}
+
+/// Requires `caller` to be the admin or to hold a role allowed to call `function_id`.
+pub fn require_permission(env: &Env, caller: &Address, function_id: u32) {
+    caller.require_auth();
+    if !has_permission(env, caller, function_id) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+}
+
+/// Whether `caller` is the admin or holds a role allowed to call `function_id`.
+/// Does not check auth.
+pub fn has_permission(env: &Env, caller: &Address, function_id: u32) -> bool {
+    if *caller == core::get_admin(env) {
+        return true;
+    }
+
+    all_roles(env).iter().any(|role| {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Role(caller.clone(), role.clone()))
+            && get_role_permission(env, role, function_id)
+    })
+}
+
+fn default_role_permission(role: &Role, function_id: u32) -> bool {
+    match function_id {
+        FN_CREATE_INVOICE_SIGNED => *role == Role::Manager,
+        FN_RESTRICT_MERCHANT_ACCOUNT | FN_REFUND_INVOICE => {
+            *role == Role::Admin || *role == Role::Manager
+        }
+        _ => false,
+    }
+}
+
+fn all_roles(env: &Env) -> Vec<Role> {
+    vec![
+        env,
+        Role::Admin,
+        Role::Manager,
+        Role::Operator,
+        Role::Onboarder,
+    ]
+}
+
+/// Returns whether `caller` is the admin along with the roles explicitly
+/// granted to it in storage.
+pub fn get_caller_permissions(env: &Env, caller: &Address) -> CallerPermissions {
+    let is_admin = *caller == core::get_admin(env);
+    let roles = get_roles(env, caller);
+
+    CallerPermissions { is_admin, roles }
+}
+
+/// Returns every role explicitly granted to `user` in storage.
+pub fn get_roles(env: &Env, user: &Address) -> Vec<Role> {
+    let mut roles: Vec<Role> = Vec::new(env);
+    for role in all_roles(env).iter() {
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Role(user.clone(), role.clone()))
+        {
+            roles.push_back(role);
+        }
+    }
+    roles
+}
@@ -1,12 +1,17 @@
-use crate::components::{core, reentrancy};
-use crate::errors::ContractError;
+use crate::components::{core, escrow, invoice, merchant, pausable, reentrancy};
+use crate::errors::{ContractError, ExtendedError};
 use crate::events;
-use crate::types::{DataKey, PendingFee};
-use soroban_sdk::{panic_with_error, token, Address, Env, Vec};
+use crate::types::{ConfigKey, DataKey, DustPolicy, PendingFee};
+use soroban_sdk::{panic_with_error, token, Address, Env, String, Vec};
 
 pub const FEE_UPDATE_DELAY: u64 = 172_800; // 48 hours in seconds
-
-// TODO: create the functionality for withdrawing revenue by admin.
+pub const MAX_FEE_TIERS: u32 = 10;
+pub const MAX_FEE_EXEMPTION_BATCH: u32 = 50;
+pub const DEFAULT_MAX_DESCRIPTION_LEN: u32 = 256;
+pub const DEFAULT_ADMIN_INACTIVITY_PERIOD: u64 = 2_592_000; // 30 days in seconds
+pub const DEFAULT_MAX_LATE_FEE_BPS: u32 = 1_000; // 10% of the invoice amount
+pub const RATE_SCALE: i128 = 10_000_000; // conversion rates carry 7 decimals
+pub const DEFAULT_QUOTE_VALIDITY: u64 = 900; // 15 minutes in seconds
 
 pub fn add_accepted_token(env: &Env, admin: &Address, token: &Address) {
     reentrancy::enter(env);
@@ -49,6 +54,8 @@ pub fn add_accepted_tokens(env: &Env, admin: &Address, tokens: &Vec<Address>) {
     reentrancy::exit(env);
 }
 
+/// Delists `token` and drops its fee settings, so re-adding it later starts
+/// from no fee. The fee ceiling and recipient are kept.
 pub fn remove_accepted_token(env: &Env, admin: &Address, token: &Address) {
     reentrancy::enter(env);
     core::assert_admin(env, admin);
@@ -69,11 +76,20 @@ pub fn remove_accepted_token(env: &Env, admin: &Address, token: &Address) {
         env.storage()
             .persistent()
             .set(&DataKey::AcceptedTokens, &updated_tokens);
+        clear_token_fees(env, token);
         events::publish_token_removed_event(env, token.clone(), env.ledger().timestamp());
     }
     reentrancy::exit(env);
 }
 
+fn clear_token_fees(env: &Env, token: &Address) {
+    let storage = env.storage().persistent();
+    storage.remove(&DataKey::TokenFee(token.clone()));
+    storage.remove(&DataKey::FlatFee(token.clone()));
+    storage.remove(&DataKey::PendingTokenFee(token.clone()));
+    storage.remove(&ConfigKey::FeeTiers(token.clone()));
+}
+
 pub fn is_accepted_token(env: &Env, token: &Address) -> bool {
     contains_token(&get_accepted_tokens(env), token)
 }
@@ -109,6 +125,8 @@ pub fn set_fee(env: &Env, admin: &Address, token: &Address, fee: i128) {
     if !is_accepted_token(env, token) {
         panic_with_error!(env, ContractError::TokenNotAccepted);
     }
+    assert_valid_fee_bps(env, fee);
+    assert_within_fee_ceiling(env, token, fee);
 
     env.storage()
         .persistent()
@@ -124,6 +142,11 @@ pub fn set_fee(env: &Env, admin: &Address, token: &Address, fee: i128) {
     reentrancy::exit(env);
 }
 
+/// `set_fee` for callers that hold the rate as a `u32` basis-point value.
+pub fn set_fee_bps(env: &Env, admin: &Address, token: &Address, bps: u32) {
+    set_fee(env, admin, token, bps as i128);
+}
+
 pub fn get_fee(env: &Env, token: &Address) -> i128 {
     env.storage()
         .persistent()
@@ -131,12 +154,461 @@ pub fn get_fee(env: &Env, token: &Address) -> i128 {
         .unwrap_or(0)
 }
 
+/// Fee on `amount` rounded down, whatever the dust policy. Settlement goes
+/// through `calculate_tiered_fee`, which applies it.
 pub fn calculate_fee(env: &Env, token: &Address, amount: i128) -> i128 {
-    let fee_bps: i128 = get_fee(env, token);
-    if fee_bps == 0 {
+    tiered_fee(env, token, amount, amount, false)
+}
+
+/// Fee on `amount` at the rate for an invoice of `invoice_amount`, so partial
+/// and split payments use the tier of the invoice as a whole.
+pub fn calculate_tiered_fee(
+    env: &Env,
+    token: &Address,
+    invoice_amount: i128,
+    amount: i128,
+) -> i128 {
+    tiered_fee(env, token, invoice_amount, amount, fee_pool_keeps_dust(env))
+}
+
+fn tiered_fee(
+    env: &Env,
+    token: &Address,
+    invoice_amount: i128,
+    amount: i128,
+    round_up: bool,
+) -> i128 {
+    let fee_bps: i128 = get_fee_bps_for_amount(env, token, invoice_amount);
+    let flat_fee = get_flat_fee(env, token);
+    if fee_bps == 0 && flat_fee == 0 {
+        return 0;
+    }
+    // never charge more than the payment itself
+    (bps_of(amount, fee_bps, round_up) + flat_fee).min(amount)
+}
+
+pub fn set_dust_policy(env: &Env, admin: &Address, policy: DustPolicy) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::DustPolicy, &policy);
+
+    events::publish_dust_policy_set_event(env, admin.clone(), policy, env.ledger().timestamp());
+}
+
+pub fn get_dust_policy(env: &Env) -> DustPolicy {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::DustPolicy)
+        .unwrap_or(DustPolicy::ToFeePool)
+}
+
+/// Stroops of rounding dust left to `payer` on a payment of `amount`: one for
+/// each fee rate that does not divide it evenly, under `DustPolicy::ToPayer`.
+pub fn calculate_payer_dust(
+    env: &Env,
+    token: &Address,
+    payer: &Address,
+    invoice_amount: i128,
+    amount: i128,
+    converted_from: Option<&Address>,
+) -> i128 {
+    if is_fee_exempt(env, payer) {
+        return 0;
+    }
+    calculate_dust(env, token, invoice_amount, amount, converted_from)
+}
+
+/// `calculate_payer_dust` for a payer without a fee exemption.
+pub fn calculate_dust(
+    env: &Env,
+    token: &Address,
+    invoice_amount: i128,
+    amount: i128,
+    converted_from: Option<&Address>,
+) -> i128 {
+    if get_dust_policy(env) != DustPolicy::ToPayer {
+        return 0;
+    }
+    let mut dust = bps_dust(amount, get_fee_bps_for_amount(env, token, invoice_amount));
+    if let Some(original_token) = converted_from {
+        if original_token != token {
+            dust += bps_dust(amount, get_conversion_fee_bps(env) as i128);
+        }
+    }
+    dust
+}
+
+fn fee_pool_keeps_dust(env: &Env) -> bool {
+    get_dust_policy(env) == DustPolicy::ToFeePool
+}
+
+// `amount * bps / 10_000`, optionally rounded up.
+fn bps_of(amount: i128, bps: i128, round_up: bool) -> i128 {
+    let share = (amount * bps) / 10_000i128;
+    if round_up {
+        share + bps_dust(amount, bps)
+    } else {
+        share
+    }
+}
+
+fn bps_dust(amount: i128, bps: i128) -> i128 {
+    if (amount * bps) % 10_000i128 == 0 {
+        0
+    } else {
+        1
+    }
+}
+
+pub fn get_flat_fee(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FlatFee(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_fee_recipient(env: &Env, token: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeeRecipient(token.clone()))
+}
+
+/// Address that receives collected fees for `token`: the contract's own fee
+/// pool while fee accrual is on, else its fee recipient, else the treasury.
+pub fn get_fee_destination(env: &Env, token: &Address) -> Address {
+    if is_fee_accrual_enabled(env) {
+        return env.current_contract_address();
+    }
+    get_fee_recipient(env, token).unwrap_or_else(|| get_treasury(env))
+}
+
+/// While on, every payment's fee is kept in the contract and added to the fee
+/// pool for `withdraw_fees` to sweep in bulk.
+pub fn set_fee_accrual(env: &Env, admin: &Address, enabled: bool) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::FeeAccrual, &enabled);
+
+    events::publish_fee_accrual_set_event(env, admin.clone(), enabled, env.ledger().timestamp());
+}
+
+pub fn is_fee_accrual_enabled(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::FeeAccrual)
+        .unwrap_or(false)
+}
+
+pub fn set_treasury(env: &Env, admin: &Address, treasury: &Address) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::Treasury, treasury);
+
+    events::publish_treasury_set_event(
+        env,
+        admin.clone(),
+        treasury.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+/// The configured treasury, or the admin until one is set.
+pub fn get_treasury(env: &Env) -> Address {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::Treasury)
+        .unwrap_or_else(|| core::get_admin(env))
+}
+
+/// Replaces the flat fee, bps fee and fee recipient for `token` in one step.
+/// Every field is validated before anything is written.
+pub fn update_fee_config(
+    env: &Env,
+    admin: &Address,
+    token: &Address,
+    fee: i128,
+    bps: u32,
+    recipient: &Address,
+) {
+    reentrancy::enter(env);
+    core::assert_admin(env, admin);
+
+    if !is_accepted_token(env, token) {
+        panic_with_error!(env, ContractError::TokenNotAccepted);
+    }
+    if fee < 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    assert_valid_fee_bps(env, bps as i128);
+    assert_within_fee_ceiling(env, token, bps as i128);
+
+    let old_fee = get_flat_fee(env, token);
+    let old_bps = get_fee(env, token) as u32;
+    let old_recipient = get_fee_recipient(env, token);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::FlatFee(token.clone()), &fee);
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenFee(token.clone()), &(bps as i128));
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeeRecipient(token.clone()), recipient);
+
+    events::publish_fee_config_updated_event(
+        env,
+        admin.clone(),
+        token.clone(),
+        old_fee,
+        fee,
+        old_bps,
+        bps,
+        old_recipient,
+        recipient.clone(),
+        env.ledger().timestamp(),
+    );
+    reentrancy::exit(env);
+}
+
+/// Fee owed on a payment made by `payer`; exempt payers owe nothing.
+pub fn calculate_payer_fee(
+    env: &Env,
+    token: &Address,
+    payer: &Address,
+    invoice_amount: i128,
+    amount: i128,
+) -> i128 {
+    if is_fee_exempt(env, payer) {
         return 0;
     }
-    (amount * fee_bps) / 10_000i128
+    calculate_tiered_fee(env, token, invoice_amount, amount)
+}
+
+pub fn set_conversion_fee_bps(env: &Env, admin: &Address, bps: u32) {
+    core::assert_admin(env, admin);
+    assert_valid_fee_bps(env, bps as i128);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::ConversionFeeBps, &bps);
+
+    events::publish_conversion_fee_set_event(env, admin.clone(), bps, env.ledger().timestamp());
+}
+
+pub fn get_conversion_fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ConversionFeeBps)
+        .unwrap_or(0)
+}
+
+/// Sets how much of `to` one unit of `from` buys, scaled by `RATE_SCALE`. New
+/// invoices in `from` snapshot a quote in `to` at this rate, and expired
+/// snapshots are re-quoted from it. A rate of 0 removes it.
+pub fn set_conversion_rate(env: &Env, admin: &Address, from: &Address, to: &Address, rate: i128) {
+    core::assert_admin(env, admin);
+
+    if rate < 0 || from == to {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    if !is_accepted_token(env, from) || !is_accepted_token(env, to) {
+        panic_with_error!(env, ContractError::TokenNotAccepted);
+    }
+
+    let key = ConfigKey::ConversionRate(from.clone(), to.clone());
+    if rate == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &rate);
+    }
+
+    events::publish_conversion_rate_set_event(
+        env,
+        admin.clone(),
+        from.clone(),
+        to.clone(),
+        rate,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_conversion_rate(env: &Env, from: &Address, to: &Address) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::ConversionRate(from.clone(), to.clone()))
+}
+
+/// `amount` of `from` expressed in `to` at the current rate, rounded up so the
+/// merchant is never short-changed. `None` when no rate is set.
+pub fn convert_amount(env: &Env, from: &Address, to: &Address, amount: i128) -> Option<i128> {
+    let rate = get_conversion_rate(env, from, to)?;
+    Some((amount * rate + RATE_SCALE - 1) / RATE_SCALE)
+}
+
+/// Sets how long quotes snapshotted at invoice creation are honored.
+pub fn set_quote_validity(env: &Env, admin: &Address, secs: u64) {
+    core::assert_admin(env, admin);
+
+    if secs == 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::QuoteValidity, &secs);
+
+    events::publish_quote_validity_set_event(env, admin.clone(), secs, env.ledger().timestamp());
+}
+
+pub fn get_quote_validity(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::QuoteValidity)
+        .unwrap_or(DEFAULT_QUOTE_VALIDITY)
+}
+
+/// Declares how much of each transfer `token` itself keeps, so settlement
+/// accepts the shortfall instead of rejecting the token outright.
+pub fn set_token_transfer_fee_bps(env: &Env, admin: &Address, token: &Address, bps: u32) {
+    core::assert_admin(env, admin);
+    assert_valid_fee_bps(env, bps as i128);
+
+    let key = ConfigKey::TransferFeeBps(token.clone());
+    if bps == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &bps);
+    }
+
+    events::publish_transfer_fee_set_event(
+        env,
+        admin.clone(),
+        token.clone(),
+        bps,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_token_transfer_fee_bps(env: &Env, token: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::TransferFeeBps(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Invoices of at least `amount` in `token` raise a `large_payment` event when
+/// they settle. An amount of 0 turns the signal off.
+pub fn set_large_payment_threshold(env: &Env, admin: &Address, token: &Address, amount: i128) {
+    core::assert_admin(env, admin);
+
+    if amount < 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let key = ConfigKey::LargePaymentThreshold(token.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &amount);
+    }
+
+    events::publish_large_payment_threshold_set_event(
+        env,
+        admin.clone(),
+        token.clone(),
+        amount,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_large_payment_threshold(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::LargePaymentThreshold(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Conversion fee owed, on top of the base fee, when a payment is made in
+/// `payer_token` against an invoice denominated in `invoice_token`.
+pub fn calculate_conversion_fee(
+    env: &Env,
+    payer_token: &Address,
+    invoice_token: &Address,
+    amount: i128,
+) -> i128 {
+    if payer_token == invoice_token {
+        return 0;
+    }
+    bps_of(
+        amount,
+        get_conversion_fee_bps(env) as i128,
+        fee_pool_keeps_dust(env),
+    )
+}
+
+pub fn add_fee_exempt_payer(env: &Env, admin: &Address, payer: &Address) {
+    core::assert_admin(env, admin);
+    set_fee_exemption(env, admin, payer, true);
+}
+
+pub fn remove_fee_exempt_payer(env: &Env, admin: &Address, payer: &Address) {
+    core::assert_admin(env, admin);
+    set_fee_exemption(env, admin, payer, false);
+}
+
+/// Exempts every listed payer in one call. Payers that are already exempt,
+/// including repeats within the list, are left alone and emit no event.
+pub fn add_fee_exempt_payers(env: &Env, admin: &Address, payers: &Vec<Address>) {
+    set_fee_exemptions(env, admin, payers, true);
+}
+
+/// Lifts the exemption from every listed payer; the batch counterpart of
+/// `remove_fee_exempt_payer`.
+pub fn remove_fee_exempt_payers(env: &Env, admin: &Address, payers: &Vec<Address>) {
+    set_fee_exemptions(env, admin, payers, false);
+}
+
+fn set_fee_exemptions(env: &Env, admin: &Address, payers: &Vec<Address>, exempt: bool) {
+    core::assert_admin(env, admin);
+
+    if payers.len() > MAX_FEE_EXEMPTION_BATCH {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    for payer in payers.iter() {
+        if is_fee_exempt(env, &payer) != exempt {
+            set_fee_exemption(env, admin, &payer, exempt);
+        }
+    }
+}
+
+fn set_fee_exemption(env: &Env, admin: &Address, payer: &Address, exempt: bool) {
+    let key = DataKey::FeeExemptPayer(payer.clone());
+    if exempt {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    events::publish_fee_exemption_updated_event(
+        env,
+        admin.clone(),
+        payer.clone(),
+        exempt,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn is_fee_exempt(env: &Env, payer: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::FeeExemptPayer(payer.clone()))
 }
 
 pub fn propose_fee(env: &Env, admin: &Address, token: &Address, fee: i128) {
@@ -146,6 +618,8 @@ pub fn propose_fee(env: &Env, admin: &Address, token: &Address, fee: i128) {
     if !is_accepted_token(env, token) {
         panic_with_error!(env, ContractError::TokenNotAccepted);
     }
+    assert_valid_fee_bps(env, fee);
+    assert_within_fee_ceiling(env, token, fee);
 
     let pending = PendingFee {
         token: token.clone(),
@@ -181,6 +655,8 @@ pub fn execute_fee(env: &Env, admin: &Address, token: &Address) {
     if elapsed < FEE_UPDATE_DELAY {
         panic_with_error!(env, ContractError::FeeUpdateTooEarly);
     }
+    // The ceiling may have been lowered while the update was pending.
+    assert_within_fee_ceiling(env, token, pending.fee);
 
     env.storage()
         .persistent()
@@ -243,7 +719,451 @@ pub fn accept_admin_transfer(env: &Env, new_admin: &Address) {
     );
 }
 
-fn get_accepted_tokens(env: &Env) -> Vec<Address> {
+pub fn set_backup_admin(env: &Env, admin: &Address, backup: &Address) {
+    core::assert_admin(env, admin);
+    env.storage()
+        .persistent()
+        .set(&DataKey::BackupAdmin, backup);
+    events::publish_backup_admin_set_event(
+        env,
+        admin.clone(),
+        backup.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_backup_admin(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::BackupAdmin)
+}
+
+pub fn set_admin_inactivity_period(env: &Env, admin: &Address, secs: u64) {
+    core::assert_admin(env, admin);
+    if secs == 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::AdminInactivityPeriod, &secs);
+}
+
+pub fn get_admin_inactivity_period(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AdminInactivityPeriod)
+        .unwrap_or(DEFAULT_ADMIN_INACTIVITY_PERIOD)
+}
+
+/// Lets the backup admin take over once the primary has been inactive for the
+/// configured period.
+pub fn claim_admin(env: &Env, backup: &Address) {
+    backup.require_auth();
+    if get_backup_admin(env).as_ref() != Some(backup) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    let inactive_since = core::get_last_admin_activity(env);
+    if env.ledger().timestamp() < inactive_since.saturating_add(get_admin_inactivity_period(env)) {
+        panic_with_error!(env, ContractError::PrimaryActive);
+    }
+
+    let old_admin = core::get_admin(env);
+    env.storage().persistent().set(&DataKey::Admin, backup);
+    env.storage().persistent().remove(&DataKey::BackupAdmin);
+    env.storage().persistent().remove(&DataKey::PendingAdmin);
+    core::record_admin_activity(env);
+
+    events::publish_admin_claimed_event(env, old_admin, backup.clone(), env.ledger().timestamp());
+}
+
+pub fn set_fee_ceiling(env: &Env, admin: &Address, token: &Address, max_fee: i128) {
+    reentrancy::enter(env);
+    core::assert_admin(env, admin);
+
+    if max_fee < 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeeCeiling(token.clone()), &max_fee);
+
+    events::publish_fee_ceiling_set_event(
+        env,
+        admin.clone(),
+        token.clone(),
+        max_fee,
+        env.ledger().timestamp(),
+    );
+    reentrancy::exit(env);
+}
+
+pub fn get_fee_ceiling(env: &Env, token: &Address) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeeCeiling(token.clone()))
+}
+
+pub fn set_kyc_registry(env: &Env, admin: &Address, registry: &Address) {
+    store_kyc_registry(env, admin, Some(registry.clone()));
+}
+
+pub fn clear_kyc_registry(env: &Env, admin: &Address) {
+    store_kyc_registry(env, admin, None);
+}
+
+pub fn get_kyc_registry(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::KycRegistry)
+}
+
+fn store_kyc_registry(env: &Env, admin: &Address, registry: Option<Address>) {
+    core::assert_admin(env, admin);
+
+    match &registry {
+        Some(registry) => env
+            .storage()
+            .persistent()
+            .set(&DataKey::KycRegistry, registry),
+        None => env.storage().persistent().remove(&DataKey::KycRegistry),
+    }
+
+    events::publish_kyc_registry_set_event(env, admin.clone(), registry, env.ledger().timestamp());
+}
+
+/// Sets amount-based fee tiers for `token` as `(min_amount, bps)` pairs sorted by
+/// strictly increasing `min_amount`. An empty list removes the tiers.
+pub fn set_fee_tiers(env: &Env, admin: &Address, token: &Address, tiers: &Vec<(i128, u32)>) {
+    core::assert_admin(env, admin);
+
+    if !is_accepted_token(env, token) {
+        panic_with_error!(env, ContractError::TokenNotAccepted);
+    }
+    if tiers.len() > MAX_FEE_TIERS {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    let mut previous_min: Option<i128> = None;
+    for (min_amount, bps) in tiers.iter() {
+        if min_amount < 0 || previous_min.is_some_and(|prev| min_amount <= prev) {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        assert_valid_fee_bps(env, bps as i128);
+        assert_within_fee_ceiling(env, token, bps as i128);
+        previous_min = Some(min_amount);
+    }
+
+    let key = ConfigKey::FeeTiers(token.clone());
+    if tiers.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, tiers);
+    }
+
+    events::publish_fee_tiers_set_event(
+        env,
+        admin.clone(),
+        token.clone(),
+        tiers.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_fee_tiers(env: &Env, token: &Address) -> Vec<(i128, u32)> {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::FeeTiers(token.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Basis points of the highest tier `invoice_amount` reaches, or the token's
+/// default fee when it reaches none.
+pub fn get_fee_bps_for_amount(env: &Env, token: &Address, invoice_amount: i128) -> i128 {
+    let mut fee_bps = get_fee(env, token);
+    for (min_amount, bps) in get_fee_tiers(env, token).iter() {
+        if invoice_amount < min_amount {
+            break;
+        }
+        fee_bps = bps as i128;
+    }
+    fee_bps
+}
+
+/// The per-token fee is a rate in basis points, so it can't exceed 100%.
+/// Rejects a basis-point rate outside 0..=10_000 with `InvalidFee`.
+pub fn assert_valid_fee_bps(env: &Env, fee: i128) {
+    if !(0..=10_000).contains(&fee) {
+        panic_with_error!(env, ExtendedError::InvalidFee);
+    }
+}
+
+fn assert_within_fee_ceiling(env: &Env, token: &Address, fee: i128) {
+    if let Some(max_fee) = get_fee_ceiling(env, token) {
+        if fee > max_fee {
+            panic_with_error!(env, ContractError::FeeAboveCeiling);
+        }
+    }
+}
+
+/// Sweeps the contract's whole balance of `token` to `recipient` and zeroes the
+/// fee pool, held total and merchant holdbacks for it. Funded and disputed
+/// invoices in `token` are written off, since their escrow left with the
+/// sweep. Only allowed while the contract is paused, as an incident-response
+/// measure.
+pub fn emergency_withdraw(env: &Env, admin: &Address, token: &Address, recipient: &Address) {
+    reentrancy::enter(env);
+    core::assert_admin(env, admin);
+    pausable::assert_paused(env);
+
+    let token_client = token::TokenClient::new(env, token);
+    let contract_address = env.current_contract_address();
+    let amount = token_client.balance(&contract_address);
+    if amount > 0
+        && !invoice::try_settle_transfer(env, &token_client, &contract_address, recipient, amount)
+    {
+        panic_with_error!(env, ContractError::SettlementFailed);
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::FeePool(token.clone()));
+    escrow::write_off_escrow(env, token);
+    merchant::clear_holdbacks(env, token);
+
+    events::publish_balance_changed_event(
+        env,
+        contract_address.clone(),
+        token.clone(),
+        -amount,
+        token_client.balance(&contract_address),
+    );
+    events::publish_emergency_withdrawal_event(
+        env,
+        admin.clone(),
+        token.clone(),
+        recipient.clone(),
+        amount,
+        env.ledger().timestamp(),
+    );
+    reentrancy::exit(env);
+}
+
+/// Adds collected fees to the running fee pool total for `token`. Fees only
+/// enter the pool when the contract itself is the fee destination.
+pub fn record_fee_collected(env: &Env, token: &Address, amount: i128) {
+    if amount <= 0 || get_fee_destination(env, token) != env.current_contract_address() {
+        return;
+    }
+    let key = DataKey::FeePool(token.clone());
+    let pool: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(pool + amount));
+}
+
+/// Transfers the whole accrued fee pool for `token` to `to` and resets it.
+pub fn withdraw_fees(env: &Env, admin: &Address, token: &Address, to: &Address) -> i128 {
+    reentrancy::enter(env);
+    core::assert_admin(env, admin);
+
+    let key = DataKey::FeePool(token.clone());
+    let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    if amount <= 0 {
+        panic_with_error!(env, ExtendedError::NothingToWithdraw);
+    }
+    env.storage().persistent().remove(&key);
+
+    let token_client = token::TokenClient::new(env, token);
+    let contract_address = env.current_contract_address();
+    token_client.transfer(&contract_address, to, &amount);
+
+    events::publish_balance_changed_event(
+        env,
+        contract_address.clone(),
+        token.clone(),
+        -amount,
+        token_client.balance(&contract_address),
+    );
+    events::publish_fees_withdrawn_event(
+        env,
+        admin.clone(),
+        token.clone(),
+        to.clone(),
+        amount,
+        env.ledger().timestamp(),
+    );
+    reentrancy::exit(env);
+    amount
+}
+
+/// Returns `(total_escrow, fee_pool)` for `token`. `total_escrow` covers funded
+/// escrow invoices and merchant holdbacks the contract is holding.
+pub fn get_pool_totals(env: &Env, token: &Address) -> (i128, i128) {
+    let fee_pool: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::FeePool(token.clone()))
+        .unwrap_or(0);
+    (escrow::get_held_total(env, token), fee_pool)
+}
+
+pub fn get_contract_balance(env: &Env, token: &Address) -> i128 {
+    token::TokenClient::new(env, token).balance(&env.current_contract_address())
+}
+
+pub fn set_max_description_len(env: &Env, admin: &Address, len: u32) {
+    core::assert_admin(env, admin);
+
+    if len == 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::MaxDescriptionLen, &len);
+
+    events::publish_max_description_len_set_event(
+        env,
+        admin.clone(),
+        len,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_max_description_len(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MaxDescriptionLen)
+        .unwrap_or(DEFAULT_MAX_DESCRIPTION_LEN)
+}
+
+pub fn set_merchant_cooldown(env: &Env, admin: &Address, secs: u64) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::MerchantCooldown, &secs);
+
+    events::publish_merchant_cooldown_set_event(env, admin.clone(), secs, env.ledger().timestamp());
+}
+
+pub fn get_merchant_cooldown(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MerchantCooldown)
+        .unwrap_or(0)
+}
+
+/// Switches the contract's optional events on or off. Invoice settlement is
+/// always published so indexers can still follow payments.
+pub fn set_events_enabled(env: &Env, admin: &Address, enabled: bool) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::EventsEnabled, &enabled);
+
+    events::publish_events_enabled_set_event(env, admin.clone(), enabled, env.ledger().timestamp());
+}
+
+pub fn are_events_enabled(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::EventsEnabled)
+        .unwrap_or(true)
+}
+
+/// How long after creation a pending invoice can still be amended or moved to
+/// another token; 0 leaves invoices editable for as long as they are pending.
+pub fn set_edit_window(env: &Env, admin: &Address, secs: u64) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::EditWindow, &secs);
+
+    events::publish_edit_window_set_event(env, admin.clone(), secs, env.ledger().timestamp());
+}
+
+pub fn get_edit_window(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::EditWindow)
+        .unwrap_or(0)
+}
+
+pub fn set_callback_revert_on_failure(env: &Env, admin: &Address, revert: bool) {
+    core::assert_admin(env, admin);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CallbackRevertOnFailure, &revert);
+
+    events::publish_callback_revert_set_event(env, admin.clone(), revert, env.ledger().timestamp());
+}
+
+pub fn get_callback_revert_on_failure(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CallbackRevertOnFailure)
+        .unwrap_or(false)
+}
+
+pub fn set_max_partials(env: &Env, admin: &Address, max_partials: u32) {
+    core::assert_admin(env, admin);
+    env.storage()
+        .persistent()
+        .set(&DataKey::MaxPartials, &max_partials);
+
+    events::publish_max_partials_set_event(
+        env,
+        admin.clone(),
+        max_partials,
+        env.ledger().timestamp(),
+    );
+}
+
+/// Maximum number of partial payments an invoice may receive; 0 means unlimited.
+pub fn get_max_partials(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MaxPartials)
+        .unwrap_or(0)
+}
+
+/// Caps the total late fee an overdue invoice can accrue, in bps of its amount.
+pub fn set_max_late_fee_bps(env: &Env, admin: &Address, bps: u32) {
+    core::assert_admin(env, admin);
+    assert_valid_fee_bps(env, bps as i128);
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::MaxLateFeeBps, &bps);
+
+    events::publish_max_late_fee_set_event(env, admin.clone(), bps, env.ledger().timestamp());
+}
+
+pub fn get_max_late_fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::MaxLateFeeBps)
+        .unwrap_or(DEFAULT_MAX_LATE_FEE_BPS)
+}
+
+pub fn assert_description_len(env: &Env, description: &String) {
+    if description.len() > get_max_description_len(env) {
+        panic_with_error!(env, ContractError::DescriptionTooLong);
+    }
+}
+
+/// Returns `(token, flat_fee, fee_bps)` for every accepted token.
+pub fn get_accepted_tokens_with_fees(env: &Env) -> Vec<(Address, i128, u32)> {
+    let mut tokens_with_fees: Vec<(Address, i128, u32)> = Vec::new(env);
+    for token in get_accepted_tokens(env).iter() {
+        let fee_bps = get_fee(env, &token) as u32;
+        let flat_fee = get_flat_fee(env, &token);
+        tokens_with_fees.push_back((token, flat_fee, fee_bps));
+    }
+    tokens_with_fees
+}
+
+pub fn get_accepted_token_count(env: &Env) -> u32 {
+    get_accepted_tokens(env).len()
+}
+
+pub fn get_accepted_tokens(env: &Env) -> Vec<Address> {
     env.storage()
         .persistent()
         .get(&DataKey::AcceptedTokens)
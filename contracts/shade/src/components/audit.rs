@@ -0,0 +1,64 @@
+use crate::errors::ContractError;
+use crate::types::{AuditAction, ConfigKey, ExportedEvent};
+use soroban_sdk::{panic_with_error, Env, Vec};
+
+/// Number of most recent state changes kept on-chain. Older entries are
+/// dropped as new ones are recorded, so exports only cover this window.
+pub const AUDIT_RETENTION: u64 = 500;
+pub const MAX_AUDIT_EXPORT: u32 = 100;
+
+/// Appends a state change to the audit log under the next sequence number and
+/// drops the entry that falls out of the retention window.
+pub fn record(env: &Env, action: AuditAction, primary_id: u64, secondary_id: u64, amount: i128) {
+    let seq = get_audit_sequence(env) + 1;
+    let storage = env.storage().persistent();
+    storage.set(
+        &ConfigKey::AuditEntry(seq),
+        &ExportedEvent {
+            seq,
+            action,
+            primary_id,
+            secondary_id,
+            amount,
+            ledger: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    storage.set(&ConfigKey::AuditSequence, &seq);
+    if seq > AUDIT_RETENTION {
+        storage.remove(&ConfigKey::AuditEntry(seq - AUDIT_RETENTION));
+    }
+}
+
+/// Sequence number of the most recent entry, zero before anything is recorded.
+pub fn get_audit_sequence(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::AuditSequence)
+        .unwrap_or(0)
+}
+
+/// Returns up to `limit` consecutive entries starting at `seq`. When `seq` is
+/// older than the retention window the export starts at the oldest retained
+/// entry; callers detect the gap from the first entry's `seq`.
+pub fn export_events_since(env: &Env, seq: u64, limit: u32) -> Vec<ExportedEvent> {
+    if limit == 0 || limit > MAX_AUDIT_EXPORT {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let latest = get_audit_sequence(env);
+    let oldest = latest.saturating_sub(AUDIT_RETENTION) + 1;
+    let mut entries: Vec<ExportedEvent> = Vec::new(env);
+    let mut next = seq.max(oldest);
+    while next <= latest && entries.len() < limit {
+        if let Some(entry) = env
+            .storage()
+            .persistent()
+            .get::<_, ExportedEvent>(&ConfigKey::AuditEntry(next))
+        {
+            entries.push_back(entry);
+        }
+        next += 1;
+    }
+    entries
+}
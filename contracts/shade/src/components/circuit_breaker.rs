@@ -0,0 +1,78 @@
+use crate::components::core;
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{CircuitBreaker, DataKey};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+pub fn set_circuit_breaker(
+    env: &Env,
+    admin: &Address,
+    token: &Address,
+    window_secs: u64,
+    threshold: i128,
+) {
+    core::assert_admin(env, admin);
+
+    if window_secs == 0 {
+        panic_with_error!(env, ContractError::InvalidInterval);
+    }
+    if threshold <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let breaker = CircuitBreaker {
+        window_secs,
+        threshold,
+        window_start: env.ledger().timestamp(),
+        window_volume: 0,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::CircuitBreaker(token.clone()), &breaker);
+
+    events::publish_circuit_breaker_set_event(
+        env,
+        admin.clone(),
+        token.clone(),
+        window_secs,
+        threshold,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_circuit_breaker(env: &Env, token: &Address) -> Option<CircuitBreaker> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CircuitBreaker(token.clone()))
+}
+
+/// Adds `amount` to the token's current window and pauses the contract if the
+/// window volume exceeds the configured threshold. The window restarts once
+/// `window_secs` have passed since it opened.
+pub fn record_settled_volume(env: &Env, token: &Address, amount: i128) {
+    let Some(mut breaker) = get_circuit_breaker(env, token) else {
+        return;
+    };
+
+    let now = env.ledger().timestamp();
+    if now >= breaker.window_start.saturating_add(breaker.window_secs) {
+        breaker.window_start = now;
+        breaker.window_volume = 0;
+    }
+    breaker.window_volume += amount;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::CircuitBreaker(token.clone()), &breaker);
+
+    if breaker.window_volume > breaker.threshold {
+        env.storage().persistent().set(&DataKey::Paused, &true);
+        events::publish_circuit_tripped_event(
+            env,
+            token.clone(),
+            breaker.window_volume,
+            breaker.threshold,
+            now,
+        );
+    }
+}
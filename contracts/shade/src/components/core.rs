@@ -14,4 +14,20 @@ pub fn assert_admin(env: &Env, admin: &Address) {
     if *admin != get_admin(env) {
         panic_with_error!(env, ContractError::NotAuthorized);
     }
+    record_admin_activity(env);
+}
+
+/// Marks the admin as active now; a backup admin can only claim control
+/// after a period without such activity.
+pub fn record_admin_activity(env: &Env) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::LastAdminActivity, &env.ledger().timestamp());
+}
+
+pub fn get_last_admin_activity(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LastAdminActivity)
+        .unwrap_or(0)
 }
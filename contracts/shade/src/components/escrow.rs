@@ -0,0 +1,315 @@
+use crate::components::core as core_component;
+use crate::components::{health, invoice, merchant};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{ConfigKey, DataKey, Invoice, InvoiceStatus};
+use soroban_sdk::{panic_with_error, token, Address, Env, Vec};
+
+pub const DEFAULT_RECEIPT_TIMEOUT: u64 = 1_209_600; // 14 days
+
+/// Moves a full payment for a two-phase invoice from `payer` into the contract
+/// and marks the invoice `Funded`. Payouts happen when the funds are released.
+pub fn fund_escrow(env: &Env, payer: &Address, mut invoice: Invoice, amount: i128) {
+    let token_client = token::TokenClient::new(env, &invoice.token);
+    let contract_address = env.current_contract_address();
+    let received =
+        invoice::settle_transfer_received(env, &token_client, payer, &contract_address, amount)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::SettlementFailed));
+
+    let now = env.ledger().timestamp();
+    invoice.status = InvoiceStatus::Funded;
+    invoice.payer = Some(payer.clone());
+    invoice.funded_at = Some(now);
+    invoice.escrowed_amount = received;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice.id), &invoice);
+    adjust_held_total(env, &invoice.token, received);
+    track_escrowed(env, &invoice.token, invoice.id, true);
+    let ledger_delay = get_withdrawal_ledger_delay(env);
+    if ledger_delay > 0 {
+        env.storage().persistent().set(
+            &ConfigKey::WithdrawableAfterLedger(invoice.id),
+            &env.ledger().sequence().saturating_add(ledger_delay),
+        );
+    }
+
+    events::publish_invoice_funded_event(env, invoice.id, payer.clone(), amount, now);
+}
+
+/// Called by the payer once goods arrive; releases escrow to the merchant.
+pub fn confirm_receipt(env: &Env, payer: &Address, invoice_id: u64) {
+    payer.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    if invoice.status != InvoiceStatus::Funded {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+    if invoice.payer.as_ref() != Some(payer) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    events::publish_receipt_confirmed_event(
+        env,
+        invoice_id,
+        payer.clone(),
+        env.ledger().timestamp(),
+    );
+    release(env, invoice);
+}
+
+/// Lets the merchant collect escrowed funds the payer never confirmed, once the
+/// receipt timeout has passed since funding and any ledger hold has elapsed.
+pub fn claim_after_timeout(env: &Env, merchant_address: &Address, invoice_id: u64) {
+    merchant_address.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    if invoice.merchant_id != merchant::get_merchant_id(env, merchant_address) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+    if invoice.status != InvoiceStatus::Funded {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+    if env.ledger().timestamp() < release_deadline(env, &invoice) {
+        panic_with_error!(env, ContractError::ReleaseTooEarly);
+    }
+    if get_withdrawable_after_ledger(env, invoice_id)
+        .is_some_and(|ledger| env.ledger().sequence() < ledger)
+    {
+        panic_with_error!(env, ContractError::ReleaseTooEarly);
+    }
+
+    release(env, invoice);
+}
+
+/// Freezes a funded invoice before its receipt deadline until the admin
+/// resolves the dispute.
+pub fn dispute_invoice(env: &Env, payer: &Address, invoice_id: u64) {
+    payer.require_auth();
+
+    let mut invoice = invoice::get_invoice(env, invoice_id);
+    if invoice.status != InvoiceStatus::Funded {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+    if invoice.payer.as_ref() != Some(payer) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+    if env.ledger().timestamp() >= release_deadline(env, &invoice) {
+        panic_with_error!(env, ContractError::InvoiceExpired);
+    }
+
+    invoice.status = InvoiceStatus::Disputed;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_invoice_disputed_event(
+        env,
+        invoice_id,
+        payer.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+/// Settles a dispute by releasing escrow to the merchant or refunding the payer.
+pub fn resolve_dispute(env: &Env, admin: &Address, invoice_id: u64, release_to_merchant: bool) {
+    core_component::assert_admin(env, admin);
+
+    let mut invoice = invoice::get_invoice(env, invoice_id);
+    if invoice.status != InvoiceStatus::Disputed {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
+    events::publish_dispute_resolved_event(
+        env,
+        invoice_id,
+        admin.clone(),
+        release_to_merchant,
+        env.ledger().timestamp(),
+    );
+
+    if release_to_merchant {
+        release(env, invoice);
+        return;
+    }
+
+    let payer = escrow_payer(env, &invoice);
+    let token_client = token::TokenClient::new(env, &invoice.token);
+    if !invoice::try_settle_transfer(
+        env,
+        &token_client,
+        &env.current_contract_address(),
+        &payer,
+        invoice.escrowed_amount,
+    ) {
+        panic_with_error!(env, ContractError::SettlementFailed);
+    }
+
+    clear_withdrawal_hold(env, invoice_id);
+    adjust_held_total(env, &invoice.token, -invoice.escrowed_amount);
+    track_escrowed(env, &invoice.token, invoice_id, false);
+    invoice.status = InvoiceStatus::Refunded;
+    invoice.amount_paid = invoice.amount;
+    invoice.amount_refunded = invoice.amount;
+    health::track_invoice_transition(env, InvoiceStatus::Disputed, invoice.status);
+    health::record_outcome(
+        env,
+        invoice.merchant_id,
+        InvoiceStatus::Disputed,
+        invoice.status,
+    );
+    health::adjust_liability(env, &invoice.token, -invoice.amount);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+}
+
+pub fn set_receipt_timeout(env: &Env, admin: &Address, secs: u64) {
+    core_component::assert_admin(env, admin);
+
+    if secs == 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::ReceiptTimeout, &secs);
+}
+
+pub fn get_receipt_timeout(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::ReceiptTimeout)
+        .unwrap_or(DEFAULT_RECEIPT_TIMEOUT)
+}
+
+/// Number of ledgers escrowed funds are held after funding before the merchant
+/// can claim them; applies on top of the receipt timeout. 0 disables the hold.
+pub fn set_withdrawal_ledger_delay(env: &Env, admin: &Address, ledgers: u32) {
+    core_component::assert_admin(env, admin);
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::WithdrawalLedgerDelay, &ledgers);
+}
+
+pub fn get_withdrawal_ledger_delay(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::WithdrawalLedgerDelay)
+        .unwrap_or(0)
+}
+
+/// First ledger at which a funded invoice's escrow can be claimed, recorded
+/// when it was funded under a ledger delay.
+pub fn get_withdrawable_after_ledger(env: &Env, invoice_id: u64) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::WithdrawableAfterLedger(invoice_id))
+}
+
+/// Adjusts the running total of `token` the contract holds for others: funded
+/// escrow and merchant holdbacks.
+pub fn adjust_held_total(env: &Env, token: &Address, delta: i128) {
+    if delta == 0 {
+        return;
+    }
+    let held = get_held_total(env, token) + delta;
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::HeldTotal(token.clone()), &held.max(0));
+}
+
+pub fn get_held_total(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::HeldTotal(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Ids of `token` invoices whose funded payment the contract is holding.
+pub fn get_escrowed_invoices(env: &Env, token: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::EscrowedInvoices(token.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn track_escrowed(env: &Env, token: &Address, invoice_id: u64, held: bool) {
+    let mut invoice_ids = get_escrowed_invoices(env, token);
+    match (held, invoice_ids.first_index_of(invoice_id)) {
+        (true, None) => invoice_ids.push_back(invoice_id),
+        (false, Some(index)) => {
+            invoice_ids.remove(index);
+        }
+        _ => return,
+    }
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::EscrowedInvoices(token.clone()), &invoice_ids);
+}
+
+/// Writes off every funded or disputed `token` invoice and zeroes the held
+/// total, once an emergency sweep has taken the funds backing them.
+pub fn write_off_escrow(env: &Env, token: &Address) {
+    let now = env.ledger().timestamp();
+    for invoice_id in get_escrowed_invoices(env, token).iter() {
+        let mut invoice = invoice::get_invoice(env, invoice_id);
+        let previous_status = invoice.status;
+        clear_withdrawal_hold(env, invoice_id);
+        invoice.status = InvoiceStatus::WrittenOff;
+        invoice.escrowed_amount = 0;
+        invoice.written_off_at = Some(now);
+        health::track_invoice_transition(env, previous_status, invoice.status);
+        health::record_outcome(env, invoice.merchant_id, previous_status, invoice.status);
+        health::adjust_liability(env, token, -invoice.amount);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Invoice(invoice_id), &invoice);
+
+        let merchant_address = merchant::get_merchant(env, invoice.merchant_id).address;
+        events::publish_invoice_written_off_event(env, invoice_id, merchant_address, now);
+    }
+    env.storage()
+        .persistent()
+        .remove(&ConfigKey::EscrowedInvoices(token.clone()));
+    env.storage()
+        .persistent()
+        .remove(&ConfigKey::HeldTotal(token.clone()));
+}
+
+fn clear_withdrawal_hold(env: &Env, invoice_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&ConfigKey::WithdrawableAfterLedger(invoice_id));
+}
+
+fn release(env: &Env, invoice: Invoice) {
+    clear_withdrawal_hold(env, invoice.id);
+    let payer = escrow_payer(env, &invoice);
+    let released = invoice.escrowed_amount;
+    let credited = invoice.amount;
+    adjust_held_total(env, &invoice.token, -released);
+    track_escrowed(env, &invoice.token, invoice.id, false);
+    invoice::disburse_payment(
+        env,
+        &env.current_contract_address(),
+        &payer,
+        invoice,
+        released,
+        credited,
+        None,
+    );
+}
+
+fn release_deadline(env: &Env, invoice: &Invoice) -> u64 {
+    invoice
+        .funded_at
+        .unwrap_or(0)
+        .saturating_add(get_receipt_timeout(env))
+}
+
+fn escrow_payer(env: &Env, invoice: &Invoice) -> Address {
+    invoice
+        .payer
+        .clone()
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::PayerNotAvailable))
+}
@@ -0,0 +1,197 @@
+use crate::components::{admin, pausable};
+use crate::errors::ContractError;
+use crate::types::{ConfigKey, DataKey, Health, InvoiceStatus, MerchantOutcomes};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+pub const MAX_TOP_MERCHANTS: u32 = 20;
+
+pub fn is_open(status: InvoiceStatus) -> bool {
+    status == InvoiceStatus::Pending
+        || status == InvoiceStatus::PartiallyPaid
+        || status == InvoiceStatus::Settling
+        || status == InvoiceStatus::Funded
+        || status == InvoiceStatus::Disputed
+}
+
+pub fn invoice_opened(env: &Env) {
+    adjust_counter(env, &DataKey::OpenInvoiceCount, true);
+}
+
+pub fn invoice_closed(env: &Env) {
+    adjust_counter(env, &DataKey::OpenInvoiceCount, false);
+}
+
+/// Keeps the open-invoice counter in step with a single status change.
+pub fn track_invoice_transition(env: &Env, from: InvoiceStatus, to: InvoiceStatus) {
+    match (is_open(from), is_open(to)) {
+        (false, true) => invoice_opened(env),
+        (true, false) => invoice_closed(env),
+        _ => {}
+    }
+}
+
+/// Moves the amount still owed on open invoices in `token` by `delta`.
+pub fn adjust_liability(env: &Env, token: &Address, delta: i128) {
+    if delta == 0 {
+        return;
+    }
+    let key = ConfigKey::OutstandingLiability(token.clone());
+    let liability = get_outstanding_liability(env, token) + delta;
+    env.storage().persistent().set(&key, &liability.max(0));
+}
+
+pub fn get_outstanding_liability(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::OutstandingLiability(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Moves the total paid volume in `token` by `delta`: payments add, refunds
+/// subtract.
+pub fn adjust_volume(env: &Env, token: &Address, delta: i128) {
+    if delta == 0 {
+        return;
+    }
+    let volume = get_volume(env, token) + delta;
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::Volume(token.clone()), &volume.max(0));
+}
+
+pub fn get_volume(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::Volume(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Moves a merchant's invoice between outcome buckets when its status changes.
+/// Partial refunds stay counted as paid.
+pub fn record_outcome(env: &Env, merchant_id: u64, from: InvoiceStatus, to: InvoiceStatus) {
+    let mut outcomes = get_merchant_outcomes(env, merchant_id);
+    let before = outcomes.clone();
+    if let Some(count) = outcome_bucket(&mut outcomes, from) {
+        *count = count.saturating_sub(1);
+    }
+    if let Some(count) = outcome_bucket(&mut outcomes, to) {
+        *count += 1;
+    }
+    if outcomes != before {
+        env.storage()
+            .persistent()
+            .set(&ConfigKey::MerchantOutcomes(merchant_id), &outcomes);
+    }
+}
+
+pub fn get_merchant_outcomes(env: &Env, merchant_id: u64) -> MerchantOutcomes {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::MerchantOutcomes(merchant_id))
+        .unwrap_or_default()
+}
+
+/// Share of a merchant's terminal invoices that were paid, in basis points.
+pub fn get_merchant_success_rate(env: &Env, merchant_id: u64) -> u32 {
+    let outcomes = get_merchant_outcomes(env, merchant_id);
+    let terminal = outcomes.paid
+        + outcomes.cancelled
+        + outcomes.expired
+        + outcomes.refunded
+        + outcomes.written_off;
+    if terminal == 0 {
+        return 0;
+    }
+    (outcomes.paid * 10_000 / terminal) as u32
+}
+
+/// Adds `amount` to what `merchant_id` has earned in `token` and moves it up the
+/// token's leaderboard. Totals only grow, so keeping the best
+/// `MAX_TOP_MERCHANTS` entries keeps the board exact.
+pub fn record_merchant_earnings(env: &Env, merchant_id: u64, token: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let earned_key = ConfigKey::MerchantEarned(merchant_id, token.clone());
+    let earned: i128 = env.storage().persistent().get(&earned_key).unwrap_or(0) + amount;
+    env.storage().persistent().set(&earned_key, &earned);
+
+    let mut board = get_leaderboard(env, token);
+    if let Some(index) = board.iter().position(|(id, _)| id == merchant_id) {
+        board.remove(index as u32);
+    }
+    // ties keep the merchant that got there first ahead
+    let mut index = board.len();
+    while index > 0 && board.get_unchecked(index - 1).1 < earned {
+        index -= 1;
+    }
+    if index < MAX_TOP_MERCHANTS {
+        board.insert(index, (merchant_id, earned));
+        if board.len() > MAX_TOP_MERCHANTS {
+            board.pop_back();
+        }
+        env.storage()
+            .persistent()
+            .set(&ConfigKey::TopMerchants(token.clone()), &board);
+    }
+}
+
+/// Up to `limit` `(merchant_id, total earned)` pairs for `token`, highest first.
+pub fn get_top_merchants(env: &Env, token: &Address, limit: u32) -> Vec<(u64, i128)> {
+    if limit == 0 || limit > MAX_TOP_MERCHANTS {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    let board = get_leaderboard(env, token);
+    board.slice(0..limit.min(board.len()))
+}
+
+fn get_leaderboard(env: &Env, token: &Address) -> Vec<(u64, i128)> {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::TopMerchants(token.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn outcome_bucket(outcomes: &mut MerchantOutcomes, status: InvoiceStatus) -> Option<&mut u64> {
+    match status {
+        InvoiceStatus::Paid | InvoiceStatus::PartiallyRefunded => Some(&mut outcomes.paid),
+        InvoiceStatus::Cancelled => Some(&mut outcomes.cancelled),
+        InvoiceStatus::Expired => Some(&mut outcomes.expired),
+        InvoiceStatus::Refunded => Some(&mut outcomes.refunded),
+        InvoiceStatus::WrittenOff => Some(&mut outcomes.written_off),
+        _ => None,
+    }
+}
+
+pub fn merchant_activated(env: &Env) {
+    adjust_counter(env, &DataKey::ActiveMerchantCount, true);
+}
+
+pub fn merchant_deactivated(env: &Env) {
+    adjust_counter(env, &DataKey::ActiveMerchantCount, false);
+}
+
+pub fn get_health(env: &Env) -> Health {
+    Health {
+        total_invoices: get_counter(env, &DataKey::InvoiceCount),
+        open_invoices: get_counter(env, &DataKey::OpenInvoiceCount),
+        total_merchants: get_counter(env, &DataKey::MerchantCount),
+        active_merchants: get_counter(env, &DataKey::ActiveMerchantCount),
+        paused: pausable::is_paused(env),
+        accepted_token_count: admin::get_accepted_token_count(env),
+    }
+}
+
+fn get_counter(env: &Env, key: &DataKey) -> u64 {
+    env.storage().persistent().get(key).unwrap_or(0)
+}
+
+fn adjust_counter(env: &Env, key: &DataKey, increment: bool) {
+    let count = get_counter(env, key);
+    let count = if increment {
+        count + 1
+    } else {
+        count.saturating_sub(1)
+    };
+    env.storage().persistent().set(key, &count);
+}
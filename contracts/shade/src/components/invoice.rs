@@ -1,16 +1,46 @@
-use crate::components::{access_control, admin, merchant, signature_util};
-use crate::errors::ContractError;
+use crate::components::core as core_component;
+use crate::components::{
+    access_control, admin, circuit_breaker, escrow, health, merchant, signature_util,
+};
+use crate::errors::{ContractError, ExtendedError};
 use crate::events;
-use crate::types::{DataKey, Invoice, InvoiceFilter, InvoiceStatus, Role};
+use crate::types::{
+    ConfigKey, DataKey, ExpiryAction, Invoice, InvoiceFilter, InvoiceStatus, InvoiceTerms,
+    PaymentQuote, PaymentReceipt,
+};
 use soroban_sdk::token::TokenClient;
-use soroban_sdk::{contractclient, panic_with_error, token, Address, BytesN, Env, String, Vec};
+use soroban_sdk::xdr::{FromXdr, ToXdr};
+use soroban_sdk::{
+    contractclient, panic_with_error, token, vec, Address, Bytes, BytesN, Env, Map, String, Vec,
+};
 
 #[contractclient(name = "MerchantAccountRefundClient")]
 pub trait MerchantAccountRefund {
     fn refund(env: Env, token: Address, amount: i128, to: Address);
 }
 
+#[contractclient(name = "KycRegistryClient")]
+pub trait KycRegistry {
+    fn is_verified(env: Env, payer: Address) -> bool;
+}
+
+#[contractclient(name = "PayerCallbackClient")]
+pub trait PayerCallback {
+    fn on_payment_settled(env: Env, invoice_id: u64);
+}
+
 pub const MAX_REFUND_DURATION: u64 = 604_800; // 7 days
+pub const MAX_PAYER_NOTE_LEN: u32 = 256;
+pub const MAX_REISSUE_BATCH: u32 = 20;
+pub const MAX_REISSUE_SCAN: u32 = 200;
+pub const MAX_ENCRYPTED_DATA_LEN: u32 = 2048;
+pub const MAX_STATUS_BATCH: u32 = 100;
+pub const MAX_INVOICE_PAGE: u32 = 100;
+pub const MAX_PAYER_ASSIGN_BATCH: u32 = 50;
+pub const MAX_CANCEL_BATCH: u32 = 50;
+pub const MAX_CANCEL_SCAN: u32 = 200;
+pub const RENT_BYTES_PER_TAG: u64 = 40; // a Symbol of up to 32 chars in XDR
+pub const SECONDS_PER_DAY: u64 = 86_400;
 
 pub fn validate_invoice_creation(
     env: &Env,
@@ -23,11 +53,12 @@ pub fn validate_invoice_creation(
     if amount <= 0 {
         panic_with_error!(env, ContractError::InvalidAmount);
     }
-    if description.len() > 100 {
-        panic_with_error!(env, ContractError::InvalidDescription);
-    }
+    admin::assert_description_len(env, description);
     if !merchant::is_merchant(env, merchant_address) {
-        panic_with_error!(env, ContractError::NotAuthorized);
+        if merchant::has_pending_application(env, merchant_address) {
+            panic_with_error!(env, ContractError::MerchantNotApproved);
+        }
+        panic_with_error!(env, ContractError::MerchantNotFound);
     }
     // First, check global whitelist
     if !admin::is_accepted_token(env, token) {
@@ -65,6 +96,242 @@ pub fn create_invoice(
     amount: i128,
     token: &Address,
     expires_at: Option<u64>,
+) -> u64 {
+    create_scheduled_invoice(
+        env,
+        merchant_address,
+        description,
+        amount,
+        token,
+        None,
+        expires_at,
+    )
+}
+
+pub fn create_scheduled_invoice(
+    env: &Env,
+    merchant_address: &Address,
+    description: &String,
+    amount: i128,
+    token: &Address,
+    active_from: Option<u64>,
+    expires_at: Option<u64>,
+) -> u64 {
+    create_invoice_with_options(
+        env,
+        merchant_address,
+        description,
+        amount,
+        token,
+        active_from,
+        expires_at,
+        None,
+        ExpiryAction::Close,
+        None,
+        None,
+    )
+}
+
+pub fn create_invoice_with_note(
+    env: &Env,
+    merchant_address: &Address,
+    description: &String,
+    amount: i128,
+    token: &Address,
+    expires_at: Option<u64>,
+    payer_note: Option<String>,
+) -> u64 {
+    create_invoice_with_options(
+        env,
+        merchant_address,
+        description,
+        amount,
+        token,
+        None,
+        expires_at,
+        payer_note,
+        ExpiryAction::Close,
+        None,
+        None,
+    )
+}
+
+/// Creates an invoice that either just closes or reissues itself for a new
+/// period once it expires. Reissuing needs an expiry to derive the period from.
+pub fn create_invoice_on_expiry(
+    env: &Env,
+    merchant_address: &Address,
+    description: &String,
+    amount: i128,
+    token: &Address,
+    expires_at: Option<u64>,
+    on_expiry: ExpiryAction,
+) -> u64 {
+    if on_expiry == ExpiryAction::Reissue && expires_at.is_none() {
+        panic_with_error!(env, ContractError::InvalidInvoiceData);
+    }
+    create_invoice_with_options(
+        env,
+        merchant_address,
+        description,
+        amount,
+        token,
+        None,
+        expires_at,
+        None,
+        on_expiry,
+        None,
+        None,
+    )
+}
+
+/// Creates an invoice whose amount due grows by `late_fee_bps_per_day` for
+/// each full day it stays unpaid past `due_date`, up to the maximum late fee.
+pub fn create_invoice_with_late_fee(
+    env: &Env,
+    merchant_address: &Address,
+    description: &String,
+    amount: i128,
+    token: &Address,
+    due_date: u64,
+    late_fee_bps_per_day: u32,
+) -> u64 {
+    if late_fee_bps_per_day == 0 || late_fee_bps_per_day > 10_000 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    if due_date < env.ledger().timestamp() {
+        panic_with_error!(env, ContractError::InvalidInvoiceData);
+    }
+    create_invoice_with_options(
+        env,
+        merchant_address,
+        description,
+        amount,
+        token,
+        None,
+        None,
+        None,
+        ExpiryAction::Close,
+        Some(due_date),
+        Some(late_fee_bps_per_day),
+    )
+}
+
+/// A fresh `Pending` invoice with every optional field unset, stamped with the
+/// current ledger. Creation paths override what they set via struct update.
+fn new_pending_invoice(
+    env: &Env,
+    id: u64,
+    merchant_id: u64,
+    description: &String,
+    amount: i128,
+    token: &Address,
+) -> Invoice {
+    Invoice {
+        id,
+        description: description.clone(),
+        amount,
+        token: token.clone(),
+        status: InvoiceStatus::Pending,
+        merchant_id,
+        payer: None,
+        date_created: env.ledger().timestamp(),
+        date_paid: None,
+        amount_paid: 0,
+        amount_refunded: 0,
+        expires_at: None,
+        reminder_count: 0,
+        last_reminder_at: 0,
+        parts: Vec::new(env),
+        designated_payer: None,
+        fee_paid: 0,
+        receipt_hash: None,
+        delivery_proof: None,
+        quoted_amounts: Map::new(env),
+        quote_valid_until: 0,
+        active_from: None,
+        archived: false,
+        payer_note: None,
+        ledger_created: env.ledger().sequence(),
+        ledger_paid: None,
+        payer_callback: None,
+        encrypted_data: None,
+        requires_confirmation: false,
+        funded_at: None,
+        escrowed_amount: 0,
+        written_off_at: None,
+        refunded_by: None,
+        on_expiry: ExpiryAction::Close,
+        reissued_from: None,
+        due_date: None,
+        late_fee_bps_per_day: None,
+        merchant_sequence: 0,
+    }
+}
+
+/// Snapshots what the invoice costs in every other token the merchant accepts
+/// and the admin has set a conversion rate to, honored for the quote validity
+/// window. Replaces any earlier quotes.
+fn with_quote_snapshot(env: &Env, merchant_address: &Address, mut invoice: Invoice) -> Invoice {
+    let mut quotes = Map::new(env);
+    for token in admin::get_accepted_tokens(env).iter() {
+        if token == invoice.token
+            || !merchant::is_token_accepted_for_merchant(env, merchant_address, &token)
+        {
+            continue;
+        }
+        if let Some(amount) = admin::convert_amount(env, &invoice.token, &token, invoice.amount) {
+            quotes.set(token, amount);
+        }
+    }
+    invoice.quote_valid_until = if quotes.is_empty() {
+        0
+    } else {
+        env.ledger()
+            .timestamp()
+            .saturating_add(admin::get_quote_validity(env))
+    };
+    invoice.quoted_amounts = quotes;
+    invoice
+}
+
+/// What settling the invoice in `token` costs: its snapshot quote while still
+/// valid, otherwise a fresh quote at the current conversion rate.
+fn quoted_amount_for(env: &Env, invoice: &Invoice, token: &Address) -> i128 {
+    let snapshot = invoice.quoted_amounts.get(token.clone());
+    if let Some(amount) = snapshot {
+        if env.ledger().timestamp() < invoice.quote_valid_until {
+            return amount;
+        }
+    }
+
+    let merchant_address = merchant::get_merchant(env, invoice.merchant_id).address;
+    if *token != invoice.token
+        && merchant::is_token_accepted_for_merchant(env, &merchant_address, token)
+    {
+        if let Some(amount) = admin::convert_amount(env, &invoice.token, token, invoice.amount) {
+            return amount;
+        }
+    }
+    if snapshot.is_some() {
+        panic_with_error!(env, ContractError::QuoteExpired);
+    }
+    panic_with_error!(env, ContractError::TokenNotAccepted);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn create_invoice_with_options(
+    env: &Env,
+    merchant_address: &Address,
+    description: &String,
+    amount: i128,
+    token: &Address,
+    active_from: Option<u64>,
+    expires_at: Option<u64>,
+    payer_note: Option<String>,
+    on_expiry: ExpiryAction,
+    due_date: Option<u64>,
+    late_fee_bps_per_day: Option<u32>,
 ) -> u64 {
     merchant_address.require_auth();
     validate_invoice_creation(
@@ -75,8 +342,16 @@ pub fn create_invoice(
         token,
         expires_at,
     );
+    assert_payer_note_len(env, &payer_note);
+    // the payable window must not be empty
+    if let (Some(active_from), Some(expires_at)) = (active_from, expires_at) {
+        if active_from >= expires_at {
+            panic_with_error!(env, ContractError::InvalidInvoiceData);
+        }
+    }
 
     let merchant_id: u64 = merchant::get_merchant_id(env, merchant_address);
+    enforce_creation_cooldown(env, merchant_id);
 
     let invoice_count: u64 = env
         .storage()
@@ -85,25 +360,26 @@ pub fn create_invoice(
         .unwrap_or(0);
     let new_invoice_id = invoice_count + 1;
     let invoice = Invoice {
-        id: new_invoice_id,
-        description: description.clone(),
-        amount,
-        token: token.clone(),
-        status: InvoiceStatus::Pending,
-        merchant_id,
-        payer: None,
-        date_created: env.ledger().timestamp(),
-        date_paid: None,
-        amount_paid: 0,
-        amount_refunded: 0,
         expires_at,
+        active_from,
+        payer_note,
+        on_expiry,
+        due_date,
+        late_fee_bps_per_day,
+        merchant_sequence: merchant::next_invoice_sequence(env, merchant_id),
+        ..new_pending_invoice(env, new_invoice_id, merchant_id, description, amount, token)
     };
+    let invoice = with_quote_snapshot(env, merchant_address, invoice);
     env.storage()
         .persistent()
         .set(&DataKey::Invoice(new_invoice_id), &invoice);
     env.storage()
         .persistent()
         .set(&DataKey::InvoiceCount, &new_invoice_id);
+    add_merchant_invoice(env, merchant_id, new_invoice_id);
+    add_token_invoice(env, &invoice.token, new_invoice_id);
+    health::invoice_opened(env);
+    health::adjust_liability(env, &invoice.token, invoice.amount);
     events::publish_invoice_created_event(
         env,
         new_invoice_id,
@@ -133,6 +409,7 @@ pub fn create_invoice_draft(
     );
 
     let merchant_id: u64 = merchant::get_merchant_id(env, merchant_address);
+    enforce_creation_cooldown(env, merchant_id);
 
     let invoice_count: u64 = env
         .storage()
@@ -141,25 +418,20 @@ pub fn create_invoice_draft(
         .unwrap_or(0);
     let new_invoice_id = invoice_count + 1;
     let invoice = Invoice {
-        id: new_invoice_id,
-        description: description.clone(),
-        amount,
-        token: token.clone(),
         status: InvoiceStatus::Draft,
-        merchant_id,
-        payer: None,
-        date_created: env.ledger().timestamp(),
-        date_paid: None,
-        amount_paid: 0,
-        amount_refunded: 0,
         expires_at,
+        merchant_sequence: merchant::next_invoice_sequence(env, merchant_id),
+        ..new_pending_invoice(env, new_invoice_id, merchant_id, description, amount, token)
     };
+    let invoice = with_quote_snapshot(env, merchant_address, invoice);
     env.storage()
         .persistent()
         .set(&DataKey::Invoice(new_invoice_id), &invoice);
     env.storage()
         .persistent()
         .set(&DataKey::InvoiceCount, &new_invoice_id);
+    add_merchant_invoice(env, merchant_id, new_invoice_id);
+    add_token_invoice(env, &invoice.token, new_invoice_id);
 
     // We intentionally don't emit InvoiceCreatedEvent here since it's a draft
 
@@ -186,6 +458,8 @@ pub fn finalize_invoice(env: &Env, merchant_address: &Address, invoice_id: u64)
     env.storage()
         .persistent()
         .set(&DataKey::Invoice(invoice_id), &invoice);
+    health::invoice_opened(env);
+    health::adjust_liability(env, &invoice.token, invoice.amount);
 
     events::publish_invoice_created_event(
         env,
@@ -208,10 +482,7 @@ pub fn create_invoice_signed(
     signature: &BytesN<64>,
 ) -> u64 {
     // Caller must be Manager or Admin
-    if !access_control::has_role(env, caller, Role::Manager) {
-        panic_with_error!(env, ContractError::NotAuthorized);
-    }
-    caller.require_auth();
+    access_control::require_permission(env, caller, access_control::FN_CREATE_INVOICE_SIGNED);
 
     // validate invoice creation
     validate_invoice_creation(env, merchant, description, amount, token, None);
@@ -229,6 +500,7 @@ pub fn create_invoice_signed(
 
     // Standard invoice creation
     let merchant_id: u64 = merchant::get_merchant_id(env, merchant);
+    enforce_creation_cooldown(env, merchant_id);
 
     let invoice_count: u64 = env
         .storage()
@@ -239,19 +511,10 @@ pub fn create_invoice_signed(
     let new_invoice_id = invoice_count + 1;
 
     let invoice = Invoice {
-        id: new_invoice_id,
-        description: description.clone(),
-        amount,
-        token: token.clone(),
-        status: InvoiceStatus::Pending,
-        merchant_id,
-        payer: None,
-        date_created: env.ledger().timestamp(),
-        date_paid: None,
-        amount_paid: 0,
-        amount_refunded: 0,
-        expires_at: None,
+        merchant_sequence: merchant::next_invoice_sequence(env, merchant_id),
+        ..new_pending_invoice(env, new_invoice_id, merchant_id, description, amount, token)
     };
+    let invoice = with_quote_snapshot(env, merchant, invoice);
 
     env.storage()
         .persistent()
@@ -259,6 +522,10 @@ pub fn create_invoice_signed(
     env.storage()
         .persistent()
         .set(&DataKey::InvoiceCount, &new_invoice_id);
+    add_merchant_invoice(env, merchant_id, new_invoice_id);
+    add_token_invoice(env, &invoice.token, new_invoice_id);
+    health::invoice_opened(env);
+    health::adjust_liability(env, &invoice.token, invoice.amount);
 
     // 7. Emit standardInvoiceCreated event
     events::publish_invoice_created_event(
@@ -272,73 +539,485 @@ pub fn create_invoice_signed(
     new_invoice_id
 }
 
-pub fn get_invoice(env: &Env, invoice_id: u64) -> Invoice {
+/// Creates an invoice whose terms the merchant and payer both signed off-chain,
+/// so a relayer can submit it without either party's on-chain authorization.
+/// The invoice starts pending with `payer` designated as the only payer.
+pub fn create_invoice_dual_signed(
+    env: &Env,
+    merchant: &Address,
+    payer: &Address,
+    terms: &InvoiceTerms,
+    merchant_sig: &BytesN<64>,
+    payer_sig: &BytesN<64>,
+    nonce: &BytesN<32>,
+) -> u64 {
+    validate_invoice_creation(
+        env,
+        merchant,
+        &terms.description,
+        terms.amount,
+        &terms.token,
+        terms.expires_at,
+    );
+
+    signature_util::verify_dual_signature(
+        env,
+        merchant,
+        payer,
+        terms,
+        nonce,
+        merchant_sig,
+        payer_sig,
+    );
+
+    let merchant_id: u64 = merchant::get_merchant_id(env, merchant);
+    enforce_creation_cooldown(env, merchant_id);
+
+    let invoice_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::InvoiceCount)
+        .unwrap_or(0);
+    let new_invoice_id = invoice_count + 1;
+
+    let invoice = Invoice {
+        expires_at: terms.expires_at,
+        designated_payer: Some(payer.clone()),
+        merchant_sequence: merchant::next_invoice_sequence(env, merchant_id),
+        ..new_pending_invoice(
+            env,
+            new_invoice_id,
+            merchant_id,
+            &terms.description,
+            terms.amount,
+            &terms.token,
+        )
+    };
+    let invoice = with_quote_snapshot(env, merchant, invoice);
+
     env.storage()
         .persistent()
-        .get(&DataKey::Invoice(invoice_id))
-        .unwrap_or_else(|| panic_with_error!(env, ContractError::InvoiceNotFound))
-}
+        .set(&DataKey::Invoice(new_invoice_id), &invoice);
+    env.storage()
+        .persistent()
+        .set(&DataKey::InvoiceCount, &new_invoice_id);
+    add_merchant_invoice(env, merchant_id, new_invoice_id);
+    add_token_invoice(env, &invoice.token, new_invoice_id);
+    add_payer_invoice(env, payer, new_invoice_id);
+    health::invoice_opened(env);
+    health::adjust_liability(env, &invoice.token, invoice.amount);
 
-pub fn check_invoice_refund_eligibility(env: &Env, merchant_address: &Address, invoice_id: u64) {
-    let invoice = get_invoice(env, invoice_id);
+    events::publish_invoice_created_event(
+        env,
+        new_invoice_id,
+        merchant.clone(),
+        terms.amount,
+        terms.token.clone(),
+    );
+    events::publish_invoice_payer_assigned_event(
+        env,
+        new_invoice_id,
+        merchant.clone(),
+        payer.clone(),
+        env.ledger().timestamp(),
+    );
 
-    let merchant_id = merchant::get_merchant_id(env, merchant_address);
+    new_invoice_id
+}
 
-    if invoice.merchant_id != merchant_id {
+pub fn create_split_invoice(
+    env: &Env,
+    creator: &Address,
+    parts: &Vec<(u64, i128)>,
+    token: &Address,
+    description: &String,
+) -> u64 {
+    creator.require_auth();
+
+    if parts.is_empty() {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    admin::assert_description_len(env, description);
+    if !merchant::is_merchant(env, creator) {
         panic_with_error!(env, ContractError::NotAuthorized);
     }
-
-    // check if the payer is available
-    if invoice.payer.is_none() {
-        panic_with_error!(env, ContractError::PayerNotAvailable);
+    let merchant_id: u64 = merchant::get_merchant_id(env, creator);
+    if !merchant::is_merchant_active(env, merchant_id) {
+        panic_with_error!(env, ContractError::MerchantNotActive);
     }
-
-    // check if invoice is paid
-    if invoice.status != InvoiceStatus::Paid {
-        panic_with_error!(env, ContractError::InvoiceNotPaid);
+    if !admin::is_accepted_token(env, token) {
+        panic_with_error!(env, ContractError::TokenNotAccepted);
     }
 
-    // Enforce refund window
-    if let Some(date_paid) = invoice.date_paid {
-        let elapsed = env.ledger().timestamp() - date_paid;
-        if elapsed > MAX_REFUND_DURATION {
-            panic_with_error!(env, ContractError::RefundPeriodExpired);
+    let mut total: i128 = 0;
+    for (part_merchant_id, part_amount) in parts.iter() {
+        if part_amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
         }
-    } else {
-        panic_with_error!(env, ContractError::InvoiceNotPaid);
+        if !merchant::is_merchant_active(env, part_merchant_id) {
+            panic_with_error!(env, ContractError::MerchantNotActive);
+        }
+        let part_merchant = merchant::get_merchant(env, part_merchant_id);
+        if !merchant::is_token_accepted_for_merchant(env, &part_merchant.address, token) {
+            panic_with_error!(env, ContractError::TokenNotAcceptedByMerchant);
+        }
+        total += part_amount;
     }
 
-    let amount_to_refund = invoice.amount - invoice.amount_refunded;
-    if amount_to_refund <= 0 {
+    let fee_amount = admin::get_fee(env, token);
+    if total <= fee_amount {
         panic_with_error!(env, ContractError::InvalidAmount);
     }
-}
-
-pub fn refund_invoice(env: &Env, merchant_address: &Address, invoice_id: u64) {
-    merchant_address.require_auth();
-
-    check_invoice_refund_eligibility(env, merchant_address, invoice_id);
-
-    // initiate refund
-    let invoice = get_invoice(env, invoice_id);
-    let amount_to_refund = invoice.amount - invoice.amount_refunded;
+    enforce_creation_cooldown(env, merchant_id);
 
-    let payer = invoice.payer.unwrap();
-    // transfer amount_to_refund from merchant account to payer
-    // check if merchant account balance for the token is sufficient
-    let merchant_account = merchant::get_merchant_account(env, invoice.merchant_id);
-    let token_client = TokenClient::new(env, &invoice.token);
-    let merchant_balance = token_client.balance(&merchant_account);
-    if merchant_balance < amount_to_refund {
-        panic_with_error!(env, ContractError::InsufficientBalance);
+    let invoice_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::InvoiceCount)
+        .unwrap_or(0);
+    let new_invoice_id = invoice_count + 1;
+    let invoice = Invoice {
+        parts: parts.clone(),
+        merchant_sequence: merchant::next_invoice_sequence(env, merchant_id),
+        ..new_pending_invoice(env, new_invoice_id, merchant_id, description, total, token)
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(new_invoice_id), &invoice);
+    env.storage()
+        .persistent()
+        .set(&DataKey::InvoiceCount, &new_invoice_id);
+    add_merchant_invoice(env, merchant_id, new_invoice_id);
+    add_token_invoice(env, &invoice.token, new_invoice_id);
+    health::invoice_opened(env);
+    health::adjust_liability(env, &invoice.token, invoice.amount);
+    events::publish_invoice_created_event(
+        env,
+        new_invoice_id,
+        creator.clone(),
+        total,
+        token.clone(),
+    );
+    new_invoice_id
+}
+
+pub fn get_invoice(env: &Env, invoice_id: u64) -> Invoice {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Invoice(invoice_id))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::InvoiceNotFound))
+}
+
+/// Statuses for `invoice_ids` in order, for cheap dashboard polling. Missing
+/// ids come back as `None`, or fail with `InvoiceNotFound` when `strict`.
+pub fn get_statuses(env: &Env, invoice_ids: &Vec<u64>, strict: bool) -> Vec<Option<InvoiceStatus>> {
+    if invoice_ids.len() > MAX_STATUS_BATCH {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let mut statuses = Vec::new(env);
+    for invoice_id in invoice_ids.iter() {
+        let status = env
+            .storage()
+            .persistent()
+            .get::<_, Invoice>(&DataKey::Invoice(invoice_id))
+            .map(|invoice| invoice.status);
+        if strict && status.is_none() {
+            panic_with_error!(env, ContractError::InvoiceNotFound);
+        }
+        statuses.push_back(status);
+    }
+    statuses
+}
+
+/// Returns up to `limit` invoices with ids from `start_id` upward, skipping ids
+/// with no stored invoice. Page onward from the last returned id plus one.
+pub fn get_invoices_page(env: &Env, start_id: u64, limit: u32) -> Vec<Invoice> {
+    if limit == 0 || limit > MAX_INVOICE_PAGE {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let invoice_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::InvoiceCount)
+        .unwrap_or(0);
+    let mut page: Vec<Invoice> = Vec::new(env);
+    let mut invoice_id = start_id.max(1);
+    while invoice_id <= invoice_count && page.len() < limit {
+        if let Some(invoice) = env
+            .storage()
+            .persistent()
+            .get::<_, Invoice>(&DataKey::Invoice(invoice_id))
+        {
+            page.push_back(invoice);
+        }
+        invoice_id += 1;
+    }
+    page
+}
+
+/// Human-friendly number like "ACME-0001", formatted from the merchant's
+/// current prefix and the invoice's merchant sequence.
+pub fn get_display_number(env: &Env, invoice_id: u64) -> String {
+    let invoice = get_invoice(env, invoice_id);
+    let prefix = env
+        .storage()
+        .persistent()
+        .get::<_, crate::types::Merchant>(&DataKey::Merchant(invoice.merchant_id))
+        .and_then(|merchant| merchant.invoice_prefix);
+    format_display_number(env, prefix, invoice.merchant_sequence)
+}
+
+/// `PREFIX-0001`, or just the zero-padded sequence without a prefix.
+fn format_display_number(env: &Env, prefix: Option<String>, sequence: u64) -> String {
+    let mut buf = [0u8; merchant::MAX_INVOICE_PREFIX_LEN as usize + 21];
+    let mut len = 0;
+    if let Some(prefix) = prefix {
+        let prefix_len = prefix.len() as usize;
+        prefix.copy_into_slice(&mut buf[..prefix_len]);
+        buf[prefix_len] = b'-';
+        len = prefix_len + 1;
+    }
+
+    let mut digits = [0u8; 20];
+    let mut digit_count = 0;
+    let mut rest = sequence;
+    while rest > 0 || digit_count < 4 {
+        digits[digit_count] = b'0' + (rest % 10) as u8;
+        rest /= 10;
+        digit_count += 1;
+    }
+    for i in (0..digit_count).rev() {
+        buf[len] = digits[i];
+        len += 1;
+    }
+
+    String::from_bytes(env, &buf[..len])
+}
+
+pub fn submit_delivery_proof(
+    env: &Env,
+    merchant_address: &Address,
+    invoice_id: u64,
+    proof_hash: &BytesN<32>,
+) {
+    merchant_address.require_auth();
+
+    let mut invoice = get_invoice(env, invoice_id);
+
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant_address.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::NotAuthorized));
+
+    if invoice.merchant_id != merchant_id {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    // A proof is recorded once, against a settled invoice.
+    if invoice.status != InvoiceStatus::Paid || invoice.delivery_proof.is_some() {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
+    invoice.delivery_proof = Some(proof_hash.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_delivery_proof_submitted_event(
+        env,
+        invoice_id,
+        merchant_address.clone(),
+        proof_hash.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn export_invoice(env: &Env, invoice_id: u64) -> Bytes {
+    get_invoice(env, invoice_id).to_xdr(env)
+}
+
+/// Approximates the storage footprint, in entry bytes, of a freshly created
+/// invoice of the given shape: the XDR-encoded key and value of its entry.
+/// A split is costed as two parts; each tag as one full-length Symbol.
+pub fn estimate_invoice_rent(
+    env: &Env,
+    description_len: u32,
+    has_splits: bool,
+    tag_count: u32,
+) -> u64 {
+    let address = env.current_contract_address();
+    let mut parts: Vec<(u64, i128)> = Vec::new(env);
+    if has_splits {
+        parts.push_back((1, 0));
+        parts.push_back((2, 0));
+    }
+    let template = Invoice {
+        parts,
+        ..new_pending_invoice(env, 0, 0, &String::from_str(env, ""), 0, &address)
+    };
+
+    // XDR pads string bodies to 4-byte words
+    let description_bytes = (description_len as u64).div_ceil(4) * 4;
+    DataKey::Invoice(0).to_xdr(env).len() as u64
+        + template.to_xdr(env).len() as u64
+        + description_bytes
+        + tag_count as u64 * RENT_BYTES_PER_TAG
+}
+
+pub fn import_invoice(env: &Env, admin: &Address, data: &Bytes, overwrite: bool) -> u64 {
+    core_component::assert_admin(env, admin);
+
+    let invoice = Invoice::from_xdr(env, data)
+        .unwrap_or_else(|_| panic_with_error!(env, ContractError::InvalidInvoiceData));
+
+    let key = DataKey::Invoice(invoice.id);
+    let existing: Option<Invoice> = env.storage().persistent().get(&key);
+    if let Some(existing) = existing {
+        if !overwrite {
+            panic_with_error!(env, ContractError::InvoiceAlreadyExists);
+        }
+        if let Some(previous_payer) = &existing.designated_payer {
+            remove_payer_invoice(env, previous_payer, invoice.id);
+        }
+        if existing.merchant_id != invoice.merchant_id {
+            remove_merchant_invoice(env, existing.merchant_id, invoice.id);
+            add_merchant_invoice(env, invoice.merchant_id, invoice.id);
+        }
+        move_token_invoice(env, &existing.token, &invoice.token, invoice.id);
+        health::track_invoice_transition(env, existing.status, invoice.status);
+        // Draft has no outcome bucket, so it stands in for "no record".
+        health::record_outcome(
+            env,
+            existing.merchant_id,
+            existing.status,
+            InvoiceStatus::Draft,
+        );
+        health::adjust_liability(env, &existing.token, -outstanding_amount(&existing));
+    } else {
+        add_merchant_invoice(env, invoice.merchant_id, invoice.id);
+        add_token_invoice(env, &invoice.token, invoice.id);
+        if health::is_open(invoice.status) {
+            health::invoice_opened(env);
+        }
+    }
+    health::record_outcome(
+        env,
+        invoice.merchant_id,
+        InvoiceStatus::Draft,
+        invoice.status,
+    );
+    health::adjust_liability(env, &invoice.token, outstanding_amount(&invoice));
+
+    if let Some(payer) = &invoice.designated_payer {
+        add_payer_invoice(env, payer, invoice.id);
+    }
+    env.storage().persistent().set(&key, &invoice);
+
+    // Keep newly created invoices from reusing an imported id.
+    let invoice_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::InvoiceCount)
+        .unwrap_or(0);
+    if invoice.id > invoice_count {
+        env.storage()
+            .persistent()
+            .set(&DataKey::InvoiceCount, &invoice.id);
+    }
+
+    events::publish_invoice_imported_event(
+        env,
+        invoice.id,
+        admin.clone(),
+        overwrite,
+        env.ledger().timestamp(),
+    );
+
+    invoice.id
+}
+
+/// `caller` must own the invoice, or be the admin or hold a role allowed to
+/// refund invoices.
+pub fn check_invoice_refund_eligibility(env: &Env, caller: &Address, invoice_id: u64) {
+    let invoice = get_invoice(env, invoice_id);
+
+    let is_owner = merchant::is_merchant(env, caller)
+        && invoice.merchant_id == merchant::get_merchant_id(env, caller);
+    if !is_owner && !access_control::has_permission(env, caller, access_control::FN_REFUND_INVOICE)
+    {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    // only a paid invoice can be refunded
+    if invoice.status != InvoiceStatus::Paid {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
+    // split invoices were paid out to several merchant accounts
+    if !invoice.parts.is_empty() {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
+    // check if the payer is available
+    if invoice.payer.is_none() {
+        panic_with_error!(env, ContractError::PayerNotAvailable);
+    }
+
+    // Enforce refund window
+    if let Some(date_paid) = invoice.date_paid {
+        let elapsed = env.ledger().timestamp() - date_paid;
+        if elapsed > MAX_REFUND_DURATION {
+            panic_with_error!(env, ContractError::RefundPeriodExpired);
+        }
+    } else {
+        panic_with_error!(env, ContractError::InvoiceNotPaid);
+    }
+
+    let amount_to_refund = invoice.amount - invoice.amount_refunded;
+    if amount_to_refund <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+}
+
+pub fn refund_invoice(env: &Env, caller: &Address, invoice_id: u64) {
+    caller.require_auth();
+
+    check_invoice_refund_eligibility(env, caller, invoice_id);
+
+    // initiate refund
+    let invoice = get_invoice(env, invoice_id);
+    let amount_to_refund = invoice.amount - invoice.amount_refunded;
+
+    let payer = invoice.payer.unwrap();
+    // transfer amount_to_refund from merchant account to payer
+    // check if merchant account balance for the token is sufficient
+    let merchant_account = merchant::get_merchant_account(env, invoice.merchant_id);
+    let token_client = TokenClient::new(env, &invoice.token);
+    let merchant_balance = token_client.balance(&merchant_account);
+    if merchant_balance < amount_to_refund {
+        panic_with_error!(env, ContractError::InsufficientBalance);
     }
     let refund_client = MerchantAccountRefundClient::new(env, &merchant_account);
     refund_client.refund(&invoice.token, &amount_to_refund, &payer);
+    report_balance_change(
+        env,
+        &token_client,
+        &merchant_account,
+        &invoice.token,
+        -amount_to_refund,
+    );
 
     // update invoice
     let mut invoice = get_invoice(env, invoice_id);
+    let previous_status = invoice.status;
     invoice.amount_refunded += amount_to_refund;
     invoice.status = InvoiceStatus::Refunded;
+    invoice.refunded_by = Some(caller.clone());
+    health::record_outcome(env, invoice.merchant_id, previous_status, invoice.status);
+    health::adjust_volume(env, &invoice.token, -amount_to_refund);
     env.storage()
         .persistent()
         .set(&DataKey::Invoice(invoice_id), &invoice);
@@ -365,7 +1044,7 @@ pub fn get_invoices(env: &Env, filter: InvoiceFilter) -> Vec<Invoice> {
             .persistent()
             .get::<_, Invoice>(&DataKey::Invoice(i))
         {
-            let mut matches = true;
+            let mut matches = !invoice.archived;
             if let Some(status) = filter.status {
                 if invoice.status as u32 != status {
                     matches = false;
@@ -420,6 +1099,10 @@ pub fn refund_invoice_partial(env: &Env, invoice_id: u64, amount: i128) {
         panic_with_error!(env, ContractError::InvalidInvoiceStatus);
     }
 
+    if !invoice.parts.is_empty() {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
     if let Some(date_paid) = invoice.date_paid {
         let elapsed = env.ledger().timestamp() - date_paid;
         if elapsed > MAX_REFUND_DURATION {
@@ -443,6 +1126,8 @@ pub fn refund_invoice_partial(env: &Env, invoice_id: u64, amount: i128) {
     } else {
         InvoiceStatus::PartiallyRefunded
     };
+    health::record_outcome(env, invoice.merchant_id, invoice.status, new_status);
+    health::adjust_volume(env, &invoice.token, -amount);
     invoice.status = new_status;
 
     // save invoice to storage
@@ -465,6 +1150,13 @@ pub fn refund_invoice_partial(env: &Env, invoice_id: u64, amount: i128) {
     // initiate refund
     let refund_client = MerchantAccountRefundClient::new(env, &merchant_account_addr);
     refund_client.refund(&invoice.token, &amount, &payer);
+    report_balance_change(
+        env,
+        &token_client,
+        &merchant_account_addr,
+        &invoice.token,
+        -amount,
+    );
 
     if total_refund == invoice.amount {
         events::publish_invoice_refunded_event(
@@ -486,6 +1178,116 @@ pub fn refund_invoice_partial(env: &Env, invoice_id: u64, amount: i128) {
     }
 }
 
+/// Transfers `amount` and confirms the recipient's balance grew by that much,
+/// less the token's declared transfer fee. Undeclared fee-on-transfer or
+/// misreporting tokens fail settlement instead of silently under-paying.
+pub fn try_settle_transfer(
+    env: &Env,
+    token_client: &TokenClient,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> bool {
+    settle_transfer_received(env, token_client, from, to, amount).is_some()
+}
+
+/// Like `try_settle_transfer`, returning the amount the recipient received.
+pub fn settle_transfer_received(
+    env: &Env,
+    token_client: &TokenClient,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Option<i128> {
+    if from == to {
+        return matches!(token_client.try_transfer(from, to, &amount), Ok(Ok(())))
+            .then_some(amount);
+    }
+    let balance_before = token_client.balance(to);
+    if !matches!(token_client.try_transfer(from, to, &amount), Ok(Ok(()))) {
+        return None;
+    }
+    let received = token_client.balance(to) - balance_before;
+    let transfer_fee_bps = admin::get_token_transfer_fee_bps(env, &token_client.address) as i128;
+    // Round the allowed loss up so tokens that round their fee up still pass.
+    let max_loss = (amount * transfer_fee_bps + 9_999) / 10_000;
+    (received <= amount && received >= amount - max_loss).then_some(received)
+}
+
+/// Returns an invoice left in `Settling` by an unexpected trap to a payable state.
+pub fn reset_settling(env: &Env, admin: &Address, invoice_id: u64) {
+    core_component::assert_admin(env, admin);
+
+    let mut invoice = get_invoice(env, invoice_id);
+    if invoice.status != InvoiceStatus::Settling {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
+    invoice.status = if invoice.amount_paid > 0 {
+        InvoiceStatus::PartiallyPaid
+    } else {
+        InvoiceStatus::Pending
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_invoice_settling_reset_event(
+        env,
+        invoice_id,
+        admin.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+// Reports a holder's new token balance so indexers can keep a running ledger of
+// merchant accounts and the contract's fee pool.
+fn report_balance_change(
+    env: &Env,
+    token_client: &TokenClient,
+    holder: &Address,
+    token: &Address,
+    delta: i128,
+) {
+    events::publish_balance_changed_event(
+        env,
+        holder.clone(),
+        token.clone(),
+        delta,
+        token_client.balance(holder),
+    );
+}
+
+/// Whether the invoice can be paid at the current ledger time: it must be open
+/// for payment and inside its `active_from`..`expires_at` window.
+pub fn is_payable_now(env: &Env, invoice_id: u64) -> bool {
+    let invoice = get_invoice(env, invoice_id);
+    if invoice.status != InvoiceStatus::Pending && invoice.status != InvoiceStatus::PartiallyPaid {
+        return false;
+    }
+    let now = env.ledger().timestamp();
+    if invoice
+        .active_from
+        .is_some_and(|active_from| now < active_from)
+    {
+        return false;
+    }
+    invoice.expires_at.is_none_or(|expires_at| now < expires_at)
+}
+
+/// Whether a paid invoice settled by its due date, or by its expiry when it has
+/// no due date: `None` while unpaid or when the invoice has neither.
+pub fn was_paid_on_time(env: &Env, invoice_id: u64) -> Option<bool> {
+    let invoice = get_invoice(env, invoice_id);
+    if invoice.status != InvoiceStatus::Paid {
+        return None;
+    }
+    match (invoice.date_paid, invoice.due_date.or(invoice.expires_at)) {
+        (Some(date_paid), Some(due_date)) => Some(date_paid <= due_date),
+        _ => None,
+    }
+}
+
 pub fn pay_invoices_batch(env: &Env, payer: &Address, invoice_ids: &Vec<u64>) {
     payer.require_auth();
     for invoice_id in invoice_ids.iter() {
@@ -498,7 +1300,7 @@ pub fn pay_invoice(env: &Env, payer: &Address, invoice_id: u64) -> i128 {
     if invoice.status != InvoiceStatus::Pending && invoice.status != InvoiceStatus::PartiallyPaid {
         panic_with_error!(env, ContractError::InvalidInvoiceStatus);
     }
-    let remaining_amount = invoice.amount - invoice.amount_paid;
+    let remaining_amount = amount_due(env, &invoice);
     if remaining_amount <= 0 {
         panic_with_error!(env, ContractError::InvalidInvoiceStatus);
     }
@@ -507,43 +1309,243 @@ pub fn pay_invoice(env: &Env, payer: &Address, invoice_id: u64) -> i128 {
 
 pub fn pay_invoice_partial(env: &Env, payer: &Address, invoice_id: u64, amount: i128) -> i128 {
     payer.require_auth();
+    settle_invoice_payment(env, payer, invoice_id, amount, None)
+}
 
-    if amount <= 0 {
-        panic_with_error!(env, ContractError::InvalidAmount);
+// Amount still owed on an invoice; nothing once it is closed.
+fn outstanding_amount(invoice: &Invoice) -> i128 {
+    if health::is_open(invoice.status) {
+        invoice.amount - invoice.amount_paid
+    } else {
+        0
     }
+}
 
-    let mut invoice = get_invoice(env, invoice_id);
-
-    if let Some(expires_at) = invoice.expires_at {
-        if env.ledger().timestamp() >= expires_at {
-            panic_with_error!(env, ContractError::InvoiceExpired);
-        }
+/// What settling the invoice now costs: the outstanding balance plus any late
+/// fee accrued so far; nothing once it is closed.
+pub fn get_amount_due(env: &Env, invoice_id: u64) -> i128 {
+    let invoice = get_invoice(env, invoice_id);
+    if health::is_open(invoice.status) {
+        amount_due(env, &invoice)
+    } else {
+        0
     }
+}
 
-    if invoice.status != InvoiceStatus::Pending && invoice.status != InvoiceStatus::PartiallyPaid {
-        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+fn amount_due(env: &Env, invoice: &Invoice) -> i128 {
+    invoice.amount + late_fee_on(env, invoice, invoice.amount) - invoice.amount_paid
+}
+
+// Late fee accrued on `amount` for each full day past the invoice's due date,
+// capped at the maximum late fee. Paying on the due date itself is on time.
+fn late_fee_on(env: &Env, invoice: &Invoice, amount: i128) -> i128 {
+    let (Some(due_date), Some(bps_per_day)) = (invoice.due_date, invoice.late_fee_bps_per_day)
+    else {
+        return 0;
+    };
+    let now = env.ledger().timestamp();
+    if now <= due_date {
+        return 0;
+    }
+    let days_overdue = ((now - due_date) / SECONDS_PER_DAY) as i128;
+    let bps = (bps_per_day as i128 * days_overdue).min(admin::get_max_late_fee_bps(env) as i128);
+    amount * bps / 10_000
+}
+
+/// Number of partial payments received by an invoice, excluding the settling one.
+pub fn get_partial_count(env: &Env, invoice_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PartialCount(invoice_id))
+        .unwrap_or(0)
+}
+
+// Shared payment path; callers authorize `payer` first. `converted_from` is the
+// invoice's original token when the payment is made in a quoted alternative,
+// which adds the conversion fee.
+fn settle_invoice_payment(
+    env: &Env,
+    payer: &Address,
+    invoice_id: u64,
+    amount: i128,
+    converted_from: Option<Address>,
+) -> i128 {
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let mut invoice = get_invoice(env, invoice_id);
+
+    if let Some(expires_at) = invoice.expires_at {
+        if env.ledger().timestamp() >= expires_at {
+            panic_with_error!(env, ContractError::InvoiceExpired);
+        }
+    }
+
+    if let Some(active_from) = invoice.active_from {
+        if env.ledger().timestamp() < active_from {
+            panic_with_error!(env, ContractError::InvoiceNotActive);
+        }
+    }
+
+    if invoice.status != InvoiceStatus::Pending && invoice.status != InvoiceStatus::PartiallyPaid {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
+    if let Some(designated_payer) = &invoice.designated_payer {
+        if designated_payer != payer {
+            panic_with_error!(env, ContractError::PayerNotAllowed);
+        }
+    }
+
+    if let Some(registry) = admin::get_kyc_registry(env) {
+        if !KycRegistryClient::new(env, &registry).is_verified(payer) {
+            panic_with_error!(env, ContractError::PayerNotVerified);
+        }
+    }
+
+    // Once overdue, the accrued late fee is folded into the amount and the balance
+    // must be settled in one payment, so the fee is charged exactly once.
+    let late_fee = late_fee_on(env, &invoice, invoice.amount);
+    if late_fee > 0 {
+        if invoice.amount_paid + amount != invoice.amount + late_fee {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        invoice.amount += late_fee;
+        health::adjust_liability(env, &invoice.token, late_fee);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Invoice(invoice_id), &invoice);
     }
 
     if invoice.amount_paid + amount > invoice.amount {
         panic_with_error!(env, ContractError::InvalidAmount);
     }
 
+    // A payment that settles the balance is always accepted; only payments that
+    // leave something outstanding count against the partial limit.
+    let is_partial = invoice.amount_paid + amount < invoice.amount;
+    let partial_count = get_partial_count(env, invoice_id);
+    let max_partials = admin::get_max_partials(env);
+    if is_partial && max_partials > 0 && partial_count >= max_partials {
+        panic_with_error!(env, ContractError::TooManyPartials);
+    }
+
     if !admin::is_accepted_token(env, &invoice.token) {
         panic_with_error!(env, ContractError::TokenNotAccepted);
     }
 
-    let fee_amount = admin::calculate_fee(env, &invoice.token, amount);
-    let merchant_amount = amount - fee_amount;
+    // Split invoices are settled in one full payment so every part is paid out together.
+    if !invoice.parts.is_empty() && amount != invoice.amount {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    if invoice.requires_confirmation {
+        // Two-phase invoices are funded in full and held until receipt is confirmed.
+        if converted_from.is_some() || amount != invoice.amount || invoice.amount_paid != 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        escrow::fund_escrow(env, payer, invoice, amount);
+        return 0;
+    }
+
+    let fee_amount = disburse_payment(env, payer, payer, invoice, amount, amount, converted_from);
+    if is_partial {
+        env.storage()
+            .persistent()
+            .set(&DataKey::PartialCount(invoice_id), &(partial_count + 1));
+    }
+    fee_amount
+}
+
+/// Pays `amount` of an invoice out of `source` to its merchant accounts and fee
+/// destination, then credits `credited` against the invoice for `payer`.
+/// `source` is the payer for direct payments and the contract when releasing
+/// escrow; `amount` and `credited` differ only when escrow received less than
+/// the invoice total because of a token transfer fee.
+pub fn disburse_payment(
+    env: &Env,
+    source: &Address,
+    payer: &Address,
+    mut invoice: Invoice,
+    amount: i128,
+    credited: i128,
+    converted_from: Option<Address>,
+) -> i128 {
+    let invoice_id = invoice.id;
+
+    // (merchant_id, merchant_account, gross, fee, dust, held) for each recipient
+    // of this payment; `dust` is left with the payer and `held` with the contract.
+    let mut payouts: Vec<(u64, Address, i128, i128, i128, i128)> = Vec::new(env);
+    for (share_merchant_id, gross) in payment_shares(env, &invoice, amount).iter() {
+        let (base_fee, conversion_fee, dust) = payment_fees(
+            env,
+            &invoice.token,
+            payer,
+            invoice.amount,
+            gross,
+            converted_from.as_ref(),
+        );
+        let fee = base_fee + conversion_fee;
+        payouts.push_back((
+            share_merchant_id,
+            merchant::get_merchant_account(env, share_merchant_id),
+            gross,
+            fee,
+            dust,
+            merchant::holdback_for(env, share_merchant_id, gross - fee - dust),
+        ));
+    }
+
+    // Hold the invoice in `Settling` while funds move so it can't be paid twice;
+    // a failed transfer panics, which reverts this write along with the rest.
+    let previous_status = invoice.status;
+    invoice.status = InvoiceStatus::Settling;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
 
     let token_client = token::TokenClient::new(env, &invoice.token);
-    let merchant_account_id = merchant::get_merchant_account(env, invoice.merchant_id);
 
-    token_client.transfer(payer, &merchant_account_id, &merchant_amount);
-    if fee_amount > 0 {
-        token_client.transfer(payer, env.current_contract_address(), &fee_amount);
+    let mut fee_amount: i128 = 0;
+    let mut dust_amount: i128 = 0;
+    let mut held_amount: i128 = 0;
+    let mut transferred = true;
+    for (_, merchant_account_id, gross, fee, dust, held) in payouts.iter() {
+        if !try_settle_transfer(
+            env,
+            &token_client,
+            source,
+            &merchant_account_id,
+            gross - fee - dust - held,
+        ) {
+            transferred = false;
+            break;
+        }
+        fee_amount += fee;
+        dust_amount += dust;
+        held_amount += held;
+    }
+    let contract_address = env.current_contract_address();
+    if transferred && held_amount > 0 && *source != contract_address {
+        transferred =
+            try_settle_transfer(env, &token_client, source, &contract_address, held_amount);
+    }
+    let fee_destination = admin::get_fee_destination(env, &invoice.token);
+    if transferred && fee_amount > 0 {
+        transferred = try_settle_transfer(env, &token_client, source, &fee_destination, fee_amount);
+    }
+    // Dust a direct payer never sent; escrowed funds hand it back.
+    if transferred && dust_amount > 0 && source != payer {
+        transferred = try_settle_transfer(env, &token_client, source, payer, dust_amount);
+    }
+    if !transferred {
+        panic_with_error!(env, ContractError::SettlementFailed);
     }
 
-    invoice.amount_paid += amount;
+    admin::record_fee_collected(env, &invoice.token, fee_amount);
+    invoice.amount_paid += credited;
+    invoice.fee_paid += fee_amount;
     if let Some(existing_payer) = &invoice.payer {
         if *existing_payer != *payer {
             panic_with_error!(env, ContractError::NotAuthorized);
@@ -555,34 +1557,172 @@ pub fn pay_invoice_partial(env: &Env, payer: &Address, invoice_id: u64, amount:
     if invoice.amount_paid == invoice.amount {
         invoice.status = InvoiceStatus::Paid;
         invoice.date_paid = Some(env.ledger().timestamp());
+        invoice.ledger_paid = Some(env.ledger().sequence());
+        invoice.receipt_hash = compute_receipt_hash(env, &invoice);
     } else {
         invoice.status = InvoiceStatus::PartiallyPaid;
     }
+    health::track_invoice_transition(env, previous_status, invoice.status);
+    health::record_outcome(env, invoice.merchant_id, previous_status, invoice.status);
+    health::adjust_liability(env, &invoice.token, -credited);
 
     env.storage()
         .persistent()
         .set(&DataKey::Invoice(invoice_id), &invoice);
 
-    events::publish_invoice_paid_event(
+    for (payout_merchant_id, merchant_account_id, gross, fee, dust, held) in payouts.iter() {
+        merchant::add_holdback(env, payout_merchant_id, &invoice.token, held);
+        report_balance_change(
+            env,
+            &token_client,
+            &merchant_account_id,
+            &invoice.token,
+            gross - fee - dust - held,
+        );
+        health::record_merchant_earnings(
+            env,
+            payout_merchant_id,
+            &invoice.token,
+            gross - fee - dust,
+        );
+    }
+    if fee_amount > 0 {
+        report_balance_change(
+            env,
+            &token_client,
+            &fee_destination,
+            &invoice.token,
+            fee_amount,
+        );
+    }
+
+    for (payout_merchant_id, merchant_account_id, gross, fee, dust, _) in payouts.iter() {
+        events::publish_invoice_paid_event(
+            env,
+            invoice_id,
+            payout_merchant_id,
+            merchant_account_id,
+            payer.clone(),
+            gross,
+            fee,
+            gross - fee - dust,
+            invoice.token.clone(),
+            invoice.receipt_hash.clone(),
+            env.ledger().timestamp(),
+        );
+    }
+
+    circuit_breaker::record_settled_volume(env, &invoice.token, amount);
+    health::adjust_volume(env, &invoice.token, amount);
+
+    if invoice.status == InvoiceStatus::Paid {
+        let threshold = admin::get_large_payment_threshold(env, &invoice.token);
+        if threshold > 0 && invoice.amount >= threshold {
+            events::publish_large_payment_event(
+                env,
+                invoice_id,
+                invoice.merchant_id,
+                payer.clone(),
+                invoice.token.clone(),
+                invoice.amount,
+                threshold,
+                env.ledger().timestamp(),
+            );
+        }
+        if let Some(callback) = &invoice.payer_callback {
+            notify_payer_callback(env, callback, invoice_id);
+        }
+    }
+
+    fee_amount
+}
+
+// Best-effort settlement notification. A failing callback is reported through
+// an event, or reverts the whole payment when the admin has asked for that.
+fn notify_payer_callback(env: &Env, callback: &Address, invoice_id: u64) {
+    let client = PayerCallbackClient::new(env, callback);
+    if matches!(client.try_on_payment_settled(&invoice_id), Ok(Ok(()))) {
+        return;
+    }
+    if admin::get_callback_revert_on_failure(env) {
+        panic_with_error!(env, ContractError::SettlementFailed);
+    }
+    events::publish_payer_callback_failed_event(
         env,
         invoice_id,
-        invoice.merchant_id,
-        merchant_account_id.clone(),
-        payer.clone(),
-        amount,
-        fee_amount,
-        merchant_amount,
-        invoice.token.clone(),
+        callback.clone(),
         env.ledger().timestamp(),
     );
+}
 
-    fee_amount
+pub fn set_payer_callback(
+    env: &Env,
+    merchant_address: &Address,
+    invoice_id: u64,
+    callback: Option<Address>,
+) {
+    merchant_address.require_auth();
+
+    let mut invoice = get_invoice(env, invoice_id);
+    assert_pending_invoice_owner(env, merchant_address, &invoice);
+
+    invoice.payer_callback = callback;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
 }
 
-pub fn void_invoice(env: &Env, merchant_address: &Address, invoice_id: u64) {
+/// Switches a pending invoice to two-phase settlement: payment is held in
+/// escrow until the payer confirms receipt (see `escrow`).
+pub fn set_requires_confirmation(
+    env: &Env,
+    merchant_address: &Address,
+    invoice_id: u64,
+    required: bool,
+) {
     merchant_address.require_auth();
 
     let mut invoice = get_invoice(env, invoice_id);
+    assert_pending_invoice_owner(env, merchant_address, &invoice);
+    if invoice.amount_paid != 0 {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
+    invoice.requires_confirmation = required;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+}
+
+/// Hashes `(invoice_id, payer, amount, fee_paid, token, date_paid)` of a settled invoice.
+pub fn compute_receipt_hash(env: &Env, invoice: &Invoice) -> Option<BytesN<32>> {
+    let payer = invoice.payer.clone()?;
+    let date_paid = invoice.date_paid?;
+    let terms = (
+        invoice.id,
+        payer,
+        invoice.amount,
+        invoice.fee_paid,
+        invoice.token.clone(),
+        date_paid,
+    );
+    Some(env.crypto().sha256(&terms.to_xdr(env)).into())
+}
+
+/// Checks `expected` against the stored receipt and against a fresh hash of the
+/// invoice's current terms, so a receipt whose invoice was altered fails.
+pub fn verify_receipt(env: &Env, invoice_id: u64, expected: &BytesN<32>) -> bool {
+    let invoice = get_invoice(env, invoice_id);
+    match (&invoice.receipt_hash, compute_receipt_hash(env, &invoice)) {
+        (Some(stored), Some(recomputed)) => stored == expected && recomputed == *expected,
+        _ => false,
+    }
+}
+
+pub fn void_invoice(env: &Env, merchant_address: &Address, invoice_id: u64) {
+    merchant_address.require_auth();
+
+    let invoice = get_invoice(env, invoice_id);
 
     let merchant_id: u64 = env
         .storage()
@@ -598,7 +1738,61 @@ pub fn void_invoice(env: &Env, merchant_address: &Address, invoice_id: u64) {
         panic_with_error!(env, ContractError::InvalidInvoiceStatus);
     }
 
+    cancel_pending(env, merchant_address, invoice);
+}
+
+/// Cancels the merchant's pending invoices, e.g. when they stop trading.
+/// Walks the merchant's invoice index from position `start`, reading at most
+/// `MAX_CANCEL_SCAN` invoices and cancelling at most `MAX_CANCEL_BATCH`.
+/// Returns the cancelled ids and the position to pass as `start` next time,
+/// or `None` once the end of the index is reached.
+pub fn cancel_all_pending(
+    env: &Env,
+    merchant_address: &Address,
+    start: u32,
+) -> (Vec<u64>, Option<u32>) {
+    merchant_address.require_auth();
+
+    let merchant_id = merchant::get_merchant_id(env, merchant_address);
+    let invoice_ids = get_invoices_by_merchant(env, merchant_id);
+    let end = invoice_ids.len().min(start.saturating_add(MAX_CANCEL_SCAN));
+    let mut cancelled: Vec<u64> = Vec::new(env);
+    let mut position = start;
+    while position < end && cancelled.len() < MAX_CANCEL_BATCH {
+        let invoice_id = invoice_ids.get_unchecked(position);
+        position += 1;
+        let Some(invoice) = env
+            .storage()
+            .persistent()
+            .get::<_, Invoice>(&DataKey::Invoice(invoice_id))
+        else {
+            continue;
+        };
+        if invoice.status != InvoiceStatus::Pending {
+            continue;
+        }
+        cancel_pending(env, merchant_address, invoice);
+        cancelled.push_back(invoice_id);
+    }
+    let next = if position < invoice_ids.len() {
+        Some(position)
+    } else {
+        None
+    };
+    (cancelled, next)
+}
+
+fn cancel_pending(env: &Env, merchant_address: &Address, mut invoice: Invoice) {
+    let invoice_id = invoice.id;
     invoice.status = InvoiceStatus::Cancelled;
+    health::invoice_closed(env);
+    health::record_outcome(
+        env,
+        invoice.merchant_id,
+        InvoiceStatus::Pending,
+        invoice.status,
+    );
+    health::adjust_liability(env, &invoice.token, -invoice.amount);
 
     env.storage()
         .persistent()
@@ -612,6 +1806,35 @@ pub fn void_invoice(env: &Env, merchant_address: &Address, invoice_id: u64) {
     );
 }
 
+/// Records a pending or expired invoice as uncollectable. Unlike voiding, this
+/// keeps a separate accounting category, and the invoice can't be paid after.
+pub fn write_off_invoice(env: &Env, merchant_address: &Address, invoice_id: u64) {
+    merchant_address.require_auth();
+
+    let mut invoice = get_invoice(env, invoice_id);
+    if invoice.merchant_id != merchant::get_merchant_id(env, merchant_address) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+    let previous_status = invoice.status;
+    if previous_status != InvoiceStatus::Pending && previous_status != InvoiceStatus::Expired {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
+    let now = env.ledger().timestamp();
+    invoice.status = InvoiceStatus::WrittenOff;
+    invoice.written_off_at = Some(now);
+    health::track_invoice_transition(env, previous_status, invoice.status);
+    health::record_outcome(env, invoice.merchant_id, previous_status, invoice.status);
+    if previous_status == InvoiceStatus::Pending {
+        health::adjust_liability(env, &invoice.token, -invoice.amount);
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_invoice_written_off_event(env, invoice_id, merchant_address.clone(), now);
+}
+
 pub fn amend_invoice(
     env: &Env,
     merchant_address: &Address,
@@ -636,17 +1859,26 @@ pub fn amend_invoice(
     if invoice.status != InvoiceStatus::Pending {
         panic_with_error!(env, ContractError::InvalidInvoiceStatus);
     }
+    assert_within_edit_window(env, &invoice);
 
     let old_amount = invoice.amount;
 
+    // A split invoice's amount is the sum of its parts and can't be amended directly.
+    if new_amount.is_some() && !invoice.parts.is_empty() {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
     if let Some(amount) = new_amount {
         if amount <= 0 {
             panic_with_error!(env, ContractError::InvalidAmount);
         }
         invoice.amount = amount;
+        health::adjust_liability(env, &invoice.token, amount - old_amount);
+        invoice = with_quote_snapshot(env, merchant_address, invoice);
     }
 
     if let Some(description) = new_description {
+        admin::assert_description_len(env, &description);
         invoice.description = description;
     }
 
@@ -663,3 +1895,1016 @@ pub fn amend_invoice(
         env.ledger().timestamp(),
     );
 }
+
+pub fn record_reminder(env: &Env, merchant_address: &Address, invoice_id: u64) {
+    merchant_address.require_auth();
+
+    let mut invoice = get_invoice(env, invoice_id);
+
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant_address.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::NotAuthorized));
+
+    if invoice.merchant_id != merchant_id {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
+    invoice.reminder_count += 1;
+    invoice.last_reminder_at = env.ledger().timestamp();
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_invoice_reminder_recorded_event(
+        env,
+        invoice_id,
+        merchant_address.clone(),
+        invoice.reminder_count,
+        invoice.last_reminder_at,
+    );
+}
+
+pub fn assign_payer(env: &Env, merchant_address: &Address, invoice_id: u64, payer: &Address) {
+    merchant_address.require_auth();
+    set_designated_payer(env, merchant_address, invoice_id, payer);
+}
+
+/// Designates a payer on each listed invoice in one call, e.g. for a billing
+/// run. The whole batch reverts if any invoice is not a pending invoice owned
+/// by the merchant.
+pub fn assign_payers(env: &Env, merchant_address: &Address, assignments: &Vec<(u64, Address)>) {
+    merchant_address.require_auth();
+
+    if assignments.len() > MAX_PAYER_ASSIGN_BATCH {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    for (invoice_id, payer) in assignments.iter() {
+        set_designated_payer(env, merchant_address, invoice_id, &payer);
+    }
+}
+
+fn set_designated_payer(env: &Env, merchant_address: &Address, invoice_id: u64, payer: &Address) {
+    let mut invoice = get_invoice(env, invoice_id);
+    assert_pending_invoice_owner(env, merchant_address, &invoice);
+
+    if let Some(previous_payer) = &invoice.designated_payer {
+        remove_payer_invoice(env, previous_payer, invoice_id);
+    }
+
+    add_payer_invoice(env, payer, invoice_id);
+
+    invoice.designated_payer = Some(payer.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_invoice_payer_assigned_event(
+        env,
+        invoice_id,
+        merchant_address.clone(),
+        payer.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn clear_payer(env: &Env, merchant_address: &Address, invoice_id: u64) {
+    merchant_address.require_auth();
+
+    let mut invoice = get_invoice(env, invoice_id);
+    assert_pending_invoice_owner(env, merchant_address, &invoice);
+
+    if let Some(previous_payer) = &invoice.designated_payer {
+        remove_payer_invoice(env, previous_payer, invoice_id);
+    }
+
+    invoice.designated_payer = None;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_invoice_payer_cleared_event(
+        env,
+        invoice_id,
+        merchant_address.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+/// Lets the designated payer of a pending invoice hand the right to pay it to
+/// `new_payer`, e.g. to gift it.
+pub fn transfer_invoice_payer(
+    env: &Env,
+    current_payer: &Address,
+    invoice_id: u64,
+    new_payer: &Address,
+) {
+    current_payer.require_auth();
+
+    let mut invoice = get_invoice(env, invoice_id);
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+    match &invoice.designated_payer {
+        None => panic_with_error!(env, ContractError::PayerNotAvailable),
+        Some(designated_payer) if designated_payer != current_payer => {
+            panic_with_error!(env, ContractError::PayerNotAllowed)
+        }
+        Some(_) => {}
+    }
+
+    remove_payer_invoice(env, current_payer, invoice_id);
+    add_payer_invoice(env, new_payer, invoice_id);
+
+    invoice.designated_payer = Some(new_payer.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_invoice_payer_transferred_event(
+        env,
+        invoice_id,
+        current_payer.clone(),
+        new_payer.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+/// Returns the ids of unpaid invoices that `payer` is the designated payer of.
+pub fn get_payer_outstanding_invoices(env: &Env, payer: &Address) -> Vec<u64> {
+    let payer_invoices: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PayerInvoices(payer.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+    let mut outstanding: Vec<u64> = Vec::new(env);
+    for invoice_id in payer_invoices.iter() {
+        let invoice = get_invoice(env, invoice_id);
+        if invoice.status == InvoiceStatus::Pending
+            || invoice.status == InvoiceStatus::PartiallyPaid
+        {
+            outstanding.push_back(invoice_id);
+        }
+    }
+    outstanding
+}
+
+/// Settles `payer`'s outstanding invoices from `merchant_id` in `token`, oldest
+/// first, until `max_amount` runs out. The last affordable invoice is paid
+/// partially; split invoices, which must be paid in full, are skipped instead.
+/// `payer`'s outstanding invoice ids for this merchant and token, in ascending
+/// (creation) order.
+pub fn get_oldest_outstanding(
+    env: &Env,
+    payer: &Address,
+    merchant_id: u64,
+    token: &Address,
+) -> Vec<u64> {
+    let mut candidates: Vec<u64> = Vec::new(env);
+    for invoice_id in get_payer_outstanding_invoices(env, payer).iter() {
+        let invoice = get_invoice(env, invoice_id);
+        if invoice.merchant_id != merchant_id || invoice.token != *token {
+            continue;
+        }
+        let mut index = candidates.len();
+        while index > 0 && candidates.get_unchecked(index - 1) > invoice_id {
+            index -= 1;
+        }
+        candidates.insert(index, invoice_id);
+    }
+    candidates
+}
+
+pub fn pay_oldest(
+    env: &Env,
+    payer: &Address,
+    merchant_id: u64,
+    max_amount: i128,
+    token: &Address,
+) -> Vec<PaymentReceipt> {
+    payer.require_auth();
+
+    if max_amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let candidates = get_oldest_outstanding(env, payer, merchant_id, token);
+    let mut budget = max_amount;
+    let mut receipts: Vec<PaymentReceipt> = Vec::new(env);
+    for invoice_id in candidates.iter() {
+        if budget == 0 {
+            break;
+        }
+        if !is_payable_now(env, invoice_id) {
+            continue;
+        }
+
+        let invoice = get_invoice(env, invoice_id);
+        let late_fee = late_fee_on(env, &invoice, invoice.amount);
+        let remaining = invoice.amount + late_fee - invoice.amount_paid;
+        let amount = if budget >= remaining {
+            remaining
+        } else if invoice.parts.is_empty() && !invoice.requires_confirmation && late_fee == 0 {
+            budget
+        } else {
+            continue;
+        };
+
+        let fee = settle_invoice_payment(env, payer, invoice_id, amount, None);
+        budget -= amount;
+
+        let invoice = get_invoice(env, invoice_id);
+        receipts.push_back(PaymentReceipt {
+            invoice_id,
+            amount,
+            fee,
+            status: invoice.status,
+            receipt_hash: invoice.receipt_hash,
+        });
+    }
+    receipts
+}
+
+pub fn set_payer_note(
+    env: &Env,
+    merchant_address: &Address,
+    invoice_id: u64,
+    payer_note: Option<String>,
+) {
+    merchant_address.require_auth();
+
+    let mut invoice = get_invoice(env, invoice_id);
+    assert_pending_invoice_owner(env, merchant_address, &invoice);
+    assert_payer_note_len(env, &payer_note);
+
+    invoice.payer_note = payer_note;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_payer_note_set_event(
+        env,
+        invoice_id,
+        merchant_address.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+// Rejects a creation that follows the merchant's previous one too closely,
+// then records this creation's timestamp. A zero cooldown stores nothing.
+fn enforce_creation_cooldown(env: &Env, merchant_id: u64) {
+    let cooldown = admin::get_merchant_cooldown(env);
+    if cooldown == 0 {
+        return;
+    }
+
+    let now = env.ledger().timestamp();
+    let key = DataKey::LastInvoiceAt(merchant_id);
+    if let Some(last_created) = env.storage().persistent().get::<_, u64>(&key) {
+        if now < last_created.saturating_add(cooldown) {
+            panic_with_error!(env, ContractError::Cooldown);
+        }
+    }
+    env.storage().persistent().set(&key, &now);
+}
+
+/// Attaches or clears an encrypted blob on a pending invoice. The contract never
+/// interprets the bytes; it only bounds their size.
+pub fn set_encrypted_data(
+    env: &Env,
+    merchant_address: &Address,
+    invoice_id: u64,
+    encrypted_data: Option<Bytes>,
+) {
+    merchant_address.require_auth();
+
+    let mut invoice = get_invoice(env, invoice_id);
+    assert_pending_invoice_owner(env, merchant_address, &invoice);
+    if let Some(data) = &encrypted_data {
+        if data.is_empty() || data.len() > MAX_ENCRYPTED_DATA_LEN {
+            panic_with_error!(env, ContractError::InvalidInvoiceData);
+        }
+    }
+
+    invoice.encrypted_data = encrypted_data;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_encrypted_data_set_event(
+        env,
+        invoice_id,
+        merchant_address.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+fn assert_payer_note_len(env: &Env, payer_note: &Option<String>) {
+    if let Some(payer_note) = payer_note {
+        if payer_note.len() > MAX_PAYER_NOTE_LEN {
+            panic_with_error!(env, ContractError::DescriptionTooLong);
+        }
+    }
+}
+
+// Invoices become immutable once the configured edit window after creation has
+// passed.
+fn assert_within_edit_window(env: &Env, invoice: &Invoice) {
+    let window = admin::get_edit_window(env);
+    if window > 0 && env.ledger().timestamp() >= invoice.date_created.saturating_add(window) {
+        panic_with_error!(env, ExtendedError::EditWindowClosed);
+    }
+}
+
+fn assert_pending_invoice_owner(env: &Env, merchant_address: &Address, invoice: &Invoice) {
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant_address.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::NotAuthorized));
+
+    if invoice.merchant_id != merchant_id {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+}
+
+/// Ids of every invoice the merchant created or was imported under, oldest first.
+pub fn get_invoices_by_merchant(env: &Env, merchant_id: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::MerchantInvoices(merchant_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn add_merchant_invoice(env: &Env, merchant_id: u64, invoice_id: u64) {
+    let mut merchant_invoices = get_invoices_by_merchant(env, merchant_id);
+    merchant_invoices.push_back(invoice_id);
+    env.storage().persistent().set(
+        &ConfigKey::MerchantInvoices(merchant_id),
+        &merchant_invoices,
+    );
+}
+
+fn remove_merchant_invoice(env: &Env, merchant_id: u64, invoice_id: u64) {
+    let mut merchant_invoices = get_invoices_by_merchant(env, merchant_id);
+    if let Some(index) = merchant_invoices.first_index_of(invoice_id) {
+        merchant_invoices.remove(index);
+        env.storage().persistent().set(
+            &ConfigKey::MerchantInvoices(merchant_id),
+            &merchant_invoices,
+        );
+    }
+}
+
+/// Returns up to `limit` ids of invoices denominated in `token`, skipping the
+/// first `start` entries of the index, oldest first.
+pub fn get_invoices_by_token(env: &Env, token: &Address, start: u32, limit: u32) -> Vec<u64> {
+    if limit == 0 || limit > MAX_INVOICE_PAGE {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let token_invoices = get_token_invoices(env, token);
+    if start >= token_invoices.len() {
+        return Vec::new(env);
+    }
+    let end = token_invoices.len().min(start.saturating_add(limit));
+    token_invoices.slice(start..end)
+}
+
+fn get_token_invoices(env: &Env, token: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::TokenInvoices(token.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn add_token_invoice(env: &Env, token: &Address, invoice_id: u64) {
+    let mut token_invoices = get_token_invoices(env, token);
+    token_invoices.push_back(invoice_id);
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::TokenInvoices(token.clone()), &token_invoices);
+}
+
+fn move_token_invoice(env: &Env, from: &Address, to: &Address, invoice_id: u64) {
+    if from == to {
+        return;
+    }
+    let mut token_invoices = get_token_invoices(env, from);
+    if let Some(index) = token_invoices.first_index_of(invoice_id) {
+        token_invoices.remove(index);
+        env.storage()
+            .persistent()
+            .set(&ConfigKey::TokenInvoices(from.clone()), &token_invoices);
+    }
+    add_token_invoice(env, to, invoice_id);
+}
+
+fn add_payer_invoice(env: &Env, payer: &Address, invoice_id: u64) {
+    let key = DataKey::PayerInvoices(payer.clone());
+    let mut payer_invoices: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    payer_invoices.push_back(invoice_id);
+    env.storage().persistent().set(&key, &payer_invoices);
+}
+
+fn remove_payer_invoice(env: &Env, payer: &Address, invoice_id: u64) {
+    let key = DataKey::PayerInvoices(payer.clone());
+    let mut payer_invoices: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    if let Some(index) = payer_invoices.first_index_of(invoice_id) {
+        payer_invoices.remove(index);
+        env.storage().persistent().set(&key, &payer_invoices);
+    }
+}
+
+/// Returns the ids of `Pending` invoices that have gone at least `interval`
+/// seconds without a reminder (counting from creation if none was sent yet).
+/// Only the `limit` ids from `start_id` upward are checked; page onward from
+/// `start_id + limit`.
+pub fn get_invoices_due_for_reminder(
+    env: &Env,
+    interval: u64,
+    start_id: u64,
+    limit: u32,
+) -> Vec<u64> {
+    if limit == 0 || limit > MAX_INVOICE_PAGE {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let invoice_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::InvoiceCount)
+        .unwrap_or(0);
+    let now = env.ledger().timestamp();
+    let mut due: Vec<u64> = Vec::new(env);
+    let start_id = start_id.max(1);
+    let end_id = invoice_count.min(start_id.saturating_add(limit as u64 - 1));
+    for i in start_id..=end_id {
+        if let Some(invoice) = env
+            .storage()
+            .persistent()
+            .get::<_, Invoice>(&DataKey::Invoice(i))
+        {
+            if invoice.status != InvoiceStatus::Pending {
+                continue;
+            }
+            let last_contact = if invoice.reminder_count > 0 {
+                invoice.last_reminder_at
+            } else {
+                invoice.date_created
+            };
+            if now >= last_contact.saturating_add(interval) {
+                due.push_back(invoice.id);
+            }
+        }
+    }
+    due
+}
+
+pub fn change_invoice_token(
+    env: &Env,
+    merchant_address: &Address,
+    invoice_id: u64,
+    new_token: &Address,
+) {
+    merchant_address.require_auth();
+
+    let mut invoice = get_invoice(env, invoice_id);
+
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant_address.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::NotAuthorized));
+
+    if invoice.merchant_id != merchant_id {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+    assert_within_edit_window(env, &invoice);
+
+    if !admin::is_accepted_token(env, new_token) {
+        panic_with_error!(env, ContractError::TokenNotAccepted);
+    }
+    if !merchant::is_token_accepted_for_merchant(env, merchant_address, new_token) {
+        panic_with_error!(env, ContractError::TokenNotAcceptedByMerchant);
+    }
+    for (part_merchant_id, _) in invoice.parts.iter() {
+        let part_merchant = merchant::get_merchant(env, part_merchant_id);
+        if !merchant::is_token_accepted_for_merchant(env, &part_merchant.address, new_token) {
+            panic_with_error!(env, ContractError::TokenNotAcceptedByMerchant);
+        }
+    }
+
+    // Re-check the amount against the fee configured for the new token.
+    let fee_amount = admin::get_fee(env, new_token);
+    if invoice.amount <= fee_amount {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let old_token = invoice.token.clone();
+    invoice.token = new_token.clone();
+    health::adjust_liability(env, &old_token, -invoice.amount);
+    health::adjust_liability(env, new_token, invoice.amount);
+    move_token_invoice(env, &old_token, new_token, invoice_id);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_invoice_updated_event(
+        env,
+        invoice_id,
+        merchant_address.clone(),
+        old_token,
+        new_token.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+/// Cancels the merchant's pending single-merchant invoices in `old_token` and
+/// recreates each one in `new_token`, re-validating the amount against the new
+/// token's fee. Walks the merchant's invoice index from position `start`,
+/// reading at most `MAX_REISSUE_SCAN` invoices and moving at most
+/// `MAX_REISSUE_BATCH`. Returns the new ids and the position to pass as `start`
+/// next time, or `None` once the end of the index is reached. Overdue invoices
+/// are left for maintenance.
+pub fn reissue_merchant_invoices(
+    env: &Env,
+    merchant_address: &Address,
+    old_token: &Address,
+    new_token: &Address,
+    start: u32,
+) -> (Vec<u64>, Option<u32>) {
+    merchant_address.require_auth();
+
+    if old_token == new_token {
+        panic_with_error!(env, ContractError::InvalidInvoiceData);
+    }
+    let merchant_id = merchant::get_merchant_id(env, merchant_address);
+    let invoice_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::InvoiceCount)
+        .unwrap_or(0);
+    let now = env.ledger().timestamp();
+
+    let invoice_ids = get_invoices_by_merchant(env, merchant_id);
+    let end = invoice_ids
+        .len()
+        .min(start.saturating_add(MAX_REISSUE_SCAN));
+
+    let mut reissued: Vec<u64> = Vec::new(env);
+    let mut next_invoice_id = invoice_count + 1;
+    let mut position = start;
+    while position < end && reissued.len() < MAX_REISSUE_BATCH {
+        let invoice_id = invoice_ids.get_unchecked(position);
+        position += 1;
+        let Some(mut invoice) = env
+            .storage()
+            .persistent()
+            .get::<_, Invoice>(&DataKey::Invoice(invoice_id))
+        else {
+            continue;
+        };
+        let overdue = invoice
+            .expires_at
+            .is_some_and(|expires_at| now >= expires_at);
+        if invoice.status != InvoiceStatus::Pending
+            || invoice.token != *old_token
+            || !invoice.parts.is_empty()
+            || overdue
+        {
+            continue;
+        }
+        validate_invoice_creation(
+            env,
+            merchant_address,
+            &invoice.description,
+            invoice.amount,
+            new_token,
+            invoice.expires_at,
+        );
+
+        let replacement = Invoice {
+            id: next_invoice_id,
+            token: new_token.clone(),
+            date_created: now,
+            ledger_created: env.ledger().sequence(),
+            quoted_amounts: Map::new(env),
+            quote_valid_until: 0,
+            reminder_count: 0,
+            last_reminder_at: 0,
+            merchant_sequence: merchant::next_invoice_sequence(env, invoice.merchant_id),
+            reissued_from: Some(invoice_id),
+            ..invoice.clone()
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Invoice(next_invoice_id), &replacement);
+        add_merchant_invoice(env, merchant_id, next_invoice_id);
+        add_token_invoice(env, new_token, next_invoice_id);
+        if let Some(payer) = &invoice.designated_payer {
+            remove_payer_invoice(env, payer, invoice_id);
+            add_payer_invoice(env, payer, next_invoice_id);
+        }
+
+        invoice.status = InvoiceStatus::Cancelled;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Invoice(invoice_id), &invoice);
+        health::record_outcome(
+            env,
+            invoice.merchant_id,
+            InvoiceStatus::Pending,
+            invoice.status,
+        );
+        health::adjust_liability(env, old_token, -invoice.amount);
+        health::adjust_liability(env, new_token, replacement.amount);
+
+        events::publish_invoice_cancelled_event(env, invoice_id, merchant_address.clone(), now);
+        events::publish_invoice_created_event(
+            env,
+            next_invoice_id,
+            merchant_address.clone(),
+            replacement.amount,
+            new_token.clone(),
+        );
+        events::publish_invoice_reissued_event(
+            env,
+            invoice_id,
+            next_invoice_id,
+            merchant_address.clone(),
+            new_token.clone(),
+            now,
+        );
+        reissued.push_back(next_invoice_id);
+        next_invoice_id += 1;
+    }
+
+    if !reissued.is_empty() {
+        env.storage()
+            .persistent()
+            .set(&DataKey::InvoiceCount, &(next_invoice_id - 1));
+    }
+    let next = if position < invoice_ids.len() {
+        Some(position)
+    } else {
+        None
+    };
+    (reissued, next)
+}
+
+/// Opens a fresh pending copy of an invoice that just expired, shifted so its
+/// payable window starts now and lasts as long as the original's did. Returns
+/// `None` and leaves the invoice simply expired when the merchant is no longer
+/// active, the token is no longer accepted or the shifted window overflows, so
+/// one bad invoice cannot abort a maintenance run.
+pub fn reissue_expired_invoice(env: &Env, invoice: &Invoice) -> Option<u64> {
+    if !merchant::is_merchant_active(env, invoice.merchant_id)
+        || !admin::is_accepted_token(env, &invoice.token)
+    {
+        return None;
+    }
+    let now = env.ledger().timestamp();
+    let shift = now.checked_sub(invoice.date_created)?;
+    let expires_at = shift_timestamp(invoice.expires_at, shift)?;
+    let active_from = shift_timestamp(invoice.active_from, shift)?;
+    let due_date = shift_timestamp(invoice.due_date, shift)?;
+
+    let invoice_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::InvoiceCount)
+        .unwrap_or(0);
+    let new_invoice_id = invoice_count + 1;
+
+    let replacement = Invoice {
+        id: new_invoice_id,
+        status: InvoiceStatus::Pending,
+        date_created: now,
+        ledger_created: env.ledger().sequence(),
+        expires_at,
+        active_from,
+        due_date,
+        quoted_amounts: Map::new(env),
+        quote_valid_until: 0,
+        reminder_count: 0,
+        last_reminder_at: 0,
+        merchant_sequence: merchant::next_invoice_sequence(env, invoice.merchant_id),
+        reissued_from: Some(invoice.id),
+        ..invoice.clone()
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(new_invoice_id), &replacement);
+    env.storage()
+        .persistent()
+        .set(&DataKey::InvoiceCount, &new_invoice_id);
+    add_merchant_invoice(env, invoice.merchant_id, new_invoice_id);
+    add_token_invoice(env, &replacement.token, new_invoice_id);
+    if let Some(payer) = &invoice.designated_payer {
+        remove_payer_invoice(env, payer, invoice.id);
+        add_payer_invoice(env, payer, new_invoice_id);
+    }
+    health::invoice_opened(env);
+    health::adjust_liability(env, &replacement.token, replacement.amount);
+
+    let merchant_address = merchant::get_merchant(env, invoice.merchant_id).address;
+    events::publish_invoice_created_event(
+        env,
+        new_invoice_id,
+        merchant_address.clone(),
+        replacement.amount,
+        replacement.token.clone(),
+    );
+    events::publish_invoice_reissued_event(
+        env,
+        invoice.id,
+        new_invoice_id,
+        merchant_address,
+        replacement.token.clone(),
+        now,
+    );
+    Some(new_invoice_id)
+}
+
+// `Some(None)` for an unset timestamp, `None` if shifting a set one overflows.
+fn shift_timestamp(timestamp: Option<u64>, shift: u64) -> Option<Option<u64>> {
+    match timestamp {
+        Some(timestamp) => timestamp.checked_add(shift).map(Some),
+        None => Some(None),
+    }
+}
+
+/// Records merchant-quoted amounts for paying the invoice in other tokens.
+/// The snapshot is honored until `valid_until`; after that it must be re-quoted.
+pub fn set_invoice_quotes(
+    env: &Env,
+    merchant_address: &Address,
+    invoice_id: u64,
+    quotes: &Map<Address, i128>,
+    valid_until: u64,
+) {
+    merchant_address.require_auth();
+
+    let mut invoice = get_invoice(env, invoice_id);
+    assert_pending_invoice_owner(env, merchant_address, &invoice);
+
+    // Split parts are denominated in the invoice token and can't be re-quoted.
+    if !invoice.parts.is_empty() {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
+    if valid_until <= env.ledger().timestamp() {
+        panic_with_error!(env, ContractError::QuoteExpired);
+    }
+
+    for (token, amount) in quotes.iter() {
+        if !admin::is_accepted_token(env, &token) {
+            panic_with_error!(env, ContractError::TokenNotAccepted);
+        }
+        if !merchant::is_token_accepted_for_merchant(env, merchant_address, &token) {
+            panic_with_error!(env, ContractError::TokenNotAcceptedByMerchant);
+        }
+        if amount <= 0 || amount <= admin::calculate_tiered_fee(env, &token, amount, amount) {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+    }
+
+    invoice.quoted_amounts = quotes.clone();
+    invoice.quote_valid_until = valid_until;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_invoice_quotes_set_event(
+        env,
+        invoice_id,
+        merchant_address.clone(),
+        valid_until,
+        env.ledger().timestamp(),
+    );
+}
+
+/// Settles the invoice in full using the quoted amount for `token`, re-quoting
+/// at the current conversion rate once the snapshot has expired. The invoice is
+/// re-denominated in `token` so refunds and receipts reflect what was paid.
+pub fn pay_invoice_with_quote(
+    env: &Env,
+    payer: &Address,
+    invoice_id: u64,
+    token: &Address,
+) -> i128 {
+    payer.require_auth();
+
+    let mut invoice = get_invoice(env, invoice_id);
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
+    let quoted_amount = quoted_amount_for(env, &invoice, token);
+
+    let original_token = invoice.token.clone();
+    health::adjust_liability(env, &original_token, -invoice.amount);
+    health::adjust_liability(env, token, quoted_amount);
+    invoice.token = token.clone();
+    invoice.amount = quoted_amount;
+    move_token_invoice(env, &original_token, token, invoice_id);
+    invoice.quoted_amounts = Map::new(env);
+    invoice.quote_valid_until = 0;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    let amount_due = quoted_amount + late_fee_on(env, &invoice, quoted_amount);
+    settle_invoice_payment(env, payer, invoice_id, amount_due, Some(original_token))
+}
+
+/// What `merchant_id` would receive from a fully paid invoice of `amount` in
+/// `token` under the current fee, dust and holdback settings, assuming a payer
+/// without a fee exemption.
+pub fn preview_net(env: &Env, merchant_id: u64, amount: i128, token: &Address) -> i128 {
+    merchant::get_merchant(env, merchant_id);
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    if !admin::is_accepted_token(env, token) {
+        panic_with_error!(env, ContractError::TokenNotAccepted);
+    }
+
+    let fee = admin::calculate_tiered_fee(env, token, amount, amount);
+    let dust = admin::calculate_dust(env, token, amount, amount, None).min(amount - fee);
+    let net = amount - fee - dust;
+    net - merchant::holdback_for(env, merchant_id, net)
+}
+
+/// Previews what settling the invoice's remaining balance in `pay_token` costs
+/// `payer`, using the same fee rules as the payment path. Fees are withheld
+/// from the merchant's share, so `total` is what leaves the payer's wallet.
+pub fn quote_payment(
+    env: &Env,
+    invoice_id: u64,
+    pay_token: &Address,
+    payer: &Address,
+) -> PaymentQuote {
+    let invoice = get_invoice(env, invoice_id);
+    if !admin::is_accepted_token(env, pay_token) {
+        panic_with_error!(env, ContractError::TokenNotAccepted);
+    }
+
+    // `invoice_amount` selects the fee tier: the invoice total, or the quoted
+    // total when paying in an alternative token.
+    let (principal, invoice_amount, converted_from) = if *pay_token == invoice.token {
+        if invoice.status != InvoiceStatus::Pending
+            && invoice.status != InvoiceStatus::PartiallyPaid
+        {
+            panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+        }
+        let late_fee = late_fee_on(env, &invoice, invoice.amount);
+        (
+            invoice.amount + late_fee - invoice.amount_paid,
+            invoice.amount + late_fee,
+            None,
+        )
+    } else {
+        // Alternative tokens can only be used through a live quote.
+        if invoice.status != InvoiceStatus::Pending {
+            panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+        }
+        let quoted_amount = quoted_amount_for(env, &invoice, pay_token);
+        let quoted_total = quoted_amount + late_fee_on(env, &invoice, quoted_amount);
+        (quoted_total, quoted_total, Some(invoice.token.clone()))
+    };
+
+    let mut base_fee: i128 = 0;
+    let mut conversion_fee: i128 = 0;
+    let mut dust: i128 = 0;
+    for (_, gross) in payment_shares(env, &invoice, principal).iter() {
+        let (share_base_fee, share_conversion_fee, share_dust) = payment_fees(
+            env,
+            pay_token,
+            payer,
+            invoice_amount,
+            gross,
+            converted_from.as_ref(),
+        );
+        base_fee += share_base_fee;
+        conversion_fee += share_conversion_fee;
+        dust += share_dust;
+    }
+
+    PaymentQuote {
+        principal,
+        base_fee,
+        conversion_fee,
+        total: principal - dust,
+        pay_token: pay_token.clone(),
+    }
+}
+
+/// Previews where a payment of the invoice's outstanding balance would land:
+/// each merchant account with its net share, followed by the contract for any
+/// holdback and the fee destination when a fee is due. Two-phase invoices are
+/// funded into the contract's escrow in full. Fees assume the designated (or
+/// existing) payer, if any.
+pub fn get_settlement_destinations(env: &Env, invoice_id: u64) -> Vec<(Address, i128)> {
+    let invoice = get_invoice(env, invoice_id);
+    if invoice.status != InvoiceStatus::Pending && invoice.status != InvoiceStatus::PartiallyPaid {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
+    let payer = invoice.designated_payer.clone().or(invoice.payer.clone());
+    let invoice_amount = invoice.amount + late_fee_on(env, &invoice, invoice.amount);
+    let outstanding = invoice_amount - invoice.amount_paid;
+    if invoice.requires_confirmation {
+        return vec![env, (env.current_contract_address(), outstanding)];
+    }
+
+    let mut destinations: Vec<(Address, i128)> = Vec::new(env);
+    let mut fee_amount: i128 = 0;
+    let mut held_amount: i128 = 0;
+    for (share_merchant_id, gross) in payment_shares(env, &invoice, outstanding).iter() {
+        let (fee, dust) = match &payer {
+            Some(payer) => {
+                let (base_fee, _, dust) =
+                    payment_fees(env, &invoice.token, payer, invoice_amount, gross, None);
+                (base_fee, dust)
+            }
+            None => (
+                admin::calculate_tiered_fee(env, &invoice.token, invoice_amount, gross),
+                0,
+            ),
+        };
+        let net = gross - fee - dust;
+        let held = merchant::holdback_for(env, share_merchant_id, net);
+        destinations.push_back((
+            merchant::get_merchant_account(env, share_merchant_id),
+            net - held,
+        ));
+        fee_amount += fee;
+        held_amount += held;
+    }
+    if held_amount > 0 {
+        destinations.push_back((env.current_contract_address(), held_amount));
+    }
+    if fee_amount > 0 {
+        destinations.push_back((admin::get_fee_destination(env, &invoice.token), fee_amount));
+    }
+    destinations
+}
+
+// `(merchant_id, gross)` for each recipient of a payment of `amount`.
+fn payment_shares(env: &Env, invoice: &Invoice, amount: i128) -> Vec<(u64, i128)> {
+    if invoice.parts.is_empty() {
+        vec![env, (invoice.merchant_id, amount)]
+    } else {
+        invoice.parts.clone()
+    }
+}
+
+// `(base_fee, conversion_fee, dust)` withheld from a `gross` share paid in
+// `token`; `dust` stays with the payer. Exempt payers owe none of them, and
+// together they never exceed the share.
+fn payment_fees(
+    env: &Env,
+    token: &Address,
+    payer: &Address,
+    invoice_amount: i128,
+    gross: i128,
+    converted_from: Option<&Address>,
+) -> (i128, i128, i128) {
+    let base_fee = admin::calculate_payer_fee(env, token, payer, invoice_amount, gross);
+    let conversion_fee = match converted_from {
+        Some(original_token) if !admin::is_fee_exempt(env, payer) => {
+            admin::calculate_conversion_fee(env, token, original_token, gross).min(gross - base_fee)
+        }
+        _ => 0,
+    };
+    let dust =
+        admin::calculate_payer_dust(env, token, payer, invoice_amount, gross, converted_from)
+            .min(gross - base_fee - conversion_fee);
+    (base_fee, conversion_fee, dust)
+}
@@ -0,0 +1,195 @@
+use crate::components::{core, health, invoice as invoice_component};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{ConfigKey, DataKey, ExpiryAction, Invoice, InvoiceStatus, MaintenanceReport};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+pub const MAX_MAINTENANCE_OPS: u32 = 50;
+pub const MAX_MAINTENANCE_SCAN: u64 = 200;
+pub const ARCHIVE_AFTER: u64 = 7_776_000; // default auto-archive delay, 90 days in seconds
+
+/// Expires overdue pending invoices and archives long-settled ones, performing
+/// at most `limit` transitions. Scanning resumes from where the previous run
+/// stopped so repeated calls walk the whole invoice set.
+pub fn run_maintenance(env: &Env, caller: &Address, limit: u32) -> MaintenanceReport {
+    caller.require_auth();
+
+    if limit == 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    let limit = limit.min(MAX_MAINTENANCE_OPS);
+
+    let invoice_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::InvoiceCount)
+        .unwrap_or(0);
+    let mut report = MaintenanceReport {
+        expired: 0,
+        archived: 0,
+        scanned: 0,
+    };
+    if invoice_count == 0 {
+        return report;
+    }
+
+    let mut invoice_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MaintenanceCursor)
+        .unwrap_or(1);
+    if invoice_id == 0 || invoice_id > invoice_count {
+        invoice_id = 1;
+    }
+
+    let now = env.ledger().timestamp();
+    let archive_delay = get_auto_archive_delay(env);
+    let to_scan = invoice_count.min(MAX_MAINTENANCE_SCAN);
+    while (report.scanned as u64) < to_scan && report.expired + report.archived < limit {
+        let key = DataKey::Invoice(invoice_id);
+        if let Some(mut invoice) = env.storage().persistent().get::<_, Invoice>(&key) {
+            if is_overdue(&invoice, now) {
+                expire(env, &mut invoice);
+                report.expired += 1;
+            } else if is_long_settled(&invoice, now, archive_delay) {
+                invoice.archived = true;
+                env.storage().persistent().set(&key, &invoice);
+                report.archived += 1;
+            }
+        }
+        report.scanned += 1;
+        invoice_id = if invoice_id >= invoice_count {
+            1
+        } else {
+            invoice_id + 1
+        };
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::MaintenanceCursor, &invoice_id);
+
+    events::publish_maintenance_run_event(
+        env,
+        caller.clone(),
+        report.expired,
+        report.archived,
+        now,
+    );
+    report
+}
+
+/// Expires a single overdue pending invoice. Anyone may call it, so stale
+/// invoices can be closed without waiting for a maintenance run.
+pub fn expire_invoice(env: &Env, invoice_id: u64) {
+    let mut invoice: Invoice = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Invoice(invoice_id))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::InvoiceNotFound));
+    if !is_overdue(&invoice, env.ledger().timestamp()) {
+        panic_with_error!(env, ContractError::InvalidInvoiceStatus);
+    }
+
+    expire(env, &mut invoice);
+}
+
+fn expire(env: &Env, invoice: &mut Invoice) {
+    invoice.status = InvoiceStatus::Expired;
+    health::track_invoice_transition(env, InvoiceStatus::Pending, invoice.status);
+    health::record_outcome(
+        env,
+        invoice.merchant_id,
+        InvoiceStatus::Pending,
+        invoice.status,
+    );
+    health::adjust_liability(env, &invoice.token, -invoice.amount);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice.id), invoice);
+    events::publish_invoice_expired_event(env, invoice.id, env.ledger().timestamp());
+    if invoice.on_expiry == ExpiryAction::Reissue {
+        invoice_component::reissue_expired_invoice(env, invoice);
+    }
+}
+
+/// Sets how long an invoice must stay settled before maintenance archives it.
+/// A delay of 0 turns auto-archival off.
+pub fn set_auto_archive_delay(env: &Env, admin: &Address, secs: u64) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::AutoArchiveDelay, &secs);
+
+    events::publish_auto_archive_delay_set_event(
+        env,
+        admin.clone(),
+        secs,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_auto_archive_delay(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::AutoArchiveDelay)
+        .unwrap_or(ARCHIVE_AFTER)
+}
+
+/// Returns ids of pending invoices that are past their expiry right now, oldest
+/// first, so a keeper knows whether a maintenance run has anything to expire.
+/// Scans at most `MAX_MAINTENANCE_SCAN` invoices from `start_id` and returns at
+/// most `limit` ids, capped to what a single run can process.
+pub fn get_expirable(env: &Env, start_id: u64, limit: u32) -> Vec<u64> {
+    if limit == 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    let limit = limit.min(MAX_MAINTENANCE_OPS);
+
+    let invoice_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::InvoiceCount)
+        .unwrap_or(0);
+    let start_id = start_id.max(1);
+    let end_id = invoice_count.min(start_id.saturating_add(MAX_MAINTENANCE_SCAN - 1));
+    let now = env.ledger().timestamp();
+    let mut expirable = Vec::new(env);
+    for invoice_id in start_id..=end_id {
+        if expirable.len() >= limit {
+            break;
+        }
+        if let Some(invoice) = env
+            .storage()
+            .persistent()
+            .get::<_, Invoice>(&DataKey::Invoice(invoice_id))
+        {
+            if is_overdue(&invoice, now) {
+                expirable.push_back(invoice_id);
+            }
+        }
+    }
+    expirable
+}
+
+fn is_overdue(invoice: &Invoice, now: u64) -> bool {
+    invoice.status == InvoiceStatus::Pending
+        && invoice
+            .expires_at
+            .is_some_and(|expires_at| now >= expires_at)
+}
+
+fn is_long_settled(invoice: &Invoice, now: u64, archive_delay: u64) -> bool {
+    if archive_delay == 0 {
+        return false;
+    }
+    let settled = invoice.status == InvoiceStatus::Paid
+        || invoice.status == InvoiceStatus::Refunded
+        || invoice.status == InvoiceStatus::PartiallyRefunded;
+    settled
+        && !invoice.archived
+        && invoice
+            .date_paid
+            .is_some_and(|date_paid| now >= date_paid.saturating_add(archive_delay))
+}
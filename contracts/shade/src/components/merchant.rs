@@ -1,19 +1,130 @@
 use crate::components::access_control;
 use crate::components::admin as admin_component;
 use crate::components::core as core_component;
+use crate::components::escrow;
+use crate::components::health;
+use crate::components::reentrancy;
 use crate::errors::ContractError;
 use crate::events;
-use crate::types::{DataKey, Merchant, MerchantFilter, Role};
-use soroban_sdk::{contractclient, panic_with_error, Address, BytesN, Env, Vec};
+use crate::types::{ConfigKey, DataKey, Merchant, MerchantFilter, MerchantState, Role};
+use soroban_sdk::{contractclient, panic_with_error, token, Address, BytesN, Env, String, Vec};
+
+pub const MAX_TIP_SUGGESTIONS: u32 = 5;
+pub const MAX_INVOICE_PREFIX_LEN: u32 = 16;
+pub const MAX_DEACTIVATION_BATCH: u32 = 50;
+pub const MAX_MERCHANT_NAME_LEN: u32 = 64;
+pub const MAX_MERCHANT_CONTACT_LEN: u32 = 128;
 
 #[contractclient(name = "MerchantAccountClient")]
 pub trait MerchantAccountContract {
     fn restrict_account(env: Env, status: bool);
+    fn is_restricted_account(env: Env) -> bool;
 }
 
-pub fn register_merchant(env: &Env, merchant: &Address) {
+/// Registers the caller as a merchant, optionally storing a display name and
+/// contact string. A missing value is stored as an empty string.
+pub fn register_merchant(
+    env: &Env,
+    merchant: &Address,
+    name: &Option<String>,
+    contact: &Option<String>,
+) {
     merchant.require_auth();
 
+    if !is_open_registration(env) && !can_onboard(env, merchant) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+    let name = name.clone().unwrap_or_else(|| String::from_str(env, ""));
+    let contact = contact.clone().unwrap_or_else(|| String::from_str(env, ""));
+    if name.len() > MAX_MERCHANT_NAME_LEN || contact.len() > MAX_MERCHANT_CONTACT_LEN {
+        panic_with_error!(env, ContractError::DescriptionTooLong);
+    }
+
+    store_new_merchant(env, merchant, &name, &contact);
+}
+
+/// Registers `merchant` on its behalf; used for curated onboarding.
+pub fn onboard_merchant(env: &Env, caller: &Address, merchant: &Address) {
+    caller.require_auth();
+
+    if !can_onboard(env, caller) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    store_new_merchant(
+        env,
+        merchant,
+        &String::from_str(env, ""),
+        &String::from_str(env, ""),
+    );
+}
+
+/// Queues `merchant` for approval by an onboarder instead of registering it.
+pub fn apply_as_merchant(env: &Env, merchant: &Address) {
+    merchant.require_auth();
+
+    if is_merchant(env, merchant) {
+        panic_with_error!(env, ContractError::MerchantAlreadyRegistered);
+    }
+
+    env.storage().persistent().set(
+        &DataKey::MerchantApplication(merchant.clone()),
+        &env.ledger().timestamp(),
+    );
+
+    events::publish_merchant_applied_event(env, merchant.clone(), env.ledger().timestamp());
+}
+
+pub fn approve_merchant_application(env: &Env, caller: &Address, merchant: &Address) {
+    caller.require_auth();
+
+    if !can_onboard(env, caller) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+    if !has_pending_application(env, merchant) {
+        panic_with_error!(env, ContractError::MerchantNotFound);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::MerchantApplication(merchant.clone()));
+    store_new_merchant(
+        env,
+        merchant,
+        &String::from_str(env, ""),
+        &String::from_str(env, ""),
+    );
+}
+
+pub fn has_pending_application(env: &Env, merchant: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::MerchantApplication(merchant.clone()))
+}
+
+pub fn set_open_registration(env: &Env, admin: &Address, open: bool) {
+    core_component::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::OpenRegistration, &open);
+
+    events::publish_open_registration_set_event(env, admin.clone(), open, env.ledger().timestamp());
+}
+
+pub fn is_open_registration(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OpenRegistration)
+        .unwrap_or(true)
+}
+
+fn can_onboard(env: &Env, caller: &Address) -> bool {
+    access_control::has_role(env, caller, Role::Admin)
+        || access_control::has_role(env, caller, Role::Onboarder)
+}
+
+fn store_new_merchant(env: &Env, merchant: &Address, name: &String, contact: &String) {
     if env
         .storage()
         .persistent()
@@ -36,6 +147,10 @@ pub fn register_merchant(env: &Env, merchant: &Address) {
         active: true,
         verified: false,
         date_registered: env.ledger().timestamp(),
+        tip_suggestions: Vec::new(env),
+        invoice_prefix: None,
+        name: name.clone(),
+        contact: contact.clone(),
     };
 
     env.storage()
@@ -47,6 +162,7 @@ pub fn register_merchant(env: &Env, merchant: &Address) {
     env.storage()
         .persistent()
         .set(&DataKey::MerchantCount, &new_id);
+    health::merchant_activated(env);
 
     events::publish_merchant_registered_event(
         env,
@@ -122,6 +238,13 @@ pub fn set_merchant_status(env: &Env, admin: &Address, merchant_id: u64, status:
         .get(&DataKey::Merchant(merchant_id))
         .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound));
 
+    if merchant.active != status {
+        if status {
+            health::merchant_activated(env);
+        } else {
+            health::merchant_deactivated(env);
+        }
+    }
     merchant.active = status;
 
     env.storage()
@@ -136,6 +259,40 @@ pub fn set_merchant_status(env: &Env, admin: &Address, merchant_id: u64, status:
     );
 }
 
+/// Deactivates each listed merchant that exists and is still active, returning
+/// the ids that changed. Unknown or already-inactive ids are skipped.
+pub fn deactivate_merchants(env: &Env, admin: &Address, merchant_ids: &Vec<u64>) -> Vec<u64> {
+    core_component::assert_admin(env, admin);
+
+    if merchant_ids.len() > MAX_DEACTIVATION_BATCH {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let mut deactivated: Vec<u64> = Vec::new(env);
+    for merchant_id in merchant_ids.iter() {
+        let key = DataKey::Merchant(merchant_id);
+        let Some(mut merchant) = env.storage().persistent().get::<_, Merchant>(&key) else {
+            continue;
+        };
+        if !merchant.active {
+            continue;
+        }
+
+        merchant.active = false;
+        env.storage().persistent().set(&key, &merchant);
+        health::merchant_deactivated(env);
+        deactivated.push_back(merchant_id);
+
+        events::publish_merchant_deactivated_event(
+            env,
+            merchant_id,
+            admin.clone(),
+            env.ledger().timestamp(),
+        );
+    }
+    deactivated
+}
+
 pub fn is_merchant_active(env: &Env, merchant_id: u64) -> bool {
     if merchant_id == 0 {
         panic_with_error!(env, ContractError::MerchantNotFound);
@@ -160,6 +317,37 @@ pub fn is_merchant_active(env: &Env, merchant_id: u64) -> bool {
     merchant.active
 }
 
+/// Reads the merchant's standing in one call. An account that cannot report
+/// its restriction is treated as unrestricted.
+pub fn get_merchant_state(env: &Env, merchant: &Address) -> MerchantState {
+    let Some(merchant_id) = env
+        .storage()
+        .persistent()
+        .get::<_, u64>(&DataKey::MerchantId(merchant.clone()))
+    else {
+        return MerchantState::Unknown;
+    };
+    let merchant_data = get_merchant(env, merchant_id);
+
+    let restricted = env
+        .storage()
+        .persistent()
+        .get::<_, Address>(&DataKey::MerchantAccount(merchant_id))
+        .is_some_and(|account| {
+            matches!(
+                MerchantAccountClient::new(env, &account).try_is_restricted_account(),
+                Ok(Ok(true))
+            )
+        });
+
+    match (merchant_data.active, restricted) {
+        (true, false) => MerchantState::Active,
+        (false, false) => MerchantState::Inactive,
+        (true, true) => MerchantState::Paused,
+        (false, true) => MerchantState::Closed,
+    }
+}
+
 pub fn verify_merchant(env: &Env, admin: &Address, merchant_id: u64, status: bool) {
     core_component::assert_admin(env, admin);
 
@@ -248,13 +436,7 @@ pub fn restrict_merchant_account(
     merchant_address: &Address,
     status: bool,
 ) {
-    caller.require_auth();
-
-    if !access_control::has_role(env, caller, Role::Admin)
-        && !access_control::has_role(env, caller, Role::Manager)
-    {
-        panic_with_error!(env, ContractError::NotAuthorized);
-    }
+    access_control::require_permission(env, caller, access_control::FN_RESTRICT_MERCHANT_ACCOUNT);
 
     let merchant_id: u64 = env
         .storage()
@@ -298,6 +480,197 @@ pub fn set_merchant_account(env: &Env, merchant: &Address, account: &Address) {
         .set(&DataKey::MerchantAccount(merchant_id), account);
 }
 
+pub fn set_tip_suggestions(env: &Env, merchant: &Address, tip_suggestions: &Vec<u32>) {
+    merchant.require_auth();
+
+    let merchant_id = get_merchant_id(env, merchant);
+
+    if tip_suggestions.len() > MAX_TIP_SUGGESTIONS {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    for bps in tip_suggestions.iter() {
+        if bps > 10_000 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+    }
+
+    let mut merchant_data = get_merchant(env, merchant_id);
+    merchant_data.tip_suggestions = tip_suggestions.clone();
+    env.storage()
+        .persistent()
+        .set(&DataKey::Merchant(merchant_id), &merchant_data);
+
+    events::publish_tip_suggestions_set_event(
+        env,
+        merchant_id,
+        tip_suggestions.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn set_merchant_prefix(env: &Env, merchant: &Address, prefix: &Option<String>) {
+    merchant.require_auth();
+
+    let merchant_id = get_merchant_id(env, merchant);
+
+    if let Some(prefix) = prefix {
+        if prefix.is_empty() {
+            panic_with_error!(env, ContractError::InvalidDescription);
+        }
+        if prefix.len() > MAX_INVOICE_PREFIX_LEN {
+            panic_with_error!(env, ContractError::DescriptionTooLong);
+        }
+    }
+
+    let mut merchant_data = get_merchant(env, merchant_id);
+    merchant_data.invoice_prefix = prefix.clone();
+    env.storage()
+        .persistent()
+        .set(&DataKey::Merchant(merchant_id), &merchant_data);
+
+    events::publish_merchant_prefix_set_event(
+        env,
+        merchant_id,
+        prefix.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+/// Hands out the next per-merchant invoice number, starting at 1.
+pub fn next_invoice_sequence(env: &Env, merchant_id: u64) -> u64 {
+    let key = ConfigKey::MerchantInvoiceSequence(merchant_id);
+    let sequence = env.storage().persistent().get::<_, u64>(&key).unwrap_or(0) + 1;
+    env.storage().persistent().set(&key, &sequence);
+    sequence
+}
+
+/// Share of each settlement, in basis points of the merchant's net, that the
+/// contract holds back until an admin releases it. 0 turns holdback off.
+pub fn set_merchant_holdback_bps(env: &Env, admin: &Address, merchant_id: u64, bps: u32) {
+    core_component::assert_admin(env, admin);
+    get_merchant(env, merchant_id);
+    admin_component::assert_valid_fee_bps(env, bps as i128);
+
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::HoldbackBps(merchant_id), &bps);
+
+    events::publish_merchant_holdback_set_event(env, merchant_id, bps, env.ledger().timestamp());
+}
+
+pub fn get_merchant_holdback_bps(env: &Env, merchant_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::HoldbackBps(merchant_id))
+        .unwrap_or(0)
+}
+
+/// How much of `net` the contract keeps back from `merchant_id`'s payout.
+pub fn holdback_for(env: &Env, merchant_id: u64, net: i128) -> i128 {
+    net * get_merchant_holdback_bps(env, merchant_id) as i128 / 10_000
+}
+
+pub fn get_holdback(env: &Env, merchant_id: u64, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::Holdback(merchant_id, token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn add_holdback(env: &Env, merchant_id: u64, token: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let previous = get_holdback(env, merchant_id, token);
+    env.storage().persistent().set(
+        &ConfigKey::Holdback(merchant_id, token.clone()),
+        &(previous + amount),
+    );
+    escrow::adjust_held_total(env, token, amount);
+    if previous == 0 {
+        let mut merchant_ids = get_holdback_merchants(env, token);
+        merchant_ids.push_back(merchant_id);
+        env.storage()
+            .persistent()
+            .set(&ConfigKey::HoldbackMerchants(token.clone()), &merchant_ids);
+    }
+}
+
+/// Ids of merchants with a non-zero `token` holdback.
+pub fn get_holdback_merchants(env: &Env, token: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::HoldbackMerchants(token.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Drops every merchant's `token` holdback, once an emergency sweep has taken
+/// the funds backing them.
+pub fn clear_holdbacks(env: &Env, token: &Address) {
+    for merchant_id in get_holdback_merchants(env, token).iter() {
+        env.storage()
+            .persistent()
+            .remove(&ConfigKey::Holdback(merchant_id, token.clone()));
+    }
+    env.storage()
+        .persistent()
+        .remove(&ConfigKey::HoldbackMerchants(token.clone()));
+}
+
+/// Pays `amount` of the merchant's held-back `token` balance out to their
+/// merchant account.
+pub fn release_holdback(
+    env: &Env,
+    admin: &Address,
+    merchant_id: u64,
+    token: &Address,
+    amount: i128,
+) {
+    reentrancy::enter(env);
+    core_component::assert_admin(env, admin);
+
+    let held = get_holdback(env, merchant_id, token);
+    if amount <= 0 || amount > held {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    let key = ConfigKey::Holdback(merchant_id, token.clone());
+    if amount == held {
+        env.storage().persistent().remove(&key);
+        let mut merchant_ids = get_holdback_merchants(env, token);
+        if let Some(index) = merchant_ids.first_index_of(merchant_id) {
+            merchant_ids.remove(index);
+            env.storage()
+                .persistent()
+                .set(&ConfigKey::HoldbackMerchants(token.clone()), &merchant_ids);
+        }
+    } else {
+        env.storage().persistent().set(&key, &(held - amount));
+    }
+    escrow::adjust_held_total(env, token, -amount);
+
+    let merchant_account = get_merchant_account(env, merchant_id);
+    let token_client = token::TokenClient::new(env, token);
+    let contract_address = env.current_contract_address();
+    token_client.transfer(&contract_address, &merchant_account, &amount);
+
+    events::publish_balance_changed_event(
+        env,
+        merchant_account.clone(),
+        token.clone(),
+        amount,
+        token_client.balance(&merchant_account),
+    );
+    events::publish_holdback_released_event(
+        env,
+        merchant_id,
+        token.clone(),
+        amount,
+        held - amount,
+        env.ledger().timestamp(),
+    );
+    reentrancy::exit(env);
+}
+
 pub fn get_merchant_account(env: &Env, merchant_id: u64) -> Address {
     env.storage()
         .persistent()
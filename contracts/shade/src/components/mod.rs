@@ -1,8 +1,13 @@
 pub mod access_control;
 pub mod account_factory;
 pub mod admin;
+pub mod audit;
+pub mod circuit_breaker;
 pub mod core;
+pub mod escrow;
+pub mod health;
 pub mod invoice;
+pub mod maintenance;
 pub mod merchant;
 pub mod pausable;
 pub mod reentrancy;
@@ -1,20 +1,13 @@
 use crate::components::core;
 use crate::errors::ContractError;
 use crate::events;
-use crate::types::DataKey;
-use soroban_sdk::{panic_with_error, Address, Env};
+use crate::types::{BlockedPayment, ConfigKey, DataKey, PauseScope};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
 
-// TODO: create a more complex pausable functionality that comprises of pausing and unpausing particularly
-// functionality of the contract like specifically pausing subscription, merchant, plan, payments and withdrawals etc.
-// This way, when a particular functionality is paused, other functionalities of the contract will not be affected.
-// unlike the current implementation where the entire contract is paused.
+pub const MAX_BLOCKED_PAYMENTS: u32 = 20;
 
 pub fn pause(env: &Env, admin: &Address) {
-    admin.require_auth();
-
-    if core::get_admin(env) != admin.clone() {
-        panic_with_error!(env, ContractError::NotAuthorized);
-    }
+    core::assert_admin(env, admin);
 
     assert_not_paused(env);
 
@@ -24,11 +17,7 @@ pub fn pause(env: &Env, admin: &Address) {
 }
 
 pub fn unpause(env: &Env, admin: &Address) {
-    admin.require_auth();
-
-    if core::get_admin(env) != admin.clone() {
-        panic_with_error!(env, ContractError::NotAuthorized);
-    }
+    core::assert_admin(env, admin);
 
     assert_paused(env);
 
@@ -55,3 +44,127 @@ pub fn assert_not_paused(env: &Env) {
         panic_with_error!(env, ContractError::ContractPaused);
     }
 }
+
+/// Pauses only the part of the contract covered by `scope`; `All` is the same
+/// as `pause`.
+pub fn pause_scope(env: &Env, admin: &Address, scope: PauseScope) {
+    if scope == PauseScope::All {
+        return pause(env, admin);
+    }
+    core::assert_admin(env, admin);
+
+    if is_paused_scope(env, scope) {
+        panic_with_error!(env, ContractError::ContractPaused);
+    }
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::PausedScope(scope), &true);
+
+    events::publish_scope_paused_event(env, admin.clone(), scope, env.ledger().timestamp());
+}
+
+pub fn unpause_scope(env: &Env, admin: &Address, scope: PauseScope) {
+    if scope == PauseScope::All {
+        return unpause(env, admin);
+    }
+    core::assert_admin(env, admin);
+
+    if !env
+        .storage()
+        .persistent()
+        .has(&ConfigKey::PausedScope(scope))
+    {
+        panic_with_error!(env, ContractError::ContractNotPaused);
+    }
+    env.storage()
+        .persistent()
+        .remove(&ConfigKey::PausedScope(scope));
+
+    events::publish_scope_unpaused_event(env, admin.clone(), scope, env.ledger().timestamp());
+}
+
+/// Whether `scope` is paused, either on its own or by the global pause.
+pub fn is_paused_scope(env: &Env, scope: PauseScope) -> bool {
+    is_paused(env)
+        || env
+            .storage()
+            .persistent()
+            .has(&ConfigKey::PausedScope(scope))
+}
+
+pub fn assert_scope_not_paused(env: &Env, scope: PauseScope) {
+    if is_paused_scope(env, scope) {
+        panic_with_error!(env, ContractError::ContractPaused);
+    }
+}
+
+/// While on, payments attempted during a pause are logged and return without
+/// moving funds instead of failing, since a failed call can't keep the record.
+pub fn set_log_blocked_payments(env: &Env, admin: &Address, enabled: bool) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::LogBlockedPayments, &enabled);
+
+    events::publish_payment_logging_set_event(
+        env,
+        admin.clone(),
+        enabled,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn is_logging_blocked_payments(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::LogBlockedPayments)
+        .unwrap_or(false)
+}
+
+/// Whether payments are paused with logging on, so attempts should be
+/// recorded and skipped rather than failed.
+pub fn is_blocking_logged_payments(env: &Env) -> bool {
+    is_paused_scope(env, PauseScope::Payments) && is_logging_blocked_payments(env)
+}
+
+/// Records `payer`'s attempt when the contract is paused with logging on and
+/// returns true; the caller should then skip the payment and report that
+/// nothing was paid. The log keeps the most recent `MAX_BLOCKED_PAYMENTS`
+/// attempts.
+pub fn record_blocked_payment(env: &Env, payer: &Address, invoice_id: u64) -> bool {
+    record_blocked_payments(env, payer, &Vec::from_array(env, [invoice_id]))
+}
+
+/// `record_blocked_payment` for an attempt covering several invoices.
+pub fn record_blocked_payments(env: &Env, payer: &Address, invoice_ids: &Vec<u64>) -> bool {
+    if !is_blocking_logged_payments(env) {
+        return false;
+    }
+    payer.require_auth();
+
+    let timestamp = env.ledger().timestamp();
+    let mut attempts = get_blocked_payment_attempts(env);
+    for invoice_id in invoice_ids.iter() {
+        if attempts.len() >= MAX_BLOCKED_PAYMENTS {
+            attempts.pop_front();
+        }
+        attempts.push_back(BlockedPayment {
+            payer: payer.clone(),
+            invoice_id,
+            timestamp,
+        });
+        events::publish_payment_blocked_event(env, payer.clone(), invoice_id, timestamp);
+    }
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::BlockedPayments, &attempts);
+    true
+}
+
+pub fn get_blocked_payment_attempts(env: &Env) -> Vec<BlockedPayment> {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::BlockedPayments)
+        .unwrap_or_else(|| Vec::new(env))
+}
@@ -1,6 +1,6 @@
 use crate::errors::ContractError;
-use crate::events::publish_nonce_invalidated_event;
-use crate::types::DataKey;
+use crate::events::{publish_nonce_invalidated_event, publish_payer_key_set_event};
+use crate::types::{ConfigKey, DataKey, InvoiceTerms};
 use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{panic_with_error, Address, Bytes, BytesN, Env, String};
 
@@ -57,6 +57,89 @@ pub fn verify_invoice_signature(
     env.crypto().ed25519_verify(&key, &message, signature);
 }
 
+/// Builds the message both parties sign for a dual-signed invoice.
+///
+/// Format: "contract_address|merchant_address|payer_address|nonce|amount|token_address|expires_at|description_bytes"
+fn build_dual_message(
+    env: &Env,
+    merchant: &Address,
+    payer: &Address,
+    terms: &InvoiceTerms,
+    nonce: &BytesN<32>,
+) -> Bytes {
+    let mut msg = Bytes::new(env);
+    msg.append(&env.current_contract_address().to_xdr(env));
+    msg.append(&Bytes::from_array(env, b"|"));
+    msg.append(&merchant.clone().to_xdr(env));
+    msg.append(&Bytes::from_array(env, b"|"));
+    msg.append(&payer.clone().to_xdr(env));
+    msg.append(&Bytes::from_array(env, b"|"));
+    msg.append(nonce.as_ref());
+    msg.append(&Bytes::from_array(env, b"|"));
+    msg.append(&Bytes::from_slice(env, &terms.amount.to_be_bytes()));
+    msg.append(&Bytes::from_array(env, b"|"));
+    msg.append(&terms.token.clone().to_xdr(env));
+    msg.append(&Bytes::from_array(env, b"|"));
+    msg.append(&Bytes::from_slice(
+        env,
+        &terms.expires_at.unwrap_or(0).to_be_bytes(),
+    ));
+    msg.append(&Bytes::from_array(env, b"|"));
+    msg.append(&terms.description.clone().to_xdr(env));
+    msg
+}
+
+/// Verifies that merchant and payer both signed `terms` under `nonce`.
+///
+/// The merchant signs with its registered merchant key and the payer with the
+/// key set through `set_payer_key`. The nonce is consumed in the merchant's
+/// namespace, so a replay panics with `NonceAlreadyUsed`.
+pub fn verify_dual_signature(
+    env: &Env,
+    merchant: &Address,
+    payer: &Address,
+    terms: &InvoiceTerms,
+    nonce: &BytesN<32>,
+    merchant_sig: &BytesN<64>,
+    payer_sig: &BytesN<64>,
+) {
+    let merchant_key: BytesN<32> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantKey(merchant.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantKeyNotFound));
+    let payer_key: BytesN<32> = env
+        .storage()
+        .persistent()
+        .get(&ConfigKey::PayerKey(payer.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::InvalidSignature));
+
+    invalidate_nonce(env, merchant, nonce);
+
+    let message = build_dual_message(env, merchant, payer, terms, nonce);
+
+    env.crypto()
+        .ed25519_verify(&merchant_key, &message, merchant_sig);
+    env.crypto().ed25519_verify(&payer_key, &message, payer_sig);
+}
+
+/// Registers the ed25519 public key a payer signs dual-signed invoices with.
+pub fn set_payer_key(env: &Env, payer: &Address, key: &BytesN<32>) {
+    payer.require_auth();
+
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::PayerKey(payer.clone()), key);
+
+    publish_payer_key_set_event(env, payer.clone(), key.clone(), env.ledger().timestamp());
+}
+
+pub fn get_payer_key(env: &Env, payer: &Address) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::PayerKey(payer.clone()))
+}
+
 /// Checks the nonce has not been used and marks it as used.
 ///
 /// Panics with `NonceAlreadyUsed` if the nonce was already consumed.
@@ -141,8 +141,17 @@ pub fn charge_subscription(env: &Env, subscription_id: u64) {
         panic_with_error!(env, ContractError::ChargeTooEarly);
     }
 
-    let fee = admin::calculate_fee(env, &plan.token, plan.amount);
-    let merchant_amount = plan.amount - fee;
+    let fee = admin::calculate_payer_fee(env, &plan.token, &sub.customer, plan.amount, plan.amount);
+    let dust = admin::calculate_payer_dust(
+        env,
+        &plan.token,
+        &sub.customer,
+        plan.amount,
+        plan.amount,
+        None,
+    )
+    .min(plan.amount - fee);
+    let merchant_amount = plan.amount - fee - dust;
 
     let token_client = token::TokenClient::new(env, &plan.token);
     let merchant_account = merchant::get_merchant_account(env, plan.merchant_id);
@@ -150,7 +159,9 @@ pub fn charge_subscription(env: &Env, subscription_id: u64) {
 
     token_client.transfer_from(&spender, &sub.customer, &merchant_account, &merchant_amount);
     if fee > 0 {
-        token_client.transfer_from(&spender, &sub.customer, &spender, &fee);
+        let fee_destination = admin::get_fee_destination(env, &plan.token);
+        token_client.transfer_from(&spender, &sub.customer, &fee_destination, &fee);
+        admin::record_fee_collected(env, &plan.token, fee);
     }
 
     sub.last_charged = now;
@@ -40,4 +40,29 @@ pub enum ContractError {
     TokenNotAcceptedByMerchant = 41,
     FeeUpdateTooEarly = 42,
     NoPendingFeeUpdate = 43,
+    DescriptionTooLong = 44,
+    FeeAboveCeiling = 45,
+    PayerNotAllowed = 46,
+    InvalidInvoiceData = 47,
+    InvoiceAlreadyExists = 48,
+    SettlementFailed = 49,
+    QuoteExpired = 50,
+    InvoiceNotActive = 51,
+    PayerNotVerified = 52,
+    Cooldown = 53,
+    PrimaryActive = 54,
+    MerchantNotApproved = 55,
+    TooManyPartials = 56,
+    ReleaseTooEarly = 57,
+}
+
+/// Errors added after `ContractError` reached the contract spec's limit on
+/// error cases. Codes continue from where `ContractError` stops.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ExtendedError {
+    InvalidFee = 58,
+    EditWindowClosed = 59,
+    NothingToWithdraw = 60,
 }
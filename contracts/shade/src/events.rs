@@ -1,4 +1,18 @@
-use soroban_sdk::{contractevent, Address, BytesN, Env, Vec};
+use crate::components::{admin, audit};
+use crate::types::{AuditAction, DustPolicy, PauseScope};
+use soroban_sdk::{contractevent, Address, BytesN, Env, Event, String, Vec};
+
+/// Events operators can switch off with `set_events_enabled` to save
+/// instructions. The settlement event and the toggle itself always publish.
+trait OptionalEvent: Event {
+    fn emit(&self, env: &Env) {
+        if admin::are_events_enabled(env) {
+            self.publish(env);
+        }
+    }
+}
+
+impl<E: Event> OptionalEvent for E {}
 
 // ── Existing events ───────────────────────────────────────────────────────────
 
@@ -9,7 +23,7 @@ pub struct InitalizedEvent {
 }
 
 pub fn publish_initialized_event(env: &Env, admin: Address, timestamp: u64) {
-    InitalizedEvent { admin, timestamp }.publish(env);
+    InitalizedEvent { admin, timestamp }.emit(env);
 }
 // no new changes to add
 
@@ -20,7 +34,7 @@ pub struct TokenAddedEvent {
 }
 
 pub fn publish_token_added_event(env: &Env, token: Address, timestamp: u64) {
-    TokenAddedEvent { token, timestamp }.publish(env);
+    TokenAddedEvent { token, timestamp }.emit(env);
 }
 
 #[contractevent]
@@ -30,7 +44,7 @@ pub struct TokenRemovedEvent {
 }
 
 pub fn publish_token_removed_event(env: &Env, token: Address, timestamp: u64) {
-    TokenRemovedEvent { token, timestamp }.publish(env);
+    TokenRemovedEvent { token, timestamp }.emit(env);
 }
 
 #[contractevent]
@@ -51,7 +65,8 @@ pub fn publish_merchant_registered_event(
         merchant_id,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
+    audit::record(env, AuditAction::MerchantRegistered, merchant_id, 0, 0);
 }
 
 #[contractevent]
@@ -72,7 +87,7 @@ pub fn publish_merchant_account_deployed_event(
         contract,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
 #[contractevent]
@@ -93,7 +108,35 @@ pub fn publish_merchant_status_changed_event(
         active,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
+    audit::record(
+        env,
+        AuditAction::MerchantStatusChanged,
+        merchant_id,
+        0,
+        active as i128,
+    );
+}
+
+#[contractevent(topics = ["merchant_deactivated"])]
+pub struct MerchantDeactivatedEvent {
+    pub merchant_id: u64,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_deactivated_event(
+    env: &Env,
+    merchant_id: u64,
+    admin: Address,
+    timestamp: u64,
+) {
+    MerchantDeactivatedEvent {
+        merchant_id,
+        admin,
+        timestamp,
+    }
+    .emit(env);
 }
 
 #[contractevent]
@@ -117,7 +160,8 @@ pub fn publish_invoice_created_event(
         amount,
         token,
     }
-    .publish(env);
+    .emit(env);
+    audit::record(env, AuditAction::InvoiceCreated, invoice_id, 0, amount);
 }
 
 #[contractevent]
@@ -141,7 +185,8 @@ pub fn publish_invoice_refunded_event(
         amount,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
+    audit::record(env, AuditAction::InvoiceRefunded, invoice_id, 0, amount);
 }
 
 #[contractevent]
@@ -168,7 +213,14 @@ pub fn publish_invoice_partially_refunded_event(
         total_amount_refunded,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
+    audit::record(
+        env,
+        AuditAction::InvoicePartiallyRefunded,
+        invoice_id,
+        0,
+        amount,
+    );
 }
 
 #[contractevent]
@@ -184,7 +236,50 @@ pub fn publish_merchant_verified_event(env: &Env, merchant_id: u64, status: bool
         status,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
+}
+
+#[contractevent]
+pub struct MerchantHoldbackSetEvent {
+    pub merchant_id: u64,
+    pub bps: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_holdback_set_event(env: &Env, merchant_id: u64, bps: u32, timestamp: u64) {
+    MerchantHoldbackSetEvent {
+        merchant_id,
+        bps,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct HoldbackReleasedEvent {
+    pub merchant_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub remaining: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_holdback_released_event(
+    env: &Env,
+    merchant_id: u64,
+    token: Address,
+    amount: i128,
+    remaining: i128,
+    timestamp: u64,
+) {
+    HoldbackReleasedEvent {
+        merchant_id,
+        token,
+        amount,
+        remaining,
+        timestamp,
+    }
+    .emit(env);
 }
 
 #[contractevent]
@@ -205,7 +300,23 @@ pub fn publish_merchant_key_set_event(
         key,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
+}
+
+#[contractevent]
+pub struct PayerKeySetEvent {
+    pub payer: Address,
+    pub key: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_payer_key_set_event(env: &Env, payer: Address, key: BytesN<32>, timestamp: u64) {
+    PayerKeySetEvent {
+        payer,
+        key,
+        timestamp,
+    }
+    .emit(env);
 }
 
 #[contractevent]
@@ -229,7 +340,7 @@ pub fn publish_role_granted_event(
         role,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
 #[contractevent]
@@ -253,7 +364,34 @@ pub fn publish_role_revoked_event(
         role,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
+}
+
+#[contractevent]
+pub struct RolePermissionSetEvent {
+    pub admin: Address,
+    pub role: crate::types::Role,
+    pub function_id: u32,
+    pub allowed: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_role_permission_set_event(
+    env: &Env,
+    admin: Address,
+    role: crate::types::Role,
+    function_id: u32,
+    allowed: bool,
+    timestamp: u64,
+) {
+    RolePermissionSetEvent {
+        admin,
+        role,
+        function_id,
+        allowed,
+        timestamp,
+    }
+    .emit(env);
 }
 
 #[contractevent]
@@ -263,7 +401,71 @@ pub struct ContractPausedEvent {
 }
 
 pub fn publish_contract_paused_event(env: &Env, admin: Address, timestamp: u64) {
-    ContractPausedEvent { admin, timestamp }.publish(env);
+    ContractPausedEvent { admin, timestamp }.emit(env);
+}
+
+#[contractevent]
+pub struct PaymentLoggingSetEvent {
+    pub admin: Address,
+    pub enabled: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_payment_logging_set_event(env: &Env, admin: Address, enabled: bool, timestamp: u64) {
+    PaymentLoggingSetEvent {
+        admin,
+        enabled,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct PaymentBlockedEvent {
+    pub payer: Address,
+    pub invoice_id: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_payment_blocked_event(env: &Env, payer: Address, invoice_id: u64, timestamp: u64) {
+    PaymentBlockedEvent {
+        payer,
+        invoice_id,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct ScopePausedEvent {
+    pub admin: Address,
+    pub scope: PauseScope,
+    pub timestamp: u64,
+}
+
+pub fn publish_scope_paused_event(env: &Env, admin: Address, scope: PauseScope, timestamp: u64) {
+    ScopePausedEvent {
+        admin,
+        scope,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct ScopeUnpausedEvent {
+    pub admin: Address,
+    pub scope: PauseScope,
+    pub timestamp: u64,
+}
+
+pub fn publish_scope_unpaused_event(env: &Env, admin: Address, scope: PauseScope, timestamp: u64) {
+    ScopeUnpausedEvent {
+        admin,
+        scope,
+        timestamp,
+    }
+    .emit(env);
 }
 
 #[contractevent]
@@ -273,7 +475,7 @@ pub struct ContractUnpausedEvent {
 }
 
 pub fn publish_contract_unpaused_event(env: &Env, admin: Address, timestamp: u64) {
-    ContractUnpausedEvent { admin, timestamp }.publish(env);
+    ContractUnpausedEvent { admin, timestamp }.emit(env);
 }
 
 #[contractevent]
@@ -297,7 +499,7 @@ pub fn publish_fee_proposed_event(
         fee,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
 #[contractevent]
@@ -315,393 +517,1564 @@ pub fn publish_fee_set_event(env: &Env, admin: Address, token: Address, fee: i12
         fee,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
 #[contractevent]
-pub struct ContractUpgradedEvent {
-    pub new_wasm_hash: BytesN<32>,
+pub struct FeeTiersSetEvent {
+    pub admin: Address,
+    pub token: Address,
+    pub tiers: Vec<(i128, u32)>,
     pub timestamp: u64,
 }
 
-pub fn publish_contract_upgraded_event(env: &Env, new_wasm_hash: BytesN<32>, timestamp: u64) {
-    ContractUpgradedEvent {
-        new_wasm_hash,
+pub fn publish_fee_tiers_set_event(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    tiers: Vec<(i128, u32)>,
+    timestamp: u64,
+) {
+    FeeTiersSetEvent {
+        admin,
+        token,
+        tiers,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
 #[contractevent]
-pub struct AccountRestrictedEvent {
-    pub merchant: Address,
-    pub status: bool,
-    pub caller: Address,
+pub struct FeeCeilingSetEvent {
+    pub admin: Address,
+    pub token: Address,
+    pub max_fee: i128,
     pub timestamp: u64,
 }
 
-pub fn publish_account_restricted_event(
+pub fn publish_fee_ceiling_set_event(
     env: &Env,
-    merchant: Address,
-    status: bool,
-    caller: Address,
+    admin: Address,
+    token: Address,
+    max_fee: i128,
     timestamp: u64,
 ) {
-    AccountRestrictedEvent {
-        merchant,
-        status,
-        caller,
+    FeeCeilingSetEvent {
+        admin,
+        token,
+        max_fee,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
-// Kept merchant_amount from your branch AND merchant_account from main — both are useful.
 #[contractevent]
-pub struct InvoicePaidEvent {
-    pub invoice_id: u64,
-    pub merchant_id: u64,
-    pub merchant_account: Address,
+pub struct FeeExemptionUpdatedEvent {
+    pub admin: Address,
     pub payer: Address,
-    pub amount: i128,
-    pub fee: i128,
-    pub merchant_amount: i128,
-    pub token: Address,
+    pub exempt: bool,
     pub timestamp: u64,
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn publish_invoice_paid_event(
+pub fn publish_fee_exemption_updated_event(
     env: &Env,
-    invoice_id: u64,
-    merchant_id: u64,
-    merchant_account: Address,
+    admin: Address,
     payer: Address,
-    amount: i128,
-    fee: i128,
-    merchant_amount: i128,
-    token: Address,
+    exempt: bool,
     timestamp: u64,
 ) {
-    InvoicePaidEvent {
-        invoice_id,
-        merchant_id,
-        merchant_account,
+    FeeExemptionUpdatedEvent {
+        admin,
         payer,
-        amount,
-        fee,
-        merchant_amount,
-        token,
+        exempt,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
 #[contractevent]
-pub struct InvoiceCancelledEvent {
-    pub invoice_id: u64,
-    pub merchant: Address,
+pub struct ConversionFeeSetEvent {
+    pub admin: Address,
+    pub bps: u32,
     pub timestamp: u64,
 }
 
-pub fn publish_invoice_cancelled_event(
-    env: &Env,
-    invoice_id: u64,
-    merchant: Address,
-    timestamp: u64,
-) {
-    InvoiceCancelledEvent {
-        invoice_id,
-        merchant,
+pub fn publish_conversion_fee_set_event(env: &Env, admin: Address, bps: u32, timestamp: u64) {
+    ConversionFeeSetEvent {
+        admin,
+        bps,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
 #[contractevent]
-pub struct InvoiceAmendedEvent {
-    pub invoice_id: u64,
-    pub merchant: Address,
-    pub old_amount: i128,
-    pub new_amount: i128,
+pub struct ConversionRateSetEvent {
+    pub admin: Address,
+    pub from: Address,
+    pub to: Address,
+    pub rate: i128,
     pub timestamp: u64,
 }
 
-pub fn publish_invoice_amended_event(
+pub fn publish_conversion_rate_set_event(
     env: &Env,
-    invoice_id: u64,
-    merchant: Address,
-    old_amount: i128,
-    new_amount: i128,
+    admin: Address,
+    from: Address,
+    to: Address,
+    rate: i128,
     timestamp: u64,
 ) {
-    InvoiceAmendedEvent {
-        invoice_id,
-        merchant,
-        old_amount,
-        new_amount,
+    ConversionRateSetEvent {
+        admin,
+        from,
+        to,
+        rate,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
 #[contractevent]
-pub struct NonceInvalidatedEvent {
-    pub merchant: Address,
-    pub nonce: BytesN<32>,
+pub struct QuoteValiditySetEvent {
+    pub admin: Address,
+    pub secs: u64,
     pub timestamp: u64,
 }
 
-pub fn publish_nonce_invalidated_event(
-    env: &Env,
-    merchant: Address,
-    nonce: BytesN<32>,
-    timestamp: u64,
-) {
-    NonceInvalidatedEvent {
-        merchant,
-        nonce,
+pub fn publish_quote_validity_set_event(env: &Env, admin: Address, secs: u64, timestamp: u64) {
+    QuoteValiditySetEvent {
+        admin,
+        secs,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
-// ── Subscription events ───────────────────────────────────────────────────────
-
-// Kept token field from your branch (more informative than main's leaner version).
 #[contractevent]
-pub struct SubscriptionPlanCreatedEvent {
-    pub plan_id: u64,
-    pub merchant: Address,
+pub struct TransferFeeSetEvent {
+    pub admin: Address,
     pub token: Address,
-    pub amount: i128,
+    pub bps: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_transfer_fee_set_event(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    bps: u32,
+    timestamp: u64,
+) {
+    TransferFeeSetEvent {
+        admin,
+        token,
+        bps,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent(topics = ["large_payment_threshold_set"])]
+pub struct LargePaymentThresholdSetEvent {
+    pub admin: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_large_payment_threshold_set_event(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    amount: i128,
+    timestamp: u64,
+) {
+    LargePaymentThresholdSetEvent {
+        admin,
+        token,
+        amount,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct DustPolicySetEvent {
+    pub admin: Address,
+    pub policy: DustPolicy,
+    pub timestamp: u64,
+}
+
+pub fn publish_dust_policy_set_event(
+    env: &Env,
+    admin: Address,
+    policy: DustPolicy,
+    timestamp: u64,
+) {
+    DustPolicySetEvent {
+        admin,
+        policy,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct TreasurySetEvent {
+    pub admin: Address,
+    pub treasury: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_treasury_set_event(env: &Env, admin: Address, treasury: Address, timestamp: u64) {
+    TreasurySetEvent {
+        admin,
+        treasury,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct FeeAccrualSetEvent {
+    pub admin: Address,
+    pub enabled: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_fee_accrual_set_event(env: &Env, admin: Address, enabled: bool, timestamp: u64) {
+    FeeAccrualSetEvent {
+        admin,
+        enabled,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent(topics = ["large_payment"])]
+pub struct LargePaymentEvent {
+    pub invoice_id: u64,
+    pub merchant_id: u64,
+    pub payer: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub threshold: i128,
+    pub timestamp: u64,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn publish_large_payment_event(
+    env: &Env,
+    invoice_id: u64,
+    merchant_id: u64,
+    payer: Address,
+    token: Address,
+    amount: i128,
+    threshold: i128,
+    timestamp: u64,
+) {
+    LargePaymentEvent {
+        invoice_id,
+        merchant_id,
+        payer,
+        token,
+        amount,
+        threshold,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct InvoiceWrittenOffEvent {
+    pub invoice_id: u64,
+    pub merchant: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_written_off_event(
+    env: &Env,
+    invoice_id: u64,
+    merchant: Address,
+    timestamp: u64,
+) {
+    InvoiceWrittenOffEvent {
+        invoice_id,
+        merchant,
+        timestamp,
+    }
+    .emit(env);
+    audit::record(env, AuditAction::InvoiceWrittenOff, invoice_id, 0, 0);
+}
+
+#[contractevent]
+pub struct InvoiceExpiredEvent {
+    pub invoice_id: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_expired_event(env: &Env, invoice_id: u64, timestamp: u64) {
+    InvoiceExpiredEvent {
+        invoice_id,
+        timestamp,
+    }
+    .emit(env);
+    audit::record(env, AuditAction::InvoiceExpired, invoice_id, 0, 0);
+}
+
+#[contractevent]
+pub struct ContractUpgradedEvent {
+    pub new_wasm_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_contract_upgraded_event(env: &Env, new_wasm_hash: BytesN<32>, timestamp: u64) {
+    ContractUpgradedEvent {
+        new_wasm_hash,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct AccountRestrictedEvent {
+    pub merchant: Address,
+    pub status: bool,
+    pub caller: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_account_restricted_event(
+    env: &Env,
+    merchant: Address,
+    status: bool,
+    caller: Address,
+    timestamp: u64,
+) {
+    AccountRestrictedEvent {
+        merchant,
+        status,
+        caller,
+        timestamp,
+    }
+    .emit(env);
+}
+
+// Kept merchant_amount from your branch AND merchant_account from main — both are useful.
+#[contractevent]
+pub struct InvoicePaidEvent {
+    pub invoice_id: u64,
+    pub merchant_id: u64,
+    pub merchant_account: Address,
+    pub payer: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub merchant_amount: i128,
+    pub token: Address,
+    pub receipt_hash: Option<BytesN<32>>,
+    pub timestamp: u64,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn publish_invoice_paid_event(
+    env: &Env,
+    invoice_id: u64,
+    merchant_id: u64,
+    merchant_account: Address,
+    payer: Address,
+    amount: i128,
+    fee: i128,
+    merchant_amount: i128,
+    token: Address,
+    receipt_hash: Option<BytesN<32>>,
+    timestamp: u64,
+) {
+    InvoicePaidEvent {
+        invoice_id,
+        merchant_id,
+        merchant_account,
+        payer,
+        amount,
+        fee,
+        merchant_amount,
+        token,
+        receipt_hash,
+        timestamp,
+    }
+    .publish(env);
+    audit::record(
+        env,
+        AuditAction::InvoicePaid,
+        invoice_id,
+        merchant_id,
+        amount,
+    );
+}
+
+#[contractevent]
+pub struct InvoiceCancelledEvent {
+    pub invoice_id: u64,
+    pub merchant: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_cancelled_event(
+    env: &Env,
+    invoice_id: u64,
+    merchant: Address,
+    timestamp: u64,
+) {
+    InvoiceCancelledEvent {
+        invoice_id,
+        merchant,
+        timestamp,
+    }
+    .emit(env);
+    audit::record(env, AuditAction::InvoiceCancelled, invoice_id, 0, 0);
+}
+
+#[contractevent]
+pub struct InvoiceAmendedEvent {
+    pub invoice_id: u64,
+    pub merchant: Address,
+    pub old_amount: i128,
+    pub new_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_amended_event(
+    env: &Env,
+    invoice_id: u64,
+    merchant: Address,
+    old_amount: i128,
+    new_amount: i128,
+    timestamp: u64,
+) {
+    InvoiceAmendedEvent {
+        invoice_id,
+        merchant,
+        old_amount,
+        new_amount,
+        timestamp,
+    }
+    .emit(env);
+    audit::record(env, AuditAction::InvoiceAmended, invoice_id, 0, new_amount);
+}
+
+#[contractevent]
+pub struct NonceInvalidatedEvent {
+    pub merchant: Address,
+    pub nonce: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_nonce_invalidated_event(
+    env: &Env,
+    merchant: Address,
+    nonce: BytesN<32>,
+    timestamp: u64,
+) {
+    NonceInvalidatedEvent {
+        merchant,
+        nonce,
+        timestamp,
+    }
+    .emit(env);
+}
+
+// ── Subscription events ───────────────────────────────────────────────────────
+
+// Kept token field from your branch (more informative than main's leaner version).
+#[contractevent]
+pub struct SubscriptionPlanCreatedEvent {
+    pub plan_id: u64,
+    pub merchant: Address,
+    pub token: Address,
+    pub amount: i128,
     pub interval: u64,
     pub timestamp: u64,
 }
 
-pub fn publish_subscription_plan_created_event(
+pub fn publish_subscription_plan_created_event(
+    env: &Env,
+    plan_id: u64,
+    merchant: Address,
+    token: Address,
+    amount: i128,
+    interval: u64,
+    timestamp: u64,
+) {
+    SubscriptionPlanCreatedEvent {
+        plan_id,
+        merchant,
+        token,
+        amount,
+        interval,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct SubscribedEvent {
+    pub subscription_id: u64,
+    pub plan_id: u64,
+    pub customer: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_subscribed_event(
+    env: &Env,
+    subscription_id: u64,
+    plan_id: u64,
+    customer: Address,
+    timestamp: u64,
+) {
+    SubscribedEvent {
+        subscription_id,
+        plan_id,
+        customer,
+        timestamp,
+    }
+    .emit(env);
+    audit::record(env, AuditAction::Subscribed, subscription_id, plan_id, 0);
+}
+
+// Kept the richer version from your branch (plan_id, customer, merchant, token).
+#[contractevent]
+pub struct SubscriptionChargedEvent {
+    pub subscription_id: u64,
+    pub plan_id: u64,
+    pub customer: Address,
+    pub merchant: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub token: Address,
+    pub timestamp: u64,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn publish_subscription_charged_event(
+    env: &Env,
+    subscription_id: u64,
+    plan_id: u64,
+    customer: Address,
+    merchant: Address,
+    amount: i128,
+    fee: i128,
+    token: Address,
+    timestamp: u64,
+) {
+    SubscriptionChargedEvent {
+        subscription_id,
+        plan_id,
+        customer,
+        merchant,
+        amount,
+        fee,
+        token,
+        timestamp,
+    }
+    .emit(env);
+    audit::record(
+        env,
+        AuditAction::SubscriptionCharged,
+        subscription_id,
+        plan_id,
+        amount,
+    );
+}
+
+// Used "caller" from your branch — more accurate than "cancelled_by".
+#[contractevent]
+pub struct SubscriptionCancelledEvent {
+    pub subscription_id: u64,
+    pub caller: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_subscription_cancelled_event(
+    env: &Env,
+    subscription_id: u64,
+    caller: Address,
+    timestamp: u64,
+) {
+    SubscriptionCancelledEvent {
+        subscription_id,
+        caller,
+        timestamp,
+    }
+    .emit(env);
+    audit::record(
+        env,
+        AuditAction::SubscriptionCancelled,
+        subscription_id,
+        0,
+        0,
+    );
+}
+
+#[contractevent]
+pub struct AccountWasmHashSetEvent {
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_account_wasm_hash_set_event(
+    env: &Env,
+    admin: Address,
+    wasm_hash: BytesN<32>,
+    timestamp: u64,
+) {
+    AccountWasmHashSetEvent {
+        admin,
+        wasm_hash,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct PlanDeactivatedEvent {
+    pub plan_id: u64,
+    pub merchant: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_plan_deactivated_event(env: &Env, plan_id: u64, merchant: Address, timestamp: u64) {
+    PlanDeactivatedEvent {
+        plan_id,
+        merchant,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct MerchantTokensSetEvent {
+    pub merchant: Address,
+    pub tokens: Vec<Address>,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_tokens_set_event(
+    env: &Env,
+    merchant: Address,
+    tokens: Vec<Address>,
+    timestamp: u64,
+) {
+    MerchantTokensSetEvent {
+        merchant,
+        tokens,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct MerchantTokenRemovedEvent {
+    pub merchant: Address,
+    pub token: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_token_removed_event(
+    env: &Env,
+    merchant: Address,
+    token: Address,
+    timestamp: u64,
+) {
+    MerchantTokenRemovedEvent {
+        merchant,
+        token,
+        timestamp,
+    }
+    .emit(env);
+}
+
+// ── Admin transfer events ────────────────────────────────────────────────────
+
+#[contractevent]
+pub struct AdminTransferProposedEvent {
+    pub current_admin: Address,
+    pub proposed_admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_admin_transfer_proposed_event(
+    env: &Env,
+    current_admin: Address,
+    proposed_admin: Address,
+    timestamp: u64,
+) {
+    AdminTransferProposedEvent {
+        current_admin,
+        proposed_admin,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct AdminTransferAcceptedEvent {
+    pub old_admin: Address,
+    pub new_admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_admin_transfer_accepted_event(
+    env: &Env,
+    old_admin: Address,
+    new_admin: Address,
+    timestamp: u64,
+) {
+    AdminTransferAcceptedEvent {
+        old_admin,
+        new_admin,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct BackupAdminSetEvent {
+    pub admin: Address,
+    pub backup: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_backup_admin_set_event(env: &Env, admin: Address, backup: Address, timestamp: u64) {
+    BackupAdminSetEvent {
+        admin,
+        backup,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct AdminClaimedEvent {
+    pub old_admin: Address,
+    pub new_admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_admin_claimed_event(
+    env: &Env,
+    old_admin: Address,
+    new_admin: Address,
+    timestamp: u64,
+) {
+    AdminClaimedEvent {
+        old_admin,
+        new_admin,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct InvoiceReminderRecordedEvent {
+    pub invoice_id: u64,
+    pub merchant: Address,
+    pub reminder_count: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_reminder_recorded_event(
+    env: &Env,
+    invoice_id: u64,
+    merchant: Address,
+    reminder_count: u32,
+    timestamp: u64,
+) {
+    InvoiceReminderRecordedEvent {
+        invoice_id,
+        merchant,
+        reminder_count,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent(topics = ["invoice_updated"])]
+pub struct InvoiceUpdatedEvent {
+    pub invoice_id: u64,
+    pub merchant: Address,
+    pub old_token: Address,
+    pub new_token: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_updated_event(
+    env: &Env,
+    invoice_id: u64,
+    merchant: Address,
+    old_token: Address,
+    new_token: Address,
+    timestamp: u64,
+) {
+    InvoiceUpdatedEvent {
+        invoice_id,
+        merchant,
+        old_token,
+        new_token,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct InvoiceReissuedEvent {
+    pub old_invoice_id: u64,
+    pub new_invoice_id: u64,
+    pub merchant: Address,
+    pub new_token: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_reissued_event(
+    env: &Env,
+    old_invoice_id: u64,
+    new_invoice_id: u64,
+    merchant: Address,
+    new_token: Address,
+    timestamp: u64,
+) {
+    InvoiceReissuedEvent {
+        old_invoice_id,
+        new_invoice_id,
+        merchant,
+        new_token,
+        timestamp,
+    }
+    .emit(env);
+}
+
+// ── Circuit breaker events ───────────────────────────────────────────────────
+
+#[contractevent]
+pub struct CircuitBreakerSetEvent {
+    pub admin: Address,
+    pub token: Address,
+    pub window_secs: u64,
+    pub threshold: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_circuit_breaker_set_event(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    window_secs: u64,
+    threshold: i128,
+    timestamp: u64,
+) {
+    CircuitBreakerSetEvent {
+        admin,
+        token,
+        window_secs,
+        threshold,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent(topics = ["circuit_tripped"])]
+pub struct CircuitTrippedEvent {
+    pub token: Address,
+    pub window_volume: i128,
+    pub threshold: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_circuit_tripped_event(
+    env: &Env,
+    token: Address,
+    window_volume: i128,
+    threshold: i128,
+    timestamp: u64,
+) {
+    CircuitTrippedEvent {
+        token,
+        window_volume,
+        threshold,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct MaxDescriptionLenSetEvent {
+    pub admin: Address,
+    pub max_len: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_max_description_len_set_event(
+    env: &Env,
+    admin: Address,
+    max_len: u32,
+    timestamp: u64,
+) {
+    MaxDescriptionLenSetEvent {
+        admin,
+        max_len,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct MaxLateFeeSetEvent {
+    pub admin: Address,
+    pub bps: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_max_late_fee_set_event(env: &Env, admin: Address, bps: u32, timestamp: u64) {
+    MaxLateFeeSetEvent {
+        admin,
+        bps,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct CallbackRevertSetEvent {
+    pub admin: Address,
+    pub revert: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_callback_revert_set_event(env: &Env, admin: Address, revert: bool, timestamp: u64) {
+    CallbackRevertSetEvent {
+        admin,
+        revert,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct MaxPartialsSetEvent {
+    pub admin: Address,
+    pub max_partials: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_max_partials_set_event(
+    env: &Env,
+    admin: Address,
+    max_partials: u32,
+    timestamp: u64,
+) {
+    MaxPartialsSetEvent {
+        admin,
+        max_partials,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct EventsEnabledSetEvent {
+    pub admin: Address,
+    pub enabled: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_events_enabled_set_event(env: &Env, admin: Address, enabled: bool, timestamp: u64) {
+    EventsEnabledSetEvent {
+        admin,
+        enabled,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct MerchantCooldownSetEvent {
+    pub admin: Address,
+    pub secs: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_cooldown_set_event(env: &Env, admin: Address, secs: u64, timestamp: u64) {
+    MerchantCooldownSetEvent {
+        admin,
+        secs,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct EditWindowSetEvent {
+    pub admin: Address,
+    pub secs: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_edit_window_set_event(env: &Env, admin: Address, secs: u64, timestamp: u64) {
+    EditWindowSetEvent {
+        admin,
+        secs,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent(topics = ["balance_changed"])]
+pub struct BalanceChangedEvent {
+    pub holder: Address,
+    pub token: Address,
+    pub delta: i128,
+    pub new_balance: i128,
+}
+
+pub fn publish_balance_changed_event(
+    env: &Env,
+    holder: Address,
+    token: Address,
+    delta: i128,
+    new_balance: i128,
+) {
+    BalanceChangedEvent {
+        holder,
+        token,
+        delta,
+        new_balance,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct InvoicePayerAssignedEvent {
+    pub invoice_id: u64,
+    pub merchant: Address,
+    pub payer: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_payer_assigned_event(
+    env: &Env,
+    invoice_id: u64,
+    merchant: Address,
+    payer: Address,
+    timestamp: u64,
+) {
+    InvoicePayerAssignedEvent {
+        invoice_id,
+        merchant,
+        payer,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct InvoicePayerTransferredEvent {
+    pub invoice_id: u64,
+    pub from: Address,
+    pub to: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_payer_transferred_event(
+    env: &Env,
+    invoice_id: u64,
+    from: Address,
+    to: Address,
+    timestamp: u64,
+) {
+    InvoicePayerTransferredEvent {
+        invoice_id,
+        from,
+        to,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct InvoicePayerClearedEvent {
+    pub invoice_id: u64,
+    pub merchant: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_payer_cleared_event(
+    env: &Env,
+    invoice_id: u64,
+    merchant: Address,
+    timestamp: u64,
+) {
+    InvoicePayerClearedEvent {
+        invoice_id,
+        merchant,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct InvoiceImportedEvent {
+    pub invoice_id: u64,
+    pub admin: Address,
+    pub overwritten: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_imported_event(
+    env: &Env,
+    invoice_id: u64,
+    admin: Address,
+    overwritten: bool,
+    timestamp: u64,
+) {
+    InvoiceImportedEvent {
+        invoice_id,
+        admin,
+        overwritten,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct InvoiceSettlingResetEvent {
+    pub invoice_id: u64,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_settling_reset_event(
+    env: &Env,
+    invoice_id: u64,
+    admin: Address,
+    timestamp: u64,
+) {
+    InvoiceSettlingResetEvent {
+        invoice_id,
+        admin,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct TipSuggestionsSetEvent {
+    pub merchant_id: u64,
+    pub tip_suggestions: Vec<u32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_tip_suggestions_set_event(
+    env: &Env,
+    merchant_id: u64,
+    tip_suggestions: Vec<u32>,
+    timestamp: u64,
+) {
+    TipSuggestionsSetEvent {
+        merchant_id,
+        tip_suggestions,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct MerchantPrefixSetEvent {
+    pub merchant_id: u64,
+    pub prefix: Option<String>,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_prefix_set_event(
     env: &Env,
-    plan_id: u64,
+    merchant_id: u64,
+    prefix: Option<String>,
+    timestamp: u64,
+) {
+    MerchantPrefixSetEvent {
+        merchant_id,
+        prefix,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct DeliveryProofSubmittedEvent {
+    pub invoice_id: u64,
+    pub merchant: Address,
+    pub proof_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_delivery_proof_submitted_event(
+    env: &Env,
+    invoice_id: u64,
     merchant: Address,
-    token: Address,
-    amount: i128,
-    interval: u64,
+    proof_hash: BytesN<32>,
     timestamp: u64,
 ) {
-    SubscriptionPlanCreatedEvent {
-        plan_id,
+    DeliveryProofSubmittedEvent {
+        invoice_id,
         merchant,
-        token,
-        amount,
-        interval,
+        proof_hash,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
 #[contractevent]
-pub struct SubscribedEvent {
-    pub subscription_id: u64,
-    pub plan_id: u64,
-    pub customer: Address,
+pub struct OpenRegistrationSetEvent {
+    pub admin: Address,
+    pub open: bool,
     pub timestamp: u64,
 }
 
-pub fn publish_subscribed_event(
+pub fn publish_open_registration_set_event(env: &Env, admin: Address, open: bool, timestamp: u64) {
+    OpenRegistrationSetEvent {
+        admin,
+        open,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct MerchantAppliedEvent {
+    pub merchant: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_applied_event(env: &Env, merchant: Address, timestamp: u64) {
+    MerchantAppliedEvent {
+        merchant,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct InvoiceQuotesSetEvent {
+    pub invoice_id: u64,
+    pub merchant: Address,
+    pub valid_until: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_quotes_set_event(
     env: &Env,
-    subscription_id: u64,
-    plan_id: u64,
-    customer: Address,
+    invoice_id: u64,
+    merchant: Address,
+    valid_until: u64,
     timestamp: u64,
 ) {
-    SubscribedEvent {
-        subscription_id,
-        plan_id,
-        customer,
+    InvoiceQuotesSetEvent {
+        invoice_id,
+        merchant,
+        valid_until,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
-// Kept the richer version from your branch (plan_id, customer, merchant, token).
 #[contractevent]
-pub struct SubscriptionChargedEvent {
-    pub subscription_id: u64,
-    pub plan_id: u64,
-    pub customer: Address,
-    pub merchant: Address,
-    pub amount: i128,
-    pub fee: i128,
+pub struct EmergencyWithdrawalEvent {
+    pub admin: Address,
     pub token: Address,
+    pub recipient: Address,
+    pub amount: i128,
     pub timestamp: u64,
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn publish_subscription_charged_event(
+pub fn publish_emergency_withdrawal_event(
     env: &Env,
-    subscription_id: u64,
-    plan_id: u64,
-    customer: Address,
-    merchant: Address,
-    amount: i128,
-    fee: i128,
+    admin: Address,
     token: Address,
+    recipient: Address,
+    amount: i128,
     timestamp: u64,
 ) {
-    SubscriptionChargedEvent {
-        subscription_id,
-        plan_id,
-        customer,
-        merchant,
+    EmergencyWithdrawalEvent {
+        admin,
+        token,
+        recipient,
         amount,
-        fee,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct FeesWithdrawnEvent {
+    pub admin: Address,
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_fees_withdrawn_event(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    to: Address,
+    amount: i128,
+    timestamp: u64,
+) {
+    FeesWithdrawnEvent {
+        admin,
         token,
+        to,
+        amount,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
-// Used "caller" from your branch — more accurate than "cancelled_by".
 #[contractevent]
-pub struct SubscriptionCancelledEvent {
-    pub subscription_id: u64,
+pub struct MaintenanceRunEvent {
     pub caller: Address,
+    pub expired: u32,
+    pub archived: u32,
     pub timestamp: u64,
 }
 
-pub fn publish_subscription_cancelled_event(
+pub fn publish_maintenance_run_event(
     env: &Env,
-    subscription_id: u64,
     caller: Address,
+    expired: u32,
+    archived: u32,
     timestamp: u64,
 ) {
-    SubscriptionCancelledEvent {
-        subscription_id,
+    MaintenanceRunEvent {
         caller,
+        expired,
+        archived,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
 #[contractevent]
-pub struct AccountWasmHashSetEvent {
+pub struct AutoArchiveDelaySetEvent {
     pub admin: Address,
-    pub wasm_hash: BytesN<32>,
+    pub secs: u64,
     pub timestamp: u64,
 }
 
-pub fn publish_account_wasm_hash_set_event(
+pub fn publish_auto_archive_delay_set_event(env: &Env, admin: Address, secs: u64, timestamp: u64) {
+    AutoArchiveDelaySetEvent {
+        admin,
+        secs,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct KycRegistrySetEvent {
+    pub admin: Address,
+    pub registry: Option<Address>,
+    pub timestamp: u64,
+}
+
+pub fn publish_kyc_registry_set_event(
     env: &Env,
     admin: Address,
-    wasm_hash: BytesN<32>,
+    registry: Option<Address>,
     timestamp: u64,
 ) {
-    AccountWasmHashSetEvent {
+    KycRegistrySetEvent {
         admin,
-        wasm_hash,
+        registry,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
+}
+
+#[contractevent(topics = ["fee_config_updated"])]
+pub struct FeeConfigUpdatedEvent {
+    pub admin: Address,
+    pub token: Address,
+    pub old_fee: i128,
+    pub new_fee: i128,
+    pub old_bps: u32,
+    pub new_bps: u32,
+    pub old_recipient: Option<Address>,
+    pub new_recipient: Address,
+    pub timestamp: u64,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn publish_fee_config_updated_event(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    old_fee: i128,
+    new_fee: i128,
+    old_bps: u32,
+    new_bps: u32,
+    old_recipient: Option<Address>,
+    new_recipient: Address,
+    timestamp: u64,
+) {
+    FeeConfigUpdatedEvent {
+        admin,
+        token,
+        old_fee,
+        new_fee,
+        old_bps,
+        new_bps,
+        old_recipient,
+        new_recipient,
+        timestamp,
+    }
+    .emit(env);
 }
 
 #[contractevent]
-pub struct PlanDeactivatedEvent {
-    pub plan_id: u64,
+pub struct PayerNoteSetEvent {
+    pub invoice_id: u64,
     pub merchant: Address,
     pub timestamp: u64,
 }
 
-pub fn publish_plan_deactivated_event(env: &Env, plan_id: u64, merchant: Address, timestamp: u64) {
-    PlanDeactivatedEvent {
-        plan_id,
+pub fn publish_payer_note_set_event(env: &Env, invoice_id: u64, merchant: Address, timestamp: u64) {
+    PayerNoteSetEvent {
+        invoice_id,
         merchant,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
 #[contractevent]
-pub struct MerchantTokensSetEvent {
-    pub merchant: Address,
-    pub tokens: Vec<Address>,
+pub struct InvoiceFundedEvent {
+    pub invoice_id: u64,
+    pub payer: Address,
+    pub amount: i128,
     pub timestamp: u64,
 }
 
-pub fn publish_merchant_tokens_set_event(
+pub fn publish_invoice_funded_event(
     env: &Env,
-    merchant: Address,
-    tokens: Vec<Address>,
+    invoice_id: u64,
+    payer: Address,
+    amount: i128,
     timestamp: u64,
 ) {
-    MerchantTokensSetEvent {
-        merchant,
-        tokens,
+    InvoiceFundedEvent {
+        invoice_id,
+        payer,
+        amount,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
 #[contractevent]
-pub struct MerchantTokenRemovedEvent {
-    pub merchant: Address,
-    pub token: Address,
+pub struct ReceiptConfirmedEvent {
+    pub invoice_id: u64,
+    pub payer: Address,
     pub timestamp: u64,
 }
 
-pub fn publish_merchant_token_removed_event(
+pub fn publish_receipt_confirmed_event(env: &Env, invoice_id: u64, payer: Address, timestamp: u64) {
+    ReceiptConfirmedEvent {
+        invoice_id,
+        payer,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct InvoiceDisputedEvent {
+    pub invoice_id: u64,
+    pub payer: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_disputed_event(env: &Env, invoice_id: u64, payer: Address, timestamp: u64) {
+    InvoiceDisputedEvent {
+        invoice_id,
+        payer,
+        timestamp,
+    }
+    .emit(env);
+}
+
+#[contractevent]
+pub struct DisputeResolvedEvent {
+    pub invoice_id: u64,
+    pub admin: Address,
+    pub released_to_merchant: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_dispute_resolved_event(
     env: &Env,
-    merchant: Address,
-    token: Address,
+    invoice_id: u64,
+    admin: Address,
+    released_to_merchant: bool,
     timestamp: u64,
 ) {
-    MerchantTokenRemovedEvent {
-        merchant,
-        token,
+    DisputeResolvedEvent {
+        invoice_id,
+        admin,
+        released_to_merchant,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
-// ── Admin transfer events ────────────────────────────────────────────────────
-
 #[contractevent]
-pub struct AdminTransferProposedEvent {
-    pub current_admin: Address,
-    pub proposed_admin: Address,
+pub struct EncryptedDataSetEvent {
+    pub invoice_id: u64,
+    pub merchant: Address,
     pub timestamp: u64,
 }
 
-pub fn publish_admin_transfer_proposed_event(
+pub fn publish_encrypted_data_set_event(
     env: &Env,
-    current_admin: Address,
-    proposed_admin: Address,
+    invoice_id: u64,
+    merchant: Address,
     timestamp: u64,
 ) {
-    AdminTransferProposedEvent {
-        current_admin,
-        proposed_admin,
+    EncryptedDataSetEvent {
+        invoice_id,
+        merchant,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
 
 #[contractevent]
-pub struct AdminTransferAcceptedEvent {
-    pub old_admin: Address,
-    pub new_admin: Address,
+pub struct PayerCallbackFailedEvent {
+    pub invoice_id: u64,
+    pub callback: Address,
     pub timestamp: u64,
 }
 
-pub fn publish_admin_transfer_accepted_event(
+pub fn publish_payer_callback_failed_event(
     env: &Env,
-    old_admin: Address,
-    new_admin: Address,
+    invoice_id: u64,
+    callback: Address,
     timestamp: u64,
 ) {
-    AdminTransferAcceptedEvent {
-        old_admin,
-        new_admin,
+    PayerCallbackFailedEvent {
+        invoice_id,
+        callback,
         timestamp,
     }
-    .publish(env);
+    .emit(env);
 }
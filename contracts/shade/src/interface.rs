@@ -1,8 +1,10 @@
 use crate::types::{
-    Invoice, InvoiceFilter, Merchant, MerchantFilter, PendingFee, Role, Subscription,
-    SubscriptionPlan,
+    BlockedPayment, CallerPermissions, CircuitBreaker, DustPolicy, ExpiryAction, ExportedEvent,
+    Health, Invoice, InvoiceFilter, InvoiceStatus, InvoiceTerms, MaintenanceReport, Merchant,
+    MerchantFilter, MerchantState, PauseScope, PaymentQuote, PaymentReceipt, PendingFee, Role,
+    Subscription, SubscriptionPlan,
 };
-use soroban_sdk::{contracttrait, Address, BytesN, Env, String, Vec};
+use soroban_sdk::{contracttrait, Address, Bytes, BytesN, Env, Map, String, Vec};
 
 #[contracttrait]
 pub trait ShadeTrait {
@@ -12,19 +14,94 @@ pub trait ShadeTrait {
     fn add_accepted_tokens(env: Env, admin: Address, tokens: Vec<Address>);
     fn remove_accepted_token(env: Env, admin: Address, token: Address);
     fn is_accepted_token(env: Env, token: Address) -> bool;
+    fn get_accepted_tokens_with_fees(env: Env) -> Vec<(Address, i128, u32)>;
     fn set_account_wasm_hash(env: Env, admin: Address, wasm_hash: soroban_sdk::BytesN<32>);
     fn set_fee(env: Env, admin: Address, token: Address, fee: i128);
+    fn set_fee_bps(env: Env, admin: Address, token: Address, bps: u32);
     fn get_fee(env: Env, token: Address) -> i128;
     fn propose_fee(env: Env, admin: Address, token: Address, fee: i128);
     fn execute_fee(env: Env, admin: Address, token: Address);
     fn get_pending_fee(env: Env, token: Address) -> PendingFee;
-    fn register_merchant(env: Env, merchant: Address);
+    fn set_fee_ceiling(env: Env, admin: Address, token: Address, max_fee: i128);
+    fn get_fee_ceiling(env: Env, token: Address) -> Option<i128>;
+    fn set_fee_tiers(env: Env, admin: Address, token: Address, tiers: Vec<(i128, u32)>);
+    fn get_fee_tiers(env: Env, token: Address) -> Vec<(i128, u32)>;
+    fn update_fee_config(
+        env: Env,
+        admin: Address,
+        token: Address,
+        fee: i128,
+        bps: u32,
+        recipient: Address,
+    );
+    fn get_flat_fee(env: Env, token: Address) -> i128;
+    fn get_fee_recipient(env: Env, token: Address) -> Option<Address>;
+    fn add_fee_exempt_payer(env: Env, admin: Address, payer: Address);
+    fn remove_fee_exempt_payer(env: Env, admin: Address, payer: Address);
+    fn add_fee_exempt_payers(env: Env, admin: Address, payers: Vec<Address>);
+    fn remove_fee_exempt_payers(env: Env, admin: Address, payers: Vec<Address>);
+    fn is_fee_exempt(env: Env, payer: Address) -> bool;
+    fn set_kyc_registry(env: Env, admin: Address, registry: Address);
+    fn clear_kyc_registry(env: Env, admin: Address);
+    fn get_kyc_registry(env: Env) -> Option<Address>;
+    fn set_conversion_fee_bps(env: Env, admin: Address, bps: u32);
+    fn get_conversion_fee_bps(env: Env) -> u32;
+    fn set_conversion_rate(env: Env, admin: Address, from: Address, to: Address, rate: i128);
+    fn get_conversion_rate(env: Env, from: Address, to: Address) -> Option<i128>;
+    fn set_quote_validity(env: Env, admin: Address, secs: u64);
+    fn get_quote_validity(env: Env) -> u64;
+    fn set_token_transfer_fee_bps(env: Env, admin: Address, token: Address, bps: u32);
+    fn get_token_transfer_fee_bps(env: Env, token: Address) -> u32;
+    fn set_treasury(env: Env, admin: Address, treasury: Address);
+    fn get_treasury(env: Env) -> Address;
+    fn set_fee_accrual(env: Env, admin: Address, enabled: bool);
+    fn is_fee_accrual_enabled(env: Env) -> bool;
+    fn set_dust_policy(env: Env, admin: Address, policy: DustPolicy);
+    fn get_dust_policy(env: Env) -> DustPolicy;
+    fn set_large_payment_threshold(env: Env, admin: Address, token: Address, amount: i128);
+    fn get_large_payment_threshold(env: Env, token: Address) -> i128;
+    fn set_max_description_len(env: Env, admin: Address, len: u32);
+    fn get_max_description_len(env: Env) -> u32;
+    fn set_merchant_cooldown(env: Env, admin: Address, secs: u64);
+    fn get_merchant_cooldown(env: Env) -> u64;
+    fn set_edit_window(env: Env, admin: Address, secs: u64);
+    fn set_events_enabled(env: Env, admin: Address, enabled: bool);
+    fn are_events_enabled(env: Env) -> bool;
+    fn get_edit_window(env: Env) -> u64;
+    fn set_max_partials(env: Env, admin: Address, max_partials: u32);
+    fn get_max_partials(env: Env) -> u32;
+    fn set_max_late_fee_bps(env: Env, admin: Address, bps: u32);
+    fn get_max_late_fee_bps(env: Env) -> u32;
+    fn get_partial_count(env: Env, invoice_id: u64) -> u32;
+    fn set_callback_revert_on_failure(env: Env, admin: Address, revert: bool);
+    fn get_callback_revert_on_failure(env: Env) -> bool;
+    fn register_merchant(
+        env: Env,
+        merchant: Address,
+        name: Option<String>,
+        contact: Option<String>,
+    );
+    fn onboard_merchant(env: Env, caller: Address, merchant: Address);
+    fn apply_as_merchant(env: Env, merchant: Address);
+    fn approve_merchant_application(env: Env, caller: Address, merchant: Address);
+    fn has_pending_application(env: Env, merchant: Address) -> bool;
+    fn set_open_registration(env: Env, admin: Address, open: bool);
+    fn is_open_registration(env: Env) -> bool;
     fn get_merchant(env: Env, merchant_id: u64) -> Merchant;
+    fn get_merchant_id(env: Env, merchant: Address) -> u64;
     fn get_merchants(env: Env, filter: MerchantFilter) -> Vec<Merchant>;
     fn is_merchant(env: Env, merchant: Address) -> bool;
     fn set_merchant_status(env: Env, admin: Address, merchant_id: u64, status: bool);
+    fn deactivate_merchant(env: Env, admin: Address, merchant_id: u64);
+    fn reactivate_merchant(env: Env, admin: Address, merchant_id: u64);
+    fn deactivate_merchants(env: Env, admin: Address, merchant_ids: Vec<u64>) -> Vec<u64>;
     fn is_merchant_active(env: Env, merchant_id: u64) -> bool;
+    fn get_merchant_state(env: Env, merchant: Address) -> MerchantState;
     fn verify_merchant(env: Env, admin: Address, merchant_id: u64, status: bool);
+    fn set_merchant_holdback_bps(env: Env, admin: Address, merchant_id: u64, bps: u32);
+    fn get_merchant_holdback_bps(env: Env, merchant_id: u64) -> u32;
+    fn get_holdback(env: Env, merchant_id: u64, token: Address) -> i128;
+    fn release_holdback(env: Env, admin: Address, merchant_id: u64, token: Address, amount: i128);
     fn is_merchant_verified(env: Env, merchant_id: u64) -> bool;
     fn create_invoice(
         env: Env,
@@ -34,6 +111,61 @@ pub trait ShadeTrait {
         token: Address,
         expires_at: Option<u64>,
     ) -> u64;
+    fn create_scheduled_invoice(
+        env: Env,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+        active_from: Option<u64>,
+        expires_at: Option<u64>,
+    ) -> u64;
+    fn create_invoice_with_note(
+        env: Env,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+        expires_at: Option<u64>,
+        payer_note: Option<String>,
+    ) -> u64;
+    fn create_invoice_on_expiry(
+        env: Env,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+        expires_at: Option<u64>,
+        on_expiry: ExpiryAction,
+    ) -> u64;
+    fn create_invoice_with_late_fee(
+        env: Env,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+        due_date: u64,
+        late_fee_bps_per_day: u32,
+    ) -> u64;
+    fn get_amount_due(env: Env, invoice_id: u64) -> i128;
+    fn set_payer_note(env: Env, merchant: Address, invoice_id: u64, payer_note: Option<String>);
+    fn set_payer_callback(env: Env, merchant: Address, invoice_id: u64, callback: Option<Address>);
+    fn set_requires_confirmation(env: Env, merchant: Address, invoice_id: u64, required: bool);
+    fn confirm_receipt(env: Env, payer: Address, invoice_id: u64);
+    fn claim_after_timeout(env: Env, merchant: Address, invoice_id: u64);
+    fn dispute_invoice(env: Env, payer: Address, invoice_id: u64);
+    fn resolve_dispute(env: Env, admin: Address, invoice_id: u64, release_to_merchant: bool);
+    fn set_receipt_timeout(env: Env, admin: Address, secs: u64);
+    fn get_receipt_timeout(env: Env) -> u64;
+    fn set_withdrawal_ledger_delay(env: Env, admin: Address, ledgers: u32);
+    fn get_withdrawal_ledger_delay(env: Env) -> u32;
+    fn get_withdrawable_after_ledger(env: Env, invoice_id: u64) -> Option<u32>;
+    fn set_encrypted_data(
+        env: Env,
+        merchant: Address,
+        invoice_id: u64,
+        encrypted_data: Option<Bytes>,
+    );
     fn create_invoice_draft(
         env: Env,
         merchant: Address,
@@ -54,18 +186,90 @@ pub trait ShadeTrait {
         nonce: BytesN<32>,
         signature: BytesN<64>,
     ) -> u64;
+    fn create_invoice_dual_signed(
+        env: Env,
+        merchant: Address,
+        payer: Address,
+        terms: InvoiceTerms,
+        merchant_sig: BytesN<64>,
+        payer_sig: BytesN<64>,
+        nonce: BytesN<32>,
+    ) -> u64;
+    fn create_split_invoice(
+        env: Env,
+        creator: Address,
+        parts: Vec<(u64, i128)>,
+        token: Address,
+        description: String,
+    ) -> u64;
     fn get_invoice(env: Env, invoice_id: u64) -> Invoice;
-    fn refund_invoice(env: Env, merchant: Address, invoice_id: u64);
+    fn get_display_number(env: Env, invoice_id: u64) -> String;
+    fn estimate_invoice_rent(
+        env: Env,
+        description_len: u32,
+        has_splits: bool,
+        tag_count: u32,
+    ) -> u64;
+    fn get_invoices_page(env: Env, start_id: u64, limit: u32) -> Vec<Invoice>;
+    fn get_invoices_by_merchant(env: Env, merchant_id: u64) -> Vec<u64>;
+    fn get_invoices_by_token(env: Env, token: Address, start: u32, limit: u32) -> Vec<u64>;
+    fn export_events_since(env: Env, seq: u64, limit: u32) -> Vec<ExportedEvent>;
+    fn get_audit_sequence(env: Env) -> u64;
+    fn get_statuses(env: Env, invoice_ids: Vec<u64>, strict: bool) -> Vec<Option<InvoiceStatus>>;
+    fn is_payable_now(env: Env, invoice_id: u64) -> bool;
+    fn was_paid_on_time(env: Env, invoice_id: u64) -> Option<bool>;
+    fn quote_payment(env: Env, invoice_id: u64, pay_token: Address, payer: Address)
+        -> PaymentQuote;
+    fn preview_net(env: Env, merchant_id: u64, amount: i128, token: Address) -> i128;
+    fn get_settlement_destinations(env: Env, invoice_id: u64) -> Vec<(Address, i128)>;
+    fn verify_receipt(env: Env, invoice_id: u64, expected: BytesN<32>) -> bool;
+    fn submit_delivery_proof(env: Env, merchant: Address, invoice_id: u64, proof_hash: BytesN<32>);
+    fn export_invoice(env: Env, invoice_id: u64) -> Bytes;
+    fn import_invoice(env: Env, admin: Address, data: Bytes, overwrite: bool) -> u64;
+    fn refund_invoice(env: Env, caller: Address, invoice_id: u64);
     fn set_merchant_key(env: Env, merchant: Address, key: BytesN<32>);
     fn get_merchant_key(env: Env, merchant: Address) -> BytesN<32>;
+    fn set_payer_key(env: Env, payer: Address, key: BytesN<32>);
+    fn get_payer_key(env: Env, payer: Address) -> Option<BytesN<32>>;
     fn grant_role(env: Env, admin: Address, user: Address, role: Role);
     fn revoke_role(env: Env, admin: Address, user: Address, role: Role);
     fn has_role(env: Env, user: Address, role: Role) -> bool;
+    fn get_roles(env: Env, user: Address) -> Vec<Role>;
+    fn set_role_permission(env: Env, admin: Address, role: Role, function_id: u32, allowed: bool);
+    fn get_role_permission(env: Env, role: Role, function_id: u32) -> bool;
+    fn get_caller_permissions(env: Env, caller: Address) -> CallerPermissions;
     fn get_invoices(env: Env, filter: InvoiceFilter) -> Vec<Invoice>;
     fn refund_invoice_partial(env: Env, invoice_id: u64, amount: i128);
     fn pause(env: Env, admin: Address);
     fn unpause(env: Env, admin: Address);
     fn is_paused(env: Env) -> bool;
+    fn pause_scope(env: Env, admin: Address, scope: PauseScope);
+    fn unpause_scope(env: Env, admin: Address, scope: PauseScope);
+    fn is_paused_scope(env: Env, scope: PauseScope) -> bool;
+    fn set_log_blocked_payments(env: Env, admin: Address, enabled: bool);
+    fn get_blocked_payment_attempts(env: Env) -> Vec<BlockedPayment>;
+    fn emergency_withdraw(env: Env, admin: Address, token: Address, recipient: Address);
+    fn withdraw_fees(env: Env, admin: Address, token: Address, to: Address) -> i128;
+    fn get_pool_totals(env: Env, token: Address) -> (i128, i128);
+    fn get_contract_balance(env: Env, token: Address) -> i128;
+    fn get_health(env: Env) -> Health;
+    fn get_outstanding_liability(env: Env, token: Address) -> i128;
+    fn get_merchant_success_rate(env: Env, merchant_id: u64) -> u32;
+    fn get_top_merchants(env: Env, token: Address, limit: u32) -> Vec<(u64, i128)>;
+    fn get_volume(env: Env, token: Address) -> i128;
+    fn run_maintenance(env: Env, caller: Address, limit: u32) -> MaintenanceReport;
+    fn get_expirable(env: Env, start_id: u64, limit: u32) -> Vec<u64>;
+    fn expire_invoice(env: Env, invoice_id: u64);
+    fn set_auto_archive_delay(env: Env, admin: Address, secs: u64);
+    fn get_auto_archive_delay(env: Env) -> u64;
+    fn set_circuit_breaker(
+        env: Env,
+        admin: Address,
+        token: Address,
+        window_secs: u64,
+        threshold: i128,
+    );
+    fn get_circuit_breaker(env: Env, token: Address) -> Option<CircuitBreaker>;
     fn upgrade(env: Env, new_wasm_hash: BytesN<32>);
     fn restrict_merchant_account(
         env: Env,
@@ -75,10 +279,26 @@ pub trait ShadeTrait {
     );
     fn set_merchant_account(env: Env, merchant: Address, account: Address);
     fn get_merchant_account(env: Env, merchant_id: u64) -> Address;
-    fn pay_invoice(env: Env, payer: Address, invoice_id: u64);
-    fn pay_invoices_batch(env: Env, payer: Address, invoice_ids: Vec<u64>);
-    fn pay_invoice_partial(env: Env, payer: Address, invoice_id: u64, amount: i128);
+    fn set_tip_suggestions(env: Env, merchant: Address, tip_suggestions: Vec<u32>);
+    fn set_merchant_prefix(env: Env, merchant: Address, prefix: Option<String>);
+    /// Returns false when a payments pause with logging on blocked and recorded
+    /// the attempt; nothing was paid. The other `pay_*` entrypoints do the same,
+    /// and `pay_oldest` returns no receipts.
+    fn pay_invoice(env: Env, payer: Address, invoice_id: u64) -> bool;
+    fn pay_invoices_batch(env: Env, payer: Address, invoice_ids: Vec<u64>) -> bool;
+    fn pay_invoice_partial(env: Env, payer: Address, invoice_id: u64, amount: i128) -> bool;
+    fn pay_oldest(
+        env: Env,
+        payer: Address,
+        merchant_id: u64,
+        max_amount: i128,
+        token: Address,
+    ) -> Vec<PaymentReceipt>;
     fn void_invoice(env: Env, merchant: Address, invoice_id: u64);
+    fn cancel_invoice(env: Env, merchant: Address, invoice_id: u64);
+    fn cancel_all_pending(env: Env, merchant: Address, start: u32) -> (Vec<u64>, Option<u32>);
+    fn write_off_invoice(env: Env, merchant: Address, invoice_id: u64);
+    fn reset_settling(env: Env, admin: Address, invoice_id: u64);
     fn amend_invoice(
         env: Env,
         merchant: Address,
@@ -86,6 +306,39 @@ pub trait ShadeTrait {
         new_amount: Option<i128>,
         new_description: Option<String>,
     );
+    fn record_reminder(env: Env, merchant: Address, invoice_id: u64);
+    fn get_invoices_due_for_reminder(
+        env: Env,
+        interval: u64,
+        start_id: u64,
+        limit: u32,
+    ) -> Vec<u64>;
+    fn change_invoice_token(env: Env, merchant: Address, invoice_id: u64, new_token: Address);
+    fn reissue_merchant_invoices(
+        env: Env,
+        merchant: Address,
+        old_token: Address,
+        new_token: Address,
+        start: u32,
+    ) -> (Vec<u64>, Option<u32>);
+    fn set_invoice_quotes(
+        env: Env,
+        merchant: Address,
+        invoice_id: u64,
+        quotes: Map<Address, i128>,
+        valid_until: u64,
+    );
+    fn pay_invoice_with_quote(env: Env, payer: Address, invoice_id: u64, token: Address) -> bool;
+    fn assign_payer(env: Env, merchant: Address, invoice_id: u64, payer: Address);
+    fn assign_payers(env: Env, merchant: Address, assignments: Vec<(u64, Address)>);
+    fn clear_payer(env: Env, merchant: Address, invoice_id: u64);
+    fn transfer_invoice_payer(
+        env: Env,
+        current_payer: Address,
+        invoice_id: u64,
+        new_payer: Address,
+    );
+    fn get_payer_outstanding_invoices(env: Env, payer: Address) -> Vec<u64>;
 
     fn set_merchant_accepted_tokens(env: Env, merchant: Address, tokens: Vec<Address>);
     fn get_merchant_accepted_tokens(env: Env, merchant: Address) -> Vec<Address>;
@@ -100,6 +353,17 @@ pub trait ShadeTrait {
     /// Step 2: Proposed new admin accepts and takes ownership.
     fn accept_admin_transfer(env: Env, new_admin: Address);
 
+    // ── Backup admin recovery ─────────────────────────────────────────────────
+
+    /// Names the address allowed to take over if the admin goes inactive.
+    fn set_backup_admin(env: Env, admin: Address, backup: Address);
+    fn get_backup_admin(env: Env) -> Option<Address>;
+    fn set_admin_inactivity_period(env: Env, admin: Address, secs: u64);
+    fn get_admin_inactivity_period(env: Env) -> u64;
+
+    /// Backup takes ownership after the inactivity period has passed.
+    fn claim_admin(env: Env, backup: Address);
+
     // ── Subscription engine ───────────────────────────────────────────────────
 
     /// Create a recurring billing plan.
@@ -1,16 +1,23 @@
 use crate::components::{
-    access_control as access_control_component, admin as admin_component, core as core_component,
-    invoice as invoice_component, merchant as merchant_component, pausable as pausable_component,
-    subscription as subscription_component, upgrade as upgrade_component,
+    access_control as access_control_component, admin as admin_component, audit as audit_component,
+    circuit_breaker as circuit_breaker_component, core as core_component,
+    escrow as escrow_component, health as health_component, invoice as invoice_component,
+    maintenance as maintenance_component, merchant as merchant_component,
+    pausable as pausable_component, signature_util, subscription as subscription_component,
+    upgrade as upgrade_component,
 };
 use crate::errors::ContractError;
 use crate::events;
 use crate::interface::ShadeTrait;
 use crate::types::{
-    ContractInfo, DataKey, Invoice, InvoiceFilter, Merchant, MerchantFilter, PendingFee, Role,
-    Subscription, SubscriptionPlan,
+    BlockedPayment, CallerPermissions, CircuitBreaker, ContractInfo, DataKey, DustPolicy,
+    ExpiryAction, ExportedEvent, Health, Invoice, InvoiceFilter, InvoiceStatus, InvoiceTerms,
+    MaintenanceReport, Merchant, MerchantFilter, MerchantState, PauseScope, PaymentQuote,
+    PaymentReceipt, PendingFee, Role, Subscription, SubscriptionPlan,
+};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, Address, Bytes, BytesN, Env, Map, String, Vec,
 };
-use soroban_sdk::{contract, contractimpl, panic_with_error, Address, BytesN, Env, String, Vec};
 
 #[contract]
 pub struct Shade;
@@ -29,6 +36,7 @@ impl ShadeTrait for Shade {
         env.storage()
             .persistent()
             .set(&DataKey::ContractInfo, &contract_info);
+        core_component::record_admin_activity(&env);
         events::publish_initialized_event(&env, admin, env.ledger().timestamp());
     }
 
@@ -55,6 +63,10 @@ impl ShadeTrait for Shade {
         admin_component::is_accepted_token(&env, &token)
     }
 
+    fn get_accepted_tokens_with_fees(env: Env) -> Vec<(Address, i128, u32)> {
+        admin_component::get_accepted_tokens_with_fees(&env)
+    }
+
     fn set_account_wasm_hash(env: Env, admin: Address, wasm_hash: soroban_sdk::BytesN<32>) {
         admin_component::set_account_wasm_hash(&env, &admin, &wasm_hash);
     }
@@ -64,6 +76,11 @@ impl ShadeTrait for Shade {
         admin_component::set_fee(&env, &admin, &token, fee);
     }
 
+    fn set_fee_bps(env: Env, admin: Address, token: Address, bps: u32) {
+        pausable_component::assert_not_paused(&env);
+        admin_component::set_fee_bps(&env, &admin, &token, bps);
+    }
+
     fn get_fee(env: Env, token: Address) -> i128 {
         admin_component::get_fee(&env, &token)
     }
@@ -82,9 +99,236 @@ impl ShadeTrait for Shade {
         admin_component::get_pending_fee(&env, &token)
     }
 
-    fn register_merchant(env: Env, merchant: Address) {
+    fn set_fee_ceiling(env: Env, admin: Address, token: Address, max_fee: i128) {
+        admin_component::set_fee_ceiling(&env, &admin, &token, max_fee);
+    }
+
+    fn get_fee_ceiling(env: Env, token: Address) -> Option<i128> {
+        admin_component::get_fee_ceiling(&env, &token)
+    }
+
+    fn set_fee_tiers(env: Env, admin: Address, token: Address, tiers: Vec<(i128, u32)>) {
+        admin_component::set_fee_tiers(&env, &admin, &token, &tiers);
+    }
+
+    fn get_fee_tiers(env: Env, token: Address) -> Vec<(i128, u32)> {
+        admin_component::get_fee_tiers(&env, &token)
+    }
+
+    fn update_fee_config(
+        env: Env,
+        admin: Address,
+        token: Address,
+        fee: i128,
+        bps: u32,
+        recipient: Address,
+    ) {
+        admin_component::update_fee_config(&env, &admin, &token, fee, bps, &recipient);
+    }
+
+    fn get_flat_fee(env: Env, token: Address) -> i128 {
+        admin_component::get_flat_fee(&env, &token)
+    }
+
+    fn get_fee_recipient(env: Env, token: Address) -> Option<Address> {
+        admin_component::get_fee_recipient(&env, &token)
+    }
+
+    fn add_fee_exempt_payer(env: Env, admin: Address, payer: Address) {
+        admin_component::add_fee_exempt_payer(&env, &admin, &payer);
+    }
+
+    fn remove_fee_exempt_payer(env: Env, admin: Address, payer: Address) {
+        admin_component::remove_fee_exempt_payer(&env, &admin, &payer);
+    }
+
+    fn add_fee_exempt_payers(env: Env, admin: Address, payers: Vec<Address>) {
+        admin_component::add_fee_exempt_payers(&env, &admin, &payers);
+    }
+
+    fn remove_fee_exempt_payers(env: Env, admin: Address, payers: Vec<Address>) {
+        admin_component::remove_fee_exempt_payers(&env, &admin, &payers);
+    }
+
+    fn is_fee_exempt(env: Env, payer: Address) -> bool {
+        admin_component::is_fee_exempt(&env, &payer)
+    }
+
+    fn set_kyc_registry(env: Env, admin: Address, registry: Address) {
+        admin_component::set_kyc_registry(&env, &admin, &registry);
+    }
+
+    fn clear_kyc_registry(env: Env, admin: Address) {
+        admin_component::clear_kyc_registry(&env, &admin);
+    }
+
+    fn get_kyc_registry(env: Env) -> Option<Address> {
+        admin_component::get_kyc_registry(&env)
+    }
+
+    fn set_conversion_fee_bps(env: Env, admin: Address, bps: u32) {
+        admin_component::set_conversion_fee_bps(&env, &admin, bps);
+    }
+
+    fn get_conversion_fee_bps(env: Env) -> u32 {
+        admin_component::get_conversion_fee_bps(&env)
+    }
+
+    fn set_conversion_rate(env: Env, admin: Address, from: Address, to: Address, rate: i128) {
+        admin_component::set_conversion_rate(&env, &admin, &from, &to, rate);
+    }
+
+    fn get_conversion_rate(env: Env, from: Address, to: Address) -> Option<i128> {
+        admin_component::get_conversion_rate(&env, &from, &to)
+    }
+
+    fn set_quote_validity(env: Env, admin: Address, secs: u64) {
+        admin_component::set_quote_validity(&env, &admin, secs);
+    }
+
+    fn get_quote_validity(env: Env) -> u64 {
+        admin_component::get_quote_validity(&env)
+    }
+
+    fn set_token_transfer_fee_bps(env: Env, admin: Address, token: Address, bps: u32) {
+        admin_component::set_token_transfer_fee_bps(&env, &admin, &token, bps);
+    }
+
+    fn get_token_transfer_fee_bps(env: Env, token: Address) -> u32 {
+        admin_component::get_token_transfer_fee_bps(&env, &token)
+    }
+
+    fn set_treasury(env: Env, admin: Address, treasury: Address) {
+        admin_component::set_treasury(&env, &admin, &treasury);
+    }
+
+    fn get_treasury(env: Env) -> Address {
+        admin_component::get_treasury(&env)
+    }
+
+    fn set_fee_accrual(env: Env, admin: Address, enabled: bool) {
+        admin_component::set_fee_accrual(&env, &admin, enabled);
+    }
+
+    fn is_fee_accrual_enabled(env: Env) -> bool {
+        admin_component::is_fee_accrual_enabled(&env)
+    }
+
+    fn set_dust_policy(env: Env, admin: Address, policy: DustPolicy) {
+        admin_component::set_dust_policy(&env, &admin, policy);
+    }
+
+    fn get_dust_policy(env: Env) -> DustPolicy {
+        admin_component::get_dust_policy(&env)
+    }
+
+    fn set_large_payment_threshold(env: Env, admin: Address, token: Address, amount: i128) {
+        admin_component::set_large_payment_threshold(&env, &admin, &token, amount);
+    }
+
+    fn get_large_payment_threshold(env: Env, token: Address) -> i128 {
+        admin_component::get_large_payment_threshold(&env, &token)
+    }
+
+    fn set_max_description_len(env: Env, admin: Address, len: u32) {
+        admin_component::set_max_description_len(&env, &admin, len);
+    }
+
+    fn get_max_description_len(env: Env) -> u32 {
+        admin_component::get_max_description_len(&env)
+    }
+
+    fn set_merchant_cooldown(env: Env, admin: Address, secs: u64) {
+        admin_component::set_merchant_cooldown(&env, &admin, secs);
+    }
+
+    fn get_merchant_cooldown(env: Env) -> u64 {
+        admin_component::get_merchant_cooldown(&env)
+    }
+
+    fn set_events_enabled(env: Env, admin: Address, enabled: bool) {
+        admin_component::set_events_enabled(&env, &admin, enabled);
+    }
+
+    fn are_events_enabled(env: Env) -> bool {
+        admin_component::are_events_enabled(&env)
+    }
+
+    fn set_edit_window(env: Env, admin: Address, secs: u64) {
+        admin_component::set_edit_window(&env, &admin, secs);
+    }
+
+    fn get_edit_window(env: Env) -> u64 {
+        admin_component::get_edit_window(&env)
+    }
+
+    fn set_max_partials(env: Env, admin: Address, max_partials: u32) {
+        admin_component::set_max_partials(&env, &admin, max_partials);
+    }
+
+    fn get_max_partials(env: Env) -> u32 {
+        admin_component::get_max_partials(&env)
+    }
+
+    fn set_max_late_fee_bps(env: Env, admin: Address, bps: u32) {
+        admin_component::set_max_late_fee_bps(&env, &admin, bps);
+    }
+
+    fn get_max_late_fee_bps(env: Env) -> u32 {
+        admin_component::get_max_late_fee_bps(&env)
+    }
+
+    fn get_partial_count(env: Env, invoice_id: u64) -> u32 {
+        invoice_component::get_partial_count(&env, invoice_id)
+    }
+
+    fn set_callback_revert_on_failure(env: Env, admin: Address, revert: bool) {
+        admin_component::set_callback_revert_on_failure(&env, &admin, revert);
+    }
+
+    fn get_callback_revert_on_failure(env: Env) -> bool {
+        admin_component::get_callback_revert_on_failure(&env)
+    }
+
+    fn register_merchant(
+        env: Env,
+        merchant: Address,
+        name: Option<String>,
+        contact: Option<String>,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        merchant_component::register_merchant(&env, &merchant, &name, &contact);
+    }
+
+    fn onboard_merchant(env: Env, caller: Address, merchant: Address) {
+        pausable_component::assert_not_paused(&env);
+        merchant_component::onboard_merchant(&env, &caller, &merchant);
+    }
+
+    fn apply_as_merchant(env: Env, merchant: Address) {
         pausable_component::assert_not_paused(&env);
-        merchant_component::register_merchant(&env, &merchant);
+        merchant_component::apply_as_merchant(&env, &merchant);
+    }
+
+    fn approve_merchant_application(env: Env, caller: Address, merchant: Address) {
+        pausable_component::assert_not_paused(&env);
+        merchant_component::approve_merchant_application(&env, &caller, &merchant);
+    }
+
+    fn has_pending_application(env: Env, merchant: Address) -> bool {
+        merchant_component::has_pending_application(&env, &merchant)
+    }
+
+    fn set_open_registration(env: Env, admin: Address, open: bool) {
+        merchant_component::set_open_registration(&env, &admin, open);
+    }
+
+    fn is_open_registration(env: Env) -> bool {
+        merchant_component::is_open_registration(&env)
+    }
+
+    fn get_merchant_id(env: Env, merchant: Address) -> u64 {
+        merchant_component::get_merchant_id(&env, &merchant)
     }
 
     fn get_merchant(env: Env, merchant_id: u64) -> Merchant {
@@ -103,14 +347,47 @@ impl ShadeTrait for Shade {
         merchant_component::set_merchant_status(&env, &admin, merchant_id, status);
     }
 
+    fn deactivate_merchant(env: Env, admin: Address, merchant_id: u64) {
+        merchant_component::set_merchant_status(&env, &admin, merchant_id, false);
+    }
+
+    fn reactivate_merchant(env: Env, admin: Address, merchant_id: u64) {
+        merchant_component::set_merchant_status(&env, &admin, merchant_id, true);
+    }
+
+    fn deactivate_merchants(env: Env, admin: Address, merchant_ids: Vec<u64>) -> Vec<u64> {
+        merchant_component::deactivate_merchants(&env, &admin, &merchant_ids)
+    }
+
     fn is_merchant_active(env: Env, merchant_id: u64) -> bool {
         merchant_component::is_merchant_active(&env, merchant_id)
     }
 
+    fn get_merchant_state(env: Env, merchant: Address) -> MerchantState {
+        merchant_component::get_merchant_state(&env, &merchant)
+    }
+
     fn verify_merchant(env: Env, admin: Address, merchant_id: u64, status: bool) {
         merchant_component::verify_merchant(&env, &admin, merchant_id, status);
     }
 
+    fn set_merchant_holdback_bps(env: Env, admin: Address, merchant_id: u64, bps: u32) {
+        merchant_component::set_merchant_holdback_bps(&env, &admin, merchant_id, bps);
+    }
+
+    fn get_merchant_holdback_bps(env: Env, merchant_id: u64) -> u32 {
+        merchant_component::get_merchant_holdback_bps(&env, merchant_id)
+    }
+
+    fn get_holdback(env: Env, merchant_id: u64, token: Address) -> i128 {
+        merchant_component::get_holdback(&env, merchant_id, &token)
+    }
+
+    fn release_holdback(env: Env, admin: Address, merchant_id: u64, token: Address, amount: i128) {
+        pausable_component::assert_not_paused(&env);
+        merchant_component::release_holdback(&env, &admin, merchant_id, &token, amount);
+    }
+
     fn is_merchant_verified(env: Env, merchant_id: u64) -> bool {
         merchant_component::is_merchant_verified(&env, merchant_id)
     }
@@ -123,19 +400,171 @@ impl ShadeTrait for Shade {
         token: Address,
         expires_at: Option<u64>,
     ) -> u64 {
-        pausable_component::assert_not_paused(&env);
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Invoicing);
         invoice_component::create_invoice(&env, &merchant, &description, amount, &token, expires_at)
     }
 
-    fn create_invoice_draft(
+    fn create_scheduled_invoice(
+        env: Env,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+        active_from: Option<u64>,
+        expires_at: Option<u64>,
+    ) -> u64 {
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Invoicing);
+        invoice_component::create_scheduled_invoice(
+            &env,
+            &merchant,
+            &description,
+            amount,
+            &token,
+            active_from,
+            expires_at,
+        )
+    }
+
+    fn create_invoice_with_note(
+        env: Env,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+        expires_at: Option<u64>,
+        payer_note: Option<String>,
+    ) -> u64 {
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Invoicing);
+        invoice_component::create_invoice_with_note(
+            &env,
+            &merchant,
+            &description,
+            amount,
+            &token,
+            expires_at,
+            payer_note,
+        )
+    }
+
+    fn create_invoice_on_expiry(
         env: Env,
         merchant: Address,
         description: String,
         amount: i128,
         token: Address,
         expires_at: Option<u64>,
+        on_expiry: ExpiryAction,
+    ) -> u64 {
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Invoicing);
+        invoice_component::create_invoice_on_expiry(
+            &env,
+            &merchant,
+            &description,
+            amount,
+            &token,
+            expires_at,
+            on_expiry,
+        )
+    }
+
+    fn create_invoice_with_late_fee(
+        env: Env,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+        due_date: u64,
+        late_fee_bps_per_day: u32,
     ) -> u64 {
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Invoicing);
+        invoice_component::create_invoice_with_late_fee(
+            &env,
+            &merchant,
+            &description,
+            amount,
+            &token,
+            due_date,
+            late_fee_bps_per_day,
+        )
+    }
+
+    fn get_amount_due(env: Env, invoice_id: u64) -> i128 {
+        invoice_component::get_amount_due(&env, invoice_id)
+    }
+
+    fn set_payer_note(env: Env, merchant: Address, invoice_id: u64, payer_note: Option<String>) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::set_payer_note(&env, &merchant, invoice_id, payer_note);
+    }
+
+    fn set_payer_callback(env: Env, merchant: Address, invoice_id: u64, callback: Option<Address>) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::set_payer_callback(&env, &merchant, invoice_id, callback);
+    }
+
+    fn set_requires_confirmation(env: Env, merchant: Address, invoice_id: u64, required: bool) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::set_requires_confirmation(&env, &merchant, invoice_id, required);
+    }
+
+    fn confirm_receipt(env: Env, payer: Address, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        escrow_component::confirm_receipt(&env, &payer, invoice_id);
+    }
+
+    fn claim_after_timeout(env: Env, merchant: Address, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        escrow_component::claim_after_timeout(&env, &merchant, invoice_id);
+    }
+
+    fn dispute_invoice(env: Env, payer: Address, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        escrow_component::dispute_invoice(&env, &payer, invoice_id);
+    }
+
+    fn resolve_dispute(env: Env, admin: Address, invoice_id: u64, release_to_merchant: bool) {
+        escrow_component::resolve_dispute(&env, &admin, invoice_id, release_to_merchant);
+    }
+
+    fn set_receipt_timeout(env: Env, admin: Address, secs: u64) {
+        escrow_component::set_receipt_timeout(&env, &admin, secs);
+    }
+
+    fn get_receipt_timeout(env: Env) -> u64 {
+        escrow_component::get_receipt_timeout(&env)
+    }
+
+    fn set_withdrawal_ledger_delay(env: Env, admin: Address, ledgers: u32) {
+        escrow_component::set_withdrawal_ledger_delay(&env, &admin, ledgers);
+    }
+
+    fn get_withdrawal_ledger_delay(env: Env) -> u32 {
+        escrow_component::get_withdrawal_ledger_delay(&env)
+    }
+
+    fn get_withdrawable_after_ledger(env: Env, invoice_id: u64) -> Option<u32> {
+        escrow_component::get_withdrawable_after_ledger(&env, invoice_id)
+    }
+
+    fn set_encrypted_data(
+        env: Env,
+        merchant: Address,
+        invoice_id: u64,
+        encrypted_data: Option<Bytes>,
+    ) {
         pausable_component::assert_not_paused(&env);
+        invoice_component::set_encrypted_data(&env, &merchant, invoice_id, encrypted_data);
+    }
+
+    fn create_invoice_draft(
+        env: Env,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+        expires_at: Option<u64>,
+    ) -> u64 {
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Invoicing);
         invoice_component::create_invoice_draft(
             &env,
             &merchant,
@@ -147,7 +576,7 @@ impl ShadeTrait for Shade {
     }
 
     fn finalize_invoice(env: Env, merchant: Address, invoice_id: u64) {
-        pausable_component::assert_not_paused(&env);
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Invoicing);
         invoice_component::finalize_invoice(&env, &merchant, invoice_id);
     }
 
@@ -162,7 +591,7 @@ impl ShadeTrait for Shade {
         nonce: BytesN<32>,
         signature: BytesN<64>,
     ) -> u64 {
-        pausable_component::assert_not_paused(&env);
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Invoicing);
         invoice_component::create_invoice_signed(
             &env,
             &caller,
@@ -175,13 +604,125 @@ impl ShadeTrait for Shade {
         )
     }
 
+    fn create_invoice_dual_signed(
+        env: Env,
+        merchant: Address,
+        payer: Address,
+        terms: InvoiceTerms,
+        merchant_sig: BytesN<64>,
+        payer_sig: BytesN<64>,
+        nonce: BytesN<32>,
+    ) -> u64 {
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Invoicing);
+        invoice_component::create_invoice_dual_signed(
+            &env,
+            &merchant,
+            &payer,
+            &terms,
+            &merchant_sig,
+            &payer_sig,
+            &nonce,
+        )
+    }
+
+    fn create_split_invoice(
+        env: Env,
+        creator: Address,
+        parts: Vec<(u64, i128)>,
+        token: Address,
+        description: String,
+    ) -> u64 {
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Invoicing);
+        invoice_component::create_split_invoice(&env, &creator, &parts, &token, &description)
+    }
+
     fn get_invoice(env: Env, invoice_id: u64) -> Invoice {
         invoice_component::get_invoice(&env, invoice_id)
     }
 
-    fn refund_invoice(env: Env, merchant: Address, invoice_id: u64) {
+    fn get_display_number(env: Env, invoice_id: u64) -> String {
+        invoice_component::get_display_number(&env, invoice_id)
+    }
+
+    fn estimate_invoice_rent(
+        env: Env,
+        description_len: u32,
+        has_splits: bool,
+        tag_count: u32,
+    ) -> u64 {
+        invoice_component::estimate_invoice_rent(&env, description_len, has_splits, tag_count)
+    }
+
+    fn get_invoices_page(env: Env, start_id: u64, limit: u32) -> Vec<Invoice> {
+        invoice_component::get_invoices_page(&env, start_id, limit)
+    }
+
+    fn get_invoices_by_merchant(env: Env, merchant_id: u64) -> Vec<u64> {
+        invoice_component::get_invoices_by_merchant(&env, merchant_id)
+    }
+
+    fn get_invoices_by_token(env: Env, token: Address, start: u32, limit: u32) -> Vec<u64> {
+        invoice_component::get_invoices_by_token(&env, &token, start, limit)
+    }
+
+    fn export_events_since(env: Env, seq: u64, limit: u32) -> Vec<ExportedEvent> {
+        audit_component::export_events_since(&env, seq, limit)
+    }
+
+    fn get_audit_sequence(env: Env) -> u64 {
+        audit_component::get_audit_sequence(&env)
+    }
+
+    fn get_statuses(env: Env, invoice_ids: Vec<u64>, strict: bool) -> Vec<Option<InvoiceStatus>> {
+        invoice_component::get_statuses(&env, &invoice_ids, strict)
+    }
+
+    fn is_payable_now(env: Env, invoice_id: u64) -> bool {
+        invoice_component::is_payable_now(&env, invoice_id)
+    }
+
+    fn was_paid_on_time(env: Env, invoice_id: u64) -> Option<bool> {
+        invoice_component::was_paid_on_time(&env, invoice_id)
+    }
+
+    fn quote_payment(
+        env: Env,
+        invoice_id: u64,
+        pay_token: Address,
+        payer: Address,
+    ) -> PaymentQuote {
+        invoice_component::quote_payment(&env, invoice_id, &pay_token, &payer)
+    }
+
+    fn preview_net(env: Env, merchant_id: u64, amount: i128, token: Address) -> i128 {
+        invoice_component::preview_net(&env, merchant_id, amount, &token)
+    }
+
+    fn get_settlement_destinations(env: Env, invoice_id: u64) -> Vec<(Address, i128)> {
+        invoice_component::get_settlement_destinations(&env, invoice_id)
+    }
+
+    fn verify_receipt(env: Env, invoice_id: u64, expected: BytesN<32>) -> bool {
+        invoice_component::verify_receipt(&env, invoice_id, &expected)
+    }
+
+    fn submit_delivery_proof(env: Env, merchant: Address, invoice_id: u64, proof_hash: BytesN<32>) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::submit_delivery_proof(&env, &merchant, invoice_id, &proof_hash);
+    }
+
+    fn export_invoice(env: Env, invoice_id: u64) -> Bytes {
+        invoice_component::export_invoice(&env, invoice_id)
+    }
+
+    fn import_invoice(env: Env, admin: Address, data: Bytes, overwrite: bool) -> u64 {
         pausable_component::assert_not_paused(&env);
-        invoice_component::refund_invoice(&env, &merchant, invoice_id);
+        invoice_component::import_invoice(&env, &admin, &data, overwrite)
+    }
+
+    fn refund_invoice(env: Env, caller: Address, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::refund_invoice(&env, &caller, invoice_id);
     }
 
     fn set_merchant_key(env: Env, merchant: Address, key: BytesN<32>) {
@@ -192,6 +733,14 @@ impl ShadeTrait for Shade {
         merchant_component::get_merchant_key(&env, &merchant)
     }
 
+    fn set_payer_key(env: Env, payer: Address, key: BytesN<32>) {
+        signature_util::set_payer_key(&env, &payer, &key);
+    }
+
+    fn get_payer_key(env: Env, payer: Address) -> Option<BytesN<32>> {
+        signature_util::get_payer_key(&env, &payer)
+    }
+
     fn grant_role(env: Env, admin: Address, user: Address, role: Role) {
         access_control_component::grant_role(&env, &admin, &user, role);
     }
@@ -204,6 +753,22 @@ impl ShadeTrait for Shade {
         access_control_component::has_role(&env, &user, role)
     }
 
+    fn get_roles(env: Env, user: Address) -> Vec<Role> {
+        access_control_component::get_roles(&env, &user)
+    }
+
+    fn set_role_permission(env: Env, admin: Address, role: Role, function_id: u32, allowed: bool) {
+        access_control_component::set_role_permission(&env, &admin, role, function_id, allowed);
+    }
+
+    fn get_role_permission(env: Env, role: Role, function_id: u32) -> bool {
+        access_control_component::get_role_permission(&env, role, function_id)
+    }
+
+    fn get_caller_permissions(env: Env, caller: Address) -> CallerPermissions {
+        access_control_component::get_caller_permissions(&env, &caller)
+    }
+
     fn get_invoices(env: Env, filter: InvoiceFilter) -> Vec<Invoice> {
         invoice_component::get_invoices(&env, filter)
     }
@@ -225,6 +790,105 @@ impl ShadeTrait for Shade {
         pausable_component::is_paused(&env)
     }
 
+    fn pause_scope(env: Env, admin: Address, scope: PauseScope) {
+        pausable_component::pause_scope(&env, &admin, scope);
+    }
+
+    fn unpause_scope(env: Env, admin: Address, scope: PauseScope) {
+        pausable_component::unpause_scope(&env, &admin, scope);
+    }
+
+    fn is_paused_scope(env: Env, scope: PauseScope) -> bool {
+        pausable_component::is_paused_scope(&env, scope)
+    }
+
+    fn set_log_blocked_payments(env: Env, admin: Address, enabled: bool) {
+        pausable_component::set_log_blocked_payments(&env, &admin, enabled);
+    }
+
+    fn get_blocked_payment_attempts(env: Env) -> Vec<BlockedPayment> {
+        pausable_component::get_blocked_payment_attempts(&env)
+    }
+
+    fn emergency_withdraw(env: Env, admin: Address, token: Address, recipient: Address) {
+        admin_component::emergency_withdraw(&env, &admin, &token, &recipient);
+    }
+
+    fn withdraw_fees(env: Env, admin: Address, token: Address, to: Address) -> i128 {
+        pausable_component::assert_not_paused(&env);
+        admin_component::withdraw_fees(&env, &admin, &token, &to)
+    }
+
+    fn get_pool_totals(env: Env, token: Address) -> (i128, i128) {
+        admin_component::get_pool_totals(&env, &token)
+    }
+
+    fn get_contract_balance(env: Env, token: Address) -> i128 {
+        admin_component::get_contract_balance(&env, &token)
+    }
+
+    fn get_health(env: Env) -> Health {
+        health_component::get_health(&env)
+    }
+
+    fn get_outstanding_liability(env: Env, token: Address) -> i128 {
+        health_component::get_outstanding_liability(&env, &token)
+    }
+
+    fn get_merchant_success_rate(env: Env, merchant_id: u64) -> u32 {
+        health_component::get_merchant_success_rate(&env, merchant_id)
+    }
+
+    fn get_top_merchants(env: Env, token: Address, limit: u32) -> Vec<(u64, i128)> {
+        health_component::get_top_merchants(&env, &token, limit)
+    }
+
+    fn get_volume(env: Env, token: Address) -> i128 {
+        health_component::get_volume(&env, &token)
+    }
+
+    fn run_maintenance(env: Env, caller: Address, limit: u32) -> MaintenanceReport {
+        pausable_component::assert_not_paused(&env);
+        maintenance_component::run_maintenance(&env, &caller, limit)
+    }
+
+    fn get_expirable(env: Env, start_id: u64, limit: u32) -> Vec<u64> {
+        maintenance_component::get_expirable(&env, start_id, limit)
+    }
+
+    fn expire_invoice(env: Env, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        maintenance_component::expire_invoice(&env, invoice_id);
+    }
+
+    fn set_auto_archive_delay(env: Env, admin: Address, secs: u64) {
+        maintenance_component::set_auto_archive_delay(&env, &admin, secs);
+    }
+
+    fn get_auto_archive_delay(env: Env) -> u64 {
+        maintenance_component::get_auto_archive_delay(&env)
+    }
+
+    fn set_circuit_breaker(
+        env: Env,
+        admin: Address,
+        token: Address,
+        window_secs: u64,
+        threshold: i128,
+    ) {
+        circuit_breaker_component::set_circuit_breaker(
+            &env,
+            &admin,
+            &token,
+            window_secs,
+            threshold,
+        );
+    }
+
+    fn get_circuit_breaker(env: Env, token: Address) -> Option<CircuitBreaker> {
+        circuit_breaker_component::get_circuit_breaker(&env, &token)
+    }
+
     fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
         upgrade_component::upgrade(&env, &new_wasm_hash);
     }
@@ -246,19 +910,58 @@ impl ShadeTrait for Shade {
         merchant_component::get_merchant_account(&env, merchant_id)
     }
 
-    fn pay_invoice(env: Env, payer: Address, invoice_id: u64) {
+    fn set_tip_suggestions(env: Env, merchant: Address, tip_suggestions: Vec<u32>) {
         pausable_component::assert_not_paused(&env);
-        invoice_component::pay_invoice(&env, &payer, invoice_id);
+        merchant_component::set_tip_suggestions(&env, &merchant, &tip_suggestions);
     }
 
-    fn pay_invoices_batch(env: Env, payer: Address, invoice_ids: Vec<u64>) {
+    fn set_merchant_prefix(env: Env, merchant: Address, prefix: Option<String>) {
         pausable_component::assert_not_paused(&env);
+        merchant_component::set_merchant_prefix(&env, &merchant, &prefix);
+    }
+
+    fn pay_invoice(env: Env, payer: Address, invoice_id: u64) -> bool {
+        if pausable_component::record_blocked_payment(&env, &payer, invoice_id) {
+            return false;
+        }
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Payments);
+        invoice_component::pay_invoice(&env, &payer, invoice_id);
+        true
+    }
+
+    fn pay_invoices_batch(env: Env, payer: Address, invoice_ids: Vec<u64>) -> bool {
+        if pausable_component::record_blocked_payments(&env, &payer, &invoice_ids) {
+            return false;
+        }
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Payments);
         invoice_component::pay_invoices_batch(&env, &payer, &invoice_ids);
+        true
     }
 
-    fn pay_invoice_partial(env: Env, payer: Address, invoice_id: u64, amount: i128) {
-        pausable_component::assert_not_paused(&env);
+    fn pay_invoice_partial(env: Env, payer: Address, invoice_id: u64, amount: i128) -> bool {
+        if pausable_component::record_blocked_payment(&env, &payer, invoice_id) {
+            return false;
+        }
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Payments);
         invoice_component::pay_invoice_partial(&env, &payer, invoice_id, amount);
+        true
+    }
+
+    fn pay_oldest(
+        env: Env,
+        payer: Address,
+        merchant_id: u64,
+        max_amount: i128,
+        token: Address,
+    ) -> Vec<PaymentReceipt> {
+        if pausable_component::is_blocking_logged_payments(&env) {
+            let candidates =
+                invoice_component::get_oldest_outstanding(&env, &payer, merchant_id, &token);
+            pausable_component::record_blocked_payments(&env, &payer, &candidates);
+            return Vec::new(&env);
+        }
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Payments);
+        invoice_component::pay_oldest(&env, &payer, merchant_id, max_amount, &token)
     }
 
     fn void_invoice(env: Env, merchant: Address, invoice_id: u64) {
@@ -266,6 +969,25 @@ impl ShadeTrait for Shade {
         invoice_component::void_invoice(&env, &merchant, invoice_id);
     }
 
+    fn cancel_invoice(env: Env, merchant: Address, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::void_invoice(&env, &merchant, invoice_id);
+    }
+
+    fn cancel_all_pending(env: Env, merchant: Address, start: u32) -> (Vec<u64>, Option<u32>) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::cancel_all_pending(&env, &merchant, start)
+    }
+
+    fn write_off_invoice(env: Env, merchant: Address, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::write_off_invoice(&env, &merchant, invoice_id);
+    }
+
+    fn reset_settling(env: Env, admin: Address, invoice_id: u64) {
+        invoice_component::reset_settling(&env, &admin, invoice_id);
+    }
+
     fn amend_invoice(
         env: Env,
         merchant: Address,
@@ -277,6 +999,85 @@ impl ShadeTrait for Shade {
         invoice_component::amend_invoice(&env, &merchant, invoice_id, new_amount, new_description);
     }
 
+    fn record_reminder(env: Env, merchant: Address, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::record_reminder(&env, &merchant, invoice_id);
+    }
+
+    fn get_invoices_due_for_reminder(
+        env: Env,
+        interval: u64,
+        start_id: u64,
+        limit: u32,
+    ) -> Vec<u64> {
+        invoice_component::get_invoices_due_for_reminder(&env, interval, start_id, limit)
+    }
+
+    fn change_invoice_token(env: Env, merchant: Address, invoice_id: u64, new_token: Address) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::change_invoice_token(&env, &merchant, invoice_id, &new_token);
+    }
+
+    fn reissue_merchant_invoices(
+        env: Env,
+        merchant: Address,
+        old_token: Address,
+        new_token: Address,
+        start: u32,
+    ) -> (Vec<u64>, Option<u32>) {
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Invoicing);
+        invoice_component::reissue_merchant_invoices(&env, &merchant, &old_token, &new_token, start)
+    }
+
+    fn set_invoice_quotes(
+        env: Env,
+        merchant: Address,
+        invoice_id: u64,
+        quotes: Map<Address, i128>,
+        valid_until: u64,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::set_invoice_quotes(&env, &merchant, invoice_id, &quotes, valid_until);
+    }
+
+    fn pay_invoice_with_quote(env: Env, payer: Address, invoice_id: u64, token: Address) -> bool {
+        if pausable_component::record_blocked_payment(&env, &payer, invoice_id) {
+            return false;
+        }
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Payments);
+        invoice_component::pay_invoice_with_quote(&env, &payer, invoice_id, &token);
+        true
+    }
+
+    fn assign_payer(env: Env, merchant: Address, invoice_id: u64, payer: Address) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::assign_payer(&env, &merchant, invoice_id, &payer);
+    }
+
+    fn assign_payers(env: Env, merchant: Address, assignments: Vec<(u64, Address)>) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::assign_payers(&env, &merchant, &assignments);
+    }
+
+    fn clear_payer(env: Env, merchant: Address, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::clear_payer(&env, &merchant, invoice_id);
+    }
+
+    fn transfer_invoice_payer(
+        env: Env,
+        current_payer: Address,
+        invoice_id: u64,
+        new_payer: Address,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::transfer_invoice_payer(&env, &current_payer, invoice_id, &new_payer);
+    }
+
+    fn get_payer_outstanding_invoices(env: Env, payer: Address) -> Vec<u64> {
+        invoice_component::get_payer_outstanding_invoices(&env, &payer)
+    }
+
     fn propose_admin_transfer(env: Env, admin: Address, new_admin: Address) {
         admin_component::propose_admin_transfer(&env, &admin, &new_admin);
     }
@@ -285,6 +1086,26 @@ impl ShadeTrait for Shade {
         admin_component::accept_admin_transfer(&env, &new_admin);
     }
 
+    fn set_backup_admin(env: Env, admin: Address, backup: Address) {
+        admin_component::set_backup_admin(&env, &admin, &backup);
+    }
+
+    fn get_backup_admin(env: Env) -> Option<Address> {
+        admin_component::get_backup_admin(&env)
+    }
+
+    fn set_admin_inactivity_period(env: Env, admin: Address, secs: u64) {
+        admin_component::set_admin_inactivity_period(&env, &admin, secs);
+    }
+
+    fn get_admin_inactivity_period(env: Env) -> u64 {
+        admin_component::get_admin_inactivity_period(&env)
+    }
+
+    fn claim_admin(env: Env, backup: Address) {
+        admin_component::claim_admin(&env, &backup);
+    }
+
     // ── Subscription engine ───────────────────────────────────────────────────
 
     fn create_subscription_plan(
@@ -320,7 +1141,7 @@ impl ShadeTrait for Shade {
     }
 
     fn charge_subscription(env: Env, subscription_id: u64) {
-        pausable_component::assert_not_paused(&env);
+        pausable_component::assert_scope_not_paused(&env, PauseScope::Payments);
         subscription_component::charge_subscription(&env, subscription_id);
     }
 
@@ -4,24 +4,85 @@ pub mod test_access_control;
 pub mod test_account_factory;
 pub mod test_admin_payment;
 pub mod test_admin_transfer;
+pub mod test_audit_export;
+pub mod test_backup_admin;
+pub mod test_balance_events;
 pub mod test_calculate_fee;
+pub mod test_circuit_breaker;
+pub mod test_delivery_proof;
+pub mod test_description_limit;
+pub mod test_designated_payer;
 pub mod test_draft_invoice;
+pub mod test_dust_policy;
+pub mod test_edit_window;
+pub mod test_emergency_withdraw;
+pub mod test_encrypted_data;
+pub mod test_escrow_settlement;
+pub mod test_events_toggle;
+pub mod test_fee_exemption;
 pub mod test_fees;
+pub mod test_health;
+pub mod test_holdback;
 pub mod test_invoice;
+pub mod test_invoice_display_number;
+pub mod test_invoice_dual_signed;
+pub mod test_invoice_events;
+pub mod test_invoice_expiry_action;
+pub mod test_invoice_export;
 pub mod test_invoice_filter;
+pub mod test_invoice_page;
 pub mod test_invoice_partial_refund;
+pub mod test_invoice_quotes;
+pub mod test_invoice_reissue;
+pub mod test_invoice_reminder;
+pub mod test_invoice_rent_estimate;
 pub mod test_invoice_signed;
+pub mod test_invoice_statuses;
+pub mod test_invoice_token_change;
 pub mod test_invoice_void;
+pub mod test_invoice_write_off;
+pub mod test_invoices_by_token;
+pub mod test_kyc_registry;
+pub mod test_large_payment;
+pub mod test_late_fee;
+pub mod test_ledger_sequence;
+pub mod test_maintenance;
+pub mod test_max_partials;
 pub mod test_merchant;
 pub mod test_merchant_activation;
+pub mod test_merchant_cooldown;
+pub mod test_merchant_invoices;
 pub mod test_merchant_key;
+pub mod test_merchant_onboarding;
+pub mod test_merchant_state;
+pub mod test_merchant_success_rate;
 pub mod test_merchant_tokens;
 mod test_merchant_verification;
+pub mod test_outstanding_liability;
+pub mod test_paid_on_time;
 pub mod test_pausable;
+pub mod test_pause_scope;
+pub mod test_pay_oldest;
+pub mod test_payer_callback;
+pub mod test_payer_note;
 pub mod test_payment;
+pub mod test_payment_quote;
+pub mod test_pool_totals;
+pub mod test_preview_net;
+pub mod test_receipt;
 pub mod test_reentrancy;
 pub mod test_refund;
+pub mod test_safe_transfer;
+pub mod test_scheduled_invoice;
+pub mod test_settlement;
+pub mod test_settlement_destinations;
 pub mod test_shade_restriction;
 pub mod test_signatures;
+pub mod test_split_invoice;
 pub mod test_subscription;
+pub mod test_tip_suggestions;
+pub mod test_top_merchants;
+pub mod test_treasury;
 pub mod test_upgrade;
+pub mod test_volume;
+pub mod test_withdrawal_ledger_delay;
@@ -1,5 +1,6 @@
 #![cfg(test)]
 
+use crate::errors::ContractError;
 use crate::shade::Shade;
 use crate::shade::ShadeClient;
 use soroban_sdk::testutils::Address as _;
@@ -37,3 +38,23 @@ fn test_get_admin_not_initialized() {
 
     client.get_admin();
 }
+
+#[test]
+fn test_initialize_twice_keeps_original_admin() {
+    let env = Env::default();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let attacker = Address::generate(&env);
+    let result = client.try_initialize(&attacker);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            ContractError::AlreadyInitialized as u32
+        )))
+    );
+    assert_eq!(client.get_admin(), admin);
+}
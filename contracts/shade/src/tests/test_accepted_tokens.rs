@@ -207,3 +207,136 @@ fn test_invalid_token_address_panics() {
     let invalid_token = Address::generate(&env);
     client.add_accepted_token(&admin, &invalid_token);
 }
+
+#[test]
+fn test_get_accepted_tokens_with_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    assert_eq!(client.get_accepted_tokens_with_fees(), Vec::new(&env));
+
+    let token_a = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_b = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token_a);
+    client.add_accepted_token(&admin, &token_b);
+    client.set_fee(&admin, &token_b, &250);
+
+    let mut expected: Vec<(Address, i128, u32)> = Vec::new(&env);
+    expected.push_back((token_a, 0, 0));
+    expected.push_back((token_b, 0, 250));
+    assert_eq!(client.get_accepted_tokens_with_fees(), expected);
+}
+
+fn setup_delisting() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+
+    (env, client, admin, token, merchant)
+}
+
+fn create_invoice(env: &Env, client: &ShadeClient, merchant: &Address, token: &Address) -> u64 {
+    client.create_invoice(
+        merchant,
+        &soroban_sdk::String::from_str(env, "Delisted"),
+        &1000,
+        token,
+        &None,
+    )
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+fn test_create_invoice_with_removed_token_fails() {
+    let (env, client, admin, token, merchant) = setup_delisting();
+    create_invoice(&env, &client, &merchant, &token);
+
+    client.remove_accepted_token(&admin, &token);
+    create_invoice(&env, &client, &merchant, &token);
+}
+
+#[test]
+fn test_remove_accepted_token_clears_fees() {
+    let (env, client, admin, token, _merchant) = setup_delisting();
+    client.update_fee_config(&admin, &token, &25, &300, &Address::generate(&env));
+    client.set_fee_tiers(&admin, &token, &soroban_sdk::vec![&env, (10_000, 100)]);
+    client.propose_fee(&admin, &token, &400);
+
+    client.remove_accepted_token(&admin, &token);
+
+    assert_eq!(client.get_fee(&token), 0);
+    assert_eq!(client.get_flat_fee(&token), 0);
+    assert!(client.get_fee_tiers(&token).is_empty());
+    assert!(client.try_get_pending_fee(&token).is_err());
+}
+
+#[test]
+fn test_readded_token_accepts_invoices_again_without_old_fee() {
+    let (env, client, admin, token, merchant) = setup_delisting();
+    client.set_fee(&admin, &token, &500);
+
+    client.remove_accepted_token(&admin, &token);
+    assert!(client
+        .try_create_invoice(
+            &merchant,
+            &soroban_sdk::String::from_str(&env, "Delisted"),
+            &1000,
+            &token,
+            &None,
+        )
+        .is_err());
+
+    client.add_accepted_token(&admin, &token);
+    assert!(client.is_accepted_token(&token));
+    assert_eq!(client.get_fee(&token), 0);
+    let invoice_id = create_invoice(&env, &client, &merchant, &token);
+    assert_eq!(client.get_invoice(&invoice_id).token, token);
+}
+
+#[test]
+fn test_is_accepted_token_for_accepted_never_added_and_removed() {
+    let (env, client, admin, accepted, _merchant) = setup_delisting();
+    let never_added = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let removed = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &removed);
+    client.remove_accepted_token(&admin, &removed);
+
+    assert!(client.is_accepted_token(&accepted));
+    assert!(!client.is_accepted_token(&never_added));
+    assert!(!client.is_accepted_token(&removed));
+    // removing one token leaves the others accepted
+    assert_eq!(client.get_accepted_tokens_with_fees().len(), 1);
+}
+
+#[test]
+fn test_is_accepted_token_before_initialization_is_false() {
+    let env = Env::default();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    assert!(!client.is_accepted_token(&Address::generate(&env)));
+}
@@ -156,3 +156,142 @@ fn test_revoke_non_existent() {
     client.revoke_role(&admin, &user, &Role::Manager);
     assert!(!client.has_role(&user, &Role::Manager));
 }
+
+#[test]
+fn test_get_caller_permissions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_test(&env);
+    let user = Address::generate(&env);
+
+    let admin_permissions = client.get_caller_permissions(&admin);
+    assert!(admin_permissions.is_admin);
+    assert!(admin_permissions.roles.is_empty());
+
+    let permissions = client.get_caller_permissions(&user);
+    assert!(!permissions.is_admin);
+    assert!(permissions.roles.is_empty());
+
+    client.grant_role(&admin, &user, &Role::Manager);
+    client.grant_role(&admin, &user, &Role::Operator);
+    let permissions = client.get_caller_permissions(&user);
+    assert!(!permissions.is_admin);
+    assert_eq!(
+        permissions.roles,
+        soroban_sdk::vec![&env, Role::Manager, Role::Operator]
+    );
+
+    client.revoke_role(&admin, &user, &Role::Manager);
+    let permissions = client.get_caller_permissions(&user);
+    assert_eq!(permissions.roles, soroban_sdk::vec![&env, Role::Operator]);
+}
+
+#[test]
+fn test_get_roles() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_test(&env);
+    let user = Address::generate(&env);
+
+    assert!(client.get_roles(&user).is_empty());
+
+    client.grant_role(&admin, &user, &Role::Onboarder);
+    client.grant_role(&admin, &user, &Role::Manager);
+    assert_eq!(
+        client.get_roles(&user),
+        soroban_sdk::vec![&env, Role::Manager, Role::Onboarder]
+    );
+
+    client.revoke_role(&admin, &user, &Role::Onboarder);
+    assert_eq!(
+        client.get_roles(&user),
+        soroban_sdk::vec![&env, Role::Manager]
+    );
+
+    client.revoke_role(&admin, &user, &Role::Manager);
+    assert!(client.get_roles(&user).is_empty());
+}
+
+#[test]
+fn test_get_roles_single_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_test(&env);
+    let user = Address::generate(&env);
+
+    client.grant_role(&admin, &user, &Role::Operator);
+
+    assert_eq!(
+        client.get_roles(&user),
+        soroban_sdk::vec![&env, Role::Operator]
+    );
+}
+
+#[test]
+fn test_get_roles_ignores_duplicate_grants() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_test(&env);
+    let user = Address::generate(&env);
+
+    client.grant_role(&admin, &user, &Role::Manager);
+    client.grant_role(&admin, &user, &Role::Manager);
+
+    assert_eq!(
+        client.get_roles(&user),
+        soroban_sdk::vec![&env, Role::Manager]
+    );
+
+    // one revoke clears a role granted twice
+    client.revoke_role(&admin, &user, &Role::Manager);
+    assert!(client.get_roles(&user).is_empty());
+}
+
+#[test]
+fn test_get_roles_is_per_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_test(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.grant_role(&admin, &user, &Role::Admin);
+
+    assert_eq!(
+        client.get_roles(&user),
+        soroban_sdk::vec![&env, Role::Admin]
+    );
+    assert!(client.get_roles(&other).is_empty());
+}
+
+#[test]
+fn test_has_role_tracks_repeated_grant_and_revoke() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_test(&env);
+    let user = Address::generate(&env);
+
+    for _ in 0..2 {
+        client.grant_role(&admin, &user, &Role::Operator);
+        assert!(client.has_role(&user, &Role::Operator));
+
+        client.revoke_role(&admin, &user, &Role::Operator);
+        assert!(!client.has_role(&user, &Role::Operator));
+    }
+}
+
+#[test]
+fn test_revoking_one_role_keeps_others() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_test(&env);
+    let user = Address::generate(&env);
+
+    client.grant_role(&admin, &user, &Role::Manager);
+    client.grant_role(&admin, &user, &Role::Onboarder);
+    client.revoke_role(&admin, &user, &Role::Manager);
+
+    assert!(!client.has_role(&user, &Role::Manager));
+    assert!(client.has_role(&user, &Role::Onboarder));
+    assert!(!client.has_role(&user, &Role::Operator));
+}
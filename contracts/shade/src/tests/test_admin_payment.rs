@@ -47,7 +47,7 @@ fn test_invoice_state_validation() {
     let (env, client, _admin, _manager, merchant, _payer, token) = setup_invoice_test();
 
     // Register merchant
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Create invoice
     let invoice_id = client.create_invoice(
@@ -70,7 +70,7 @@ fn test_multiple_invoices_independent() {
     let (env, client, _admin, _manager, merchant, _payer, token) = setup_invoice_test();
 
     // Register merchant
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Create multiple invoices
     let id_1 = client.create_invoice(
@@ -108,7 +108,7 @@ fn test_fee_preservation() {
     let (env, client, admin, _manager, merchant, _payer, token) = setup_invoice_test();
 
     // Register merchant
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Set custom fee
     let fee = 250i128;
@@ -147,7 +147,7 @@ fn test_contract_pause_and_unpause() {
     let (env, client, admin, _manager, merchant, _payer, token) = setup_invoice_test();
 
     // Register merchant
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Pause contract
     client.pause(&admin);
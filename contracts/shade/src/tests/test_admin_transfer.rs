@@ -79,3 +79,27 @@ fn test_admin_transfer_overwrite_proposal() {
     client.accept_admin_transfer(&second);
     assert_eq!(client.get_admin(), second);
 }
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_accept_admin_transfer_without_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_test(&env);
+
+    client.accept_admin_transfer(&Address::generate(&env));
+}
+
+#[test]
+fn test_admin_transfer_cannot_be_accepted_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_test(&env);
+    let new_admin = Address::generate(&env);
+
+    client.propose_admin_transfer(&admin, &new_admin);
+    client.accept_admin_transfer(&new_admin);
+
+    assert!(client.try_accept_admin_transfer(&new_admin).is_err());
+    assert!(client.try_propose_admin_transfer(&admin, &admin).is_err());
+}
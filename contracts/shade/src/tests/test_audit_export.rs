@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+use crate::components::audit::{self, AUDIT_RETENTION, MAX_AUDIT_EXPORT};
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{AuditAction, ExportedEvent};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, vec, Address, Env, String, Vec};
+
+struct Setup {
+    env: Env,
+    client: ShadeClient<'static>,
+    contract_id: Address,
+    token: Address,
+    merchant: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    Setup {
+        env,
+        client,
+        contract_id,
+        token,
+        merchant,
+    }
+}
+
+fn create_invoice(s: &Setup, amount: i128) -> u64 {
+    s.client.create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Audited"),
+        &amount,
+        &s.token,
+        &None,
+    )
+}
+
+fn actions(env: &Env, entries: &Vec<ExportedEvent>) -> Vec<AuditAction> {
+    let mut actions = Vec::new(env);
+    for entry in entries.iter() {
+        actions.push_back(entry.action);
+    }
+    actions
+}
+
+fn seqs(env: &Env, entries: &Vec<ExportedEvent>) -> Vec<u64> {
+    let mut seqs = Vec::new(env);
+    for entry in entries.iter() {
+        seqs.push_back(entry.seq);
+    }
+    seqs
+}
+
+#[test]
+fn test_export_lists_state_changes_in_order() {
+    let s = setup_test();
+    let invoice_id = create_invoice(&s, 1_000);
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&payer, &1_000);
+    s.client.pay_invoice(&payer, &invoice_id);
+
+    let entries = s.client.export_events_since(&1, &10);
+
+    assert_eq!(
+        actions(&s.env, &entries),
+        vec![
+            &s.env,
+            AuditAction::MerchantRegistered,
+            AuditAction::InvoiceCreated,
+            AuditAction::InvoicePaid
+        ]
+    );
+    let paid = entries.get(2).unwrap();
+    assert_eq!(paid.primary_id, invoice_id);
+    assert_eq!(paid.secondary_id, 1);
+    assert_eq!(paid.amount, 1_000);
+    assert_eq!(s.client.get_audit_sequence(), 3);
+}
+
+#[test]
+fn test_export_sequence_is_contiguous_across_pages() {
+    let s = setup_test();
+    for _ in 0..6 {
+        create_invoice(&s, 1_000);
+    }
+    let latest = s.client.get_audit_sequence();
+    assert_eq!(latest, 7);
+
+    let mut seen = Vec::new(&s.env);
+    let mut next = 1;
+    loop {
+        let page = s.client.export_events_since(&next, &3);
+        if page.is_empty() {
+            break;
+        }
+        for entry in page.iter() {
+            assert_eq!(entry.seq, next);
+            seen.push_back(entry.seq);
+            next += 1;
+        }
+    }
+    assert_eq!(seen.len() as u64, latest);
+}
+
+#[test]
+fn test_export_starts_at_requested_sequence() {
+    let s = setup_test();
+    create_invoice(&s, 1_000);
+    let invoice_id = create_invoice(&s, 2_000);
+    s.client.cancel_invoice(&s.merchant, &invoice_id);
+
+    let entries = s.client.export_events_since(&3, &10);
+
+    assert_eq!(seqs(&s.env, &entries), vec![&s.env, 3, 4]);
+    assert_eq!(entries.get(0).unwrap().amount, 2_000);
+    assert_eq!(
+        entries.get(1).unwrap().action,
+        AuditAction::InvoiceCancelled
+    );
+    assert!(s.client.export_events_since(&5, &10).is_empty());
+}
+
+#[test]
+fn test_export_drops_entries_outside_retention_window() {
+    let s = setup_test();
+    s.env.cost_estimate().budget().reset_unlimited();
+    s.env.as_contract(&s.contract_id, || {
+        for id in 0..AUDIT_RETENTION + 4 {
+            audit::record(&s.env, AuditAction::InvoiceCreated, id, 0, 1);
+        }
+    });
+    let latest = s.client.get_audit_sequence();
+    assert_eq!(latest, AUDIT_RETENTION + 5);
+
+    let entries = s.client.export_events_since(&1, &2);
+
+    // the oldest retained entry is the first one an export can return
+    assert_eq!(seqs(&s.env, &entries), vec![&s.env, 6, 7]);
+    assert_eq!(
+        s.client
+            .export_events_since(&latest, &MAX_AUDIT_EXPORT)
+            .len(),
+        1
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_export_zero_limit_fails() {
+    let s = setup_test();
+    s.client.export_events_since(&1, &0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_export_limit_above_cap_fails() {
+    let s = setup_test();
+    s.client.export_events_since(&1, &(MAX_AUDIT_EXPORT + 1));
+}
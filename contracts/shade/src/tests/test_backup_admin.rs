@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+use crate::components::admin::DEFAULT_ADMIN_INACTIVITY_PERIOD;
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Env};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let backup = Address::generate(&env);
+    client.set_backup_admin(&admin, &backup);
+    client.set_admin_inactivity_period(&admin, &100);
+
+    (env, client, admin, backup)
+}
+
+#[test]
+fn test_backup_admin_is_recorded() {
+    let (_env, client, _admin, backup) = setup_test();
+    assert_eq!(client.get_backup_admin(), Some(backup));
+    assert_eq!(client.get_admin_inactivity_period(), 100);
+}
+
+#[test]
+fn test_default_inactivity_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ShadeClient::new(&env, &env.register(Shade, ()));
+    client.initialize(&Address::generate(&env));
+    assert_eq!(
+        client.get_admin_inactivity_period(),
+        DEFAULT_ADMIN_INACTIVITY_PERIOD
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #54)")]
+fn test_claim_before_inactivity_period_fails() {
+    let (env, client, _admin, backup) = setup_test();
+    env.ledger().set_timestamp(1_099);
+    client.claim_admin(&backup);
+}
+
+#[test]
+fn test_claim_at_inactivity_boundary_succeeds() {
+    let (env, client, admin, backup) = setup_test();
+    env.ledger().set_timestamp(1_100);
+    client.claim_admin(&backup);
+
+    assert_eq!(client.get_admin(), backup);
+    assert_eq!(client.get_backup_admin(), None);
+
+    // The previous admin no longer has control.
+    let result = client.try_pause(&admin);
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #54)")]
+fn test_admin_activity_resets_inactivity_clock() {
+    let (env, client, admin, backup) = setup_test();
+    env.ledger().set_timestamp(1_050);
+    client.set_max_description_len(&admin, &128);
+
+    env.ledger().set_timestamp(1_100);
+    client.claim_admin(&backup);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #54)")]
+fn test_pausing_counts_as_admin_activity() {
+    let (env, client, admin, backup) = setup_test();
+    env.ledger().set_timestamp(1_050);
+    client.pause(&admin);
+
+    env.ledger().set_timestamp(1_100);
+    client.claim_admin(&backup);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_non_backup_cannot_claim() {
+    let (env, client, _admin, _backup) = setup_test();
+    env.ledger().set_timestamp(2_000);
+    client.claim_admin(&Address::generate(&env));
+}
@@ -0,0 +1,116 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use account::account::{MerchantAccount, MerchantAccountClient};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{token, vec, Address, Env, IntoVal, Map, String, Symbol, TryIntoVal, Val, Vec};
+
+fn setup_test() -> (
+    Env,
+    ShadeClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    // 5% fee, accrued in the contract's own fee pool
+    client.set_fee(&admin, &token, &500);
+    client.set_fee_accrual(&admin, &true);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account_id = env.register(MerchantAccount, ());
+    MerchantAccountClient::new(&env, &merchant_account_id).initialize(
+        &merchant,
+        &contract_id,
+        &1_u64,
+    );
+    client.set_merchant_account(&merchant, &merchant_account_id);
+
+    (
+        env,
+        client,
+        contract_id,
+        token,
+        merchant,
+        merchant_account_id,
+    )
+}
+
+/// Returns `(holder, delta, new_balance)` for every `balance_changed` event
+/// emitted by the contract in the last invocation.
+fn balance_changes(env: &Env, contract_id: &Address) -> Vec<(Address, i128, i128)> {
+    let topic = vec![env, Symbol::new(env, "balance_changed").into_val(env)];
+    let mut changes = Vec::new(env);
+    for (event_contract_id, topics, data) in env.events().all().iter() {
+        if event_contract_id != *contract_id || topics != topic {
+            continue;
+        }
+        let data_map: Map<Symbol, Val> = data.try_into_val(env).unwrap();
+        let field = |name: &str| data_map.get(Symbol::new(env, name)).unwrap();
+        let holder: Address = field("holder").try_into_val(env).unwrap();
+        let delta: i128 = field("delta").try_into_val(env).unwrap();
+        let new_balance: i128 = field("new_balance").try_into_val(env).unwrap();
+        changes.push_back((holder, delta, new_balance));
+    }
+    changes
+}
+
+#[test]
+fn test_payment_emits_balance_changes_for_merchant_and_fee_pool() {
+    let (env, client, contract_id, token, merchant, merchant_account_id) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Ledger"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    let customer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&customer, &1000);
+    client.pay_invoice(&customer, &invoice_id);
+
+    assert_eq!(
+        balance_changes(&env, &contract_id),
+        vec![
+            &env,
+            (merchant_account_id, 950, 950),
+            (contract_id.clone(), 50, 50),
+        ]
+    );
+}
+
+#[test]
+fn test_partial_refund_emits_merchant_balance_decrease() {
+    let (env, client, contract_id, token, merchant, merchant_account_id) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Ledger"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    let customer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&customer, &1000);
+    client.pay_invoice(&customer, &invoice_id);
+
+    client.refund_invoice_partial(&invoice_id, &300);
+
+    assert_eq!(
+        balance_changes(&env, &contract_id),
+        vec![&env, (merchant_account_id, -300, 650)]
+    );
+}
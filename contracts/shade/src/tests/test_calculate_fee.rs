@@ -3,6 +3,7 @@
 use crate::components::admin as admin_component;
 use crate::shade::Shade;
 use crate::shade::ShadeClient;
+use crate::types::DustPolicy;
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::{Address, Env};
 
@@ -128,6 +129,33 @@ fn test_calculate_fee_truncates_fractional_result() {
     });
 }
 
+// By default the fee pool keeps rounding dust, so settlement fees round up.
+#[test]
+fn test_calculate_tiered_fee_rounds_up_by_default() {
+    let env = Env::default();
+    let (admin, client, token) = setup(&env);
+    let contract_id = client.address.clone();
+
+    client.set_fee(&admin, &token, &1); // 0.01%
+    assert_eq!(client.get_dust_policy(), DustPolicy::ToFeePool);
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(admin_component::calculate_tiered_fee(&env, &token, 1, 1), 1);
+        assert_eq!(
+            admin_component::calculate_tiered_fee(&env, &token, 9_999, 9_999),
+            1
+        );
+        assert_eq!(
+            admin_component::calculate_tiered_fee(&env, &token, 10_000, 10_000),
+            1
+        );
+        assert_eq!(
+            admin_component::calculate_tiered_fee(&env, &token, 10_001, 10_001),
+            2
+        );
+    });
+}
+
 // Updating the fee changes the computed amount accordingly.
 #[test]
 fn test_calculate_fee_reflects_updated_fee() {
@@ -188,3 +216,100 @@ fn test_calculate_fee_per_token_independence() {
         assert_eq!(admin_component::calculate_fee(&env, &token_b, 10_000), 700);
     });
 }
+
+#[test]
+fn test_conversion_fee_defaults_to_zero() {
+    let env = Env::default();
+    let (_admin, client, _token) = setup(&env);
+
+    assert_eq!(client.get_conversion_fee_bps(), 0);
+}
+
+#[test]
+fn test_conversion_fee_applies_only_across_tokens() {
+    let env = Env::default();
+    let (admin, client, token) = setup(&env);
+    let other_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    client.set_fee(&admin, &token, &500);
+    client.set_conversion_fee_bps(&admin, &100);
+    assert_eq!(client.get_conversion_fee_bps(), 100);
+
+    env.as_contract(&client.address, || {
+        // The base fee is unaffected by the conversion fee.
+        assert_eq!(admin_component::calculate_fee(&env, &token, 10_000), 500);
+        assert_eq!(
+            admin_component::calculate_conversion_fee(&env, &token, &token, 10_000),
+            0
+        );
+        assert_eq!(
+            admin_component::calculate_conversion_fee(&env, &other_token, &token, 10_000),
+            100
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #58)")]
+fn test_set_fee_above_10000_bps_rejected() {
+    let env = Env::default();
+    let (admin, client, token) = setup(&env);
+
+    client.set_fee(&admin, &token, &10_001);
+}
+
+#[test]
+fn test_set_fee_bps_sets_percentage_fee() {
+    let env = Env::default();
+    let (admin, client, token) = setup(&env);
+
+    client.set_fee_bps(&admin, &token, &250);
+    assert_eq!(client.get_fee(&token), 250);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #58)")]
+fn test_set_fee_bps_above_10000_rejected() {
+    let env = Env::default();
+    let (admin, client, token) = setup(&env);
+
+    client.set_fee_bps(&admin, &token, &10_001);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #58)")]
+fn test_set_negative_fee_rejected() {
+    let env = Env::default();
+    let (admin, client, token) = setup(&env);
+
+    client.set_fee(&admin, &token, &-1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #58)")]
+fn test_propose_fee_above_10000_bps_rejected() {
+    let env = Env::default();
+    let (admin, client, token) = setup(&env);
+
+    client.propose_fee(&admin, &token, &10_001);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #58)")]
+fn test_conversion_fee_above_10000_bps_rejected() {
+    let env = Env::default();
+    let (admin, client, _token) = setup(&env);
+
+    client.set_conversion_fee_bps(&admin, &10_001);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_conversion_fee_non_admin_fails() {
+    let env = Env::default();
+    let (_admin, client, _token) = setup(&env);
+
+    client.set_conversion_fee_bps(&Address::generate(&env), &100);
+}
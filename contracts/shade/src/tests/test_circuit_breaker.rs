@@ -0,0 +1,170 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{token, vec, Address, Env, IntoVal, String, Symbol};
+
+fn setup_test() -> (
+    Env,
+    ShadeClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    (env, client, contract_id, admin, token, merchant)
+}
+
+fn create_and_pay(
+    env: &Env,
+    client: &ShadeClient,
+    merchant: &Address,
+    token: &Address,
+    amount: i128,
+) -> u64 {
+    let invoice_id = client.create_invoice(
+        merchant,
+        &String::from_str(env, "Volume"),
+        &amount,
+        token,
+        &None,
+    );
+    let customer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&customer, &amount);
+    client.pay_invoice(&customer, &invoice_id);
+    invoice_id
+}
+
+#[test]
+fn test_set_circuit_breaker_stores_config() {
+    let (env, client, _contract_id, admin, token, _merchant) = setup_test();
+    env.ledger().set_timestamp(100);
+
+    client.set_circuit_breaker(&admin, &token, &3_600, &10_000);
+
+    let breaker = client.get_circuit_breaker(&token).unwrap();
+    assert_eq!(breaker.window_secs, 3_600);
+    assert_eq!(breaker.threshold, 10_000);
+    assert_eq!(breaker.window_start, 100);
+    assert_eq!(breaker.window_volume, 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_circuit_breaker_non_admin_fails() {
+    let (env, client, _contract_id, _admin, token, _merchant) = setup_test();
+    let stranger = Address::generate(&env);
+    client.set_circuit_breaker(&stranger, &token, &3_600, &10_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #21)")]
+fn test_set_circuit_breaker_zero_window_fails() {
+    let (_env, client, _contract_id, admin, token, _merchant) = setup_test();
+    client.set_circuit_breaker(&admin, &token, &0, &10_000);
+}
+
+#[test]
+fn test_volume_under_threshold_does_not_pause() {
+    let (env, client, _contract_id, admin, token, merchant) = setup_test();
+    client.set_circuit_breaker(&admin, &token, &3_600, &10_000);
+
+    create_and_pay(&env, &client, &merchant, &token, 4_000);
+    create_and_pay(&env, &client, &merchant, &token, 6_000);
+
+    assert!(!client.is_paused());
+    assert_eq!(
+        client.get_circuit_breaker(&token).unwrap().window_volume,
+        10_000
+    );
+}
+
+#[test]
+fn test_volume_over_threshold_trips_breaker() {
+    let (env, client, contract_id, admin, token, merchant) = setup_test();
+    client.set_circuit_breaker(&admin, &token, &3_600, &10_000);
+
+    create_and_pay(&env, &client, &merchant, &token, 6_000);
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Volume"),
+        &5_000,
+        &token,
+        &None,
+    );
+    let customer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&customer, &5_000);
+    client.pay_invoice(&customer, &invoice_id);
+
+    let events = env.events().all();
+    let (event_contract_id, topics, _data) = events.get(events.len() - 1).unwrap();
+    assert_eq!(event_contract_id, contract_id);
+    assert_eq!(
+        topics,
+        vec![&env, Symbol::new(&env, "circuit_tripped").into_val(&env)]
+    );
+
+    // The tripping payment itself settles, but the contract is now paused.
+    assert!(client.is_paused());
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        crate::types::InvoiceStatus::Paid
+    );
+
+    // Only a manual admin unpause restores normal operation.
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+fn test_payments_blocked_after_trip() {
+    let (env, client, _contract_id, admin, token, merchant) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Later"),
+        &1_000,
+        &token,
+        &None,
+    );
+    client.set_circuit_breaker(&admin, &token, &3_600, &10_000);
+
+    create_and_pay(&env, &client, &merchant, &token, 10_001);
+
+    let customer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&customer, &1_000);
+    client.pay_invoice(&customer, &invoice_id);
+}
+
+#[test]
+fn test_window_resets_after_elapsed() {
+    let (env, client, _contract_id, admin, token, merchant) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    client.set_circuit_breaker(&admin, &token, &3_600, &10_000);
+
+    create_and_pay(&env, &client, &merchant, &token, 8_000);
+
+    env.ledger().set_timestamp(1_000 + 3_600);
+    create_and_pay(&env, &client, &merchant, &token, 8_000);
+
+    assert!(!client.is_paused());
+    let breaker = client.get_circuit_breaker(&token).unwrap();
+    assert_eq!(breaker.window_start, 4_600);
+    assert_eq!(breaker.window_volume, 8_000);
+}
@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, BytesN, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, u64) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Shipped goods"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    (env, client, merchant, token, invoice_id)
+}
+
+fn pay(env: &Env, client: &ShadeClient, token: &Address, invoice_id: u64) {
+    let customer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&customer, &1000);
+    client.pay_invoice(&customer, &invoice_id);
+}
+
+#[test]
+fn test_submit_delivery_proof_on_paid_invoice() {
+    let (env, client, merchant, token, invoice_id) = setup_test();
+    assert_eq!(client.get_invoice(&invoice_id).delivery_proof, None);
+    pay(&env, &client, &token, invoice_id);
+
+    let proof = BytesN::from_array(&env, &[7; 32]);
+    client.submit_delivery_proof(&merchant, &invoice_id, &proof);
+
+    assert_eq!(client.get_invoice(&invoice_id).delivery_proof, Some(proof));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_submit_delivery_proof_on_pending_invoice_fails() {
+    let (env, client, merchant, _token, invoice_id) = setup_test();
+    client.submit_delivery_proof(&merchant, &invoice_id, &BytesN::from_array(&env, &[7; 32]));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_delivery_proof_cannot_be_replaced() {
+    let (env, client, merchant, token, invoice_id) = setup_test();
+    pay(&env, &client, &token, invoice_id);
+
+    client.submit_delivery_proof(&merchant, &invoice_id, &BytesN::from_array(&env, &[7; 32]));
+    client.submit_delivery_proof(&merchant, &invoice_id, &BytesN::from_array(&env, &[8; 32]));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_submit_delivery_proof_by_other_merchant_fails() {
+    let (env, client, _merchant, token, invoice_id) = setup_test();
+    pay(&env, &client, &token, invoice_id);
+
+    let other_merchant = Address::generate(&env);
+    client.register_merchant(&other_merchant, &None, &None);
+    client.submit_delivery_proof(
+        &other_merchant,
+        &invoice_id,
+        &BytesN::from_array(&env, &[7; 32]),
+    );
+}
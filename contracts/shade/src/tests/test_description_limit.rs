@@ -0,0 +1,121 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+
+    (env, client, admin, merchant, token)
+}
+
+fn description_of_len(env: &Env, len: usize) -> String {
+    let bytes = [b'a'; 512];
+    String::from_bytes(env, &bytes[..len])
+}
+
+#[test]
+fn test_default_max_description_len() {
+    let (_env, client, _admin, _merchant, _token) = setup_test();
+    assert_eq!(client.get_max_description_len(), 256);
+}
+
+#[test]
+fn test_description_at_default_limit_accepted() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    let description = description_of_len(&env, 256);
+    let invoice_id = client.create_invoice(&merchant, &description, &1000, &token, &None);
+    assert_eq!(client.get_invoice(&invoice_id).description, description);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #44)")]
+fn test_description_over_default_limit_rejected() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    client.create_invoice(
+        &merchant,
+        &description_of_len(&env, 257),
+        &1000,
+        &token,
+        &None,
+    );
+}
+
+#[test]
+fn test_set_max_description_len() {
+    let (env, client, admin, merchant, token) = setup_test();
+    client.set_max_description_len(&admin, &10);
+    assert_eq!(client.get_max_description_len(), 10);
+
+    client.create_invoice(
+        &merchant,
+        &description_of_len(&env, 10),
+        &1000,
+        &token,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #44)")]
+fn test_configured_limit_enforced_on_create() {
+    let (env, client, admin, merchant, token) = setup_test();
+    client.set_max_description_len(&admin, &10);
+    client.create_invoice(
+        &merchant,
+        &description_of_len(&env, 11),
+        &1000,
+        &token,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #44)")]
+fn test_configured_limit_enforced_on_amend() {
+    let (env, client, admin, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &description_of_len(&env, 10),
+        &1000,
+        &token,
+        &None,
+    );
+
+    client.set_max_description_len(&admin, &10);
+    client.amend_invoice(
+        &merchant,
+        &invoice_id,
+        &None,
+        &Some(description_of_len(&env, 11)),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_max_description_len_non_admin_fails() {
+    let (env, client, _admin, _merchant, _token) = setup_test();
+    let stranger = Address::generate(&env);
+    client.set_max_description_len(&stranger, &10);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_set_max_description_len_zero_fails() {
+    let (_env, client, admin, _merchant, _token) = setup_test();
+    client.set_max_description_len(&admin, &0);
+}
@@ -0,0 +1,292 @@
+#![cfg(test)]
+
+use crate::components::invoice::MAX_PAYER_ASSIGN_BATCH;
+use crate::errors::ContractError;
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, vec, Address, Env, String, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, u64) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Designated"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    (env, client, merchant, token, invoice_id)
+}
+
+fn fund(env: &Env, token: &Address, payer: &Address) {
+    token::StellarAssetClient::new(env, token).mint(payer, &1000);
+}
+
+#[test]
+fn test_assign_payer_records_designated_payer() {
+    let (env, client, merchant, _token, invoice_id) = setup_test();
+    let payer = Address::generate(&env);
+
+    client.assign_payer(&merchant, &invoice_id, &payer);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.designated_payer, Some(payer.clone()));
+    assert_eq!(
+        client.get_payer_outstanding_invoices(&payer),
+        vec![&env, invoice_id]
+    );
+}
+
+#[test]
+fn test_designated_payer_can_pay() {
+    let (env, client, merchant, token, invoice_id) = setup_test();
+    let payer = Address::generate(&env);
+    client.assign_payer(&merchant, &invoice_id, &payer);
+
+    fund(&env, &token, &payer);
+    client.pay_invoice(&payer, &invoice_id);
+
+    assert_eq!(client.get_invoice(&invoice_id).status, InvoiceStatus::Paid);
+    assert_eq!(
+        client.get_payer_outstanding_invoices(&payer),
+        Vec::new(&env)
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #46)")]
+fn test_other_payer_rejected() {
+    let (env, client, merchant, token, invoice_id) = setup_test();
+    client.assign_payer(&merchant, &invoice_id, &Address::generate(&env));
+
+    let stranger = Address::generate(&env);
+    fund(&env, &token, &stranger);
+    client.pay_invoice(&stranger, &invoice_id);
+}
+
+#[test]
+fn test_reassign_moves_invoice_between_payers() {
+    let (env, client, merchant, _token, invoice_id) = setup_test();
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    client.assign_payer(&merchant, &invoice_id, &first);
+    client.assign_payer(&merchant, &invoice_id, &second);
+
+    assert_eq!(
+        client.get_payer_outstanding_invoices(&first),
+        Vec::new(&env)
+    );
+    assert_eq!(
+        client.get_payer_outstanding_invoices(&second),
+        vec![&env, invoice_id]
+    );
+}
+
+#[test]
+fn test_clear_payer_reopens_invoice() {
+    let (env, client, merchant, token, invoice_id) = setup_test();
+    let payer = Address::generate(&env);
+    client.assign_payer(&merchant, &invoice_id, &payer);
+
+    client.clear_payer(&merchant, &invoice_id);
+    assert_eq!(client.get_invoice(&invoice_id).designated_payer, None);
+    assert_eq!(
+        client.get_payer_outstanding_invoices(&payer),
+        Vec::new(&env)
+    );
+
+    let anyone = Address::generate(&env);
+    fund(&env, &token, &anyone);
+    client.pay_invoice(&anyone, &invoice_id);
+    assert_eq!(client.get_invoice(&invoice_id).status, InvoiceStatus::Paid);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_assign_payer_by_other_merchant_fails() {
+    let (env, client, _merchant, _token, invoice_id) = setup_test();
+    let other_merchant = Address::generate(&env);
+    client.register_merchant(&other_merchant, &None, &None);
+
+    client.assign_payer(&other_merchant, &invoice_id, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_assign_payer_on_paid_invoice_fails() {
+    let (env, client, merchant, token, invoice_id) = setup_test();
+    let payer = Address::generate(&env);
+    fund(&env, &token, &payer);
+    client.pay_invoice(&payer, &invoice_id);
+
+    client.assign_payer(&merchant, &invoice_id, &payer);
+}
+
+#[test]
+fn test_transfer_payer_moves_right_to_pay() {
+    let (env, client, merchant, token, invoice_id) = setup_test();
+    let payer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.assign_payer(&merchant, &invoice_id, &payer);
+
+    client.transfer_invoice_payer(&payer, &invoice_id, &recipient);
+
+    assert_eq!(
+        client.get_invoice(&invoice_id).designated_payer,
+        Some(recipient.clone())
+    );
+    assert_eq!(
+        client.get_payer_outstanding_invoices(&payer),
+        Vec::new(&env)
+    );
+    assert_eq!(
+        client.get_payer_outstanding_invoices(&recipient),
+        vec![&env, invoice_id]
+    );
+
+    fund(&env, &token, &recipient);
+    client.pay_invoice(&recipient, &invoice_id);
+    assert_eq!(client.get_invoice(&invoice_id).status, InvoiceStatus::Paid);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #46)")]
+fn test_transferred_away_payer_cannot_pay() {
+    let (env, client, merchant, token, invoice_id) = setup_test();
+    let payer = Address::generate(&env);
+    client.assign_payer(&merchant, &invoice_id, &payer);
+    client.transfer_invoice_payer(&payer, &invoice_id, &Address::generate(&env));
+
+    fund(&env, &token, &payer);
+    client.pay_invoice(&payer, &invoice_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #46)")]
+fn test_transfer_payer_by_non_designated_payer_fails() {
+    let (env, client, merchant, _token, invoice_id) = setup_test();
+    client.assign_payer(&merchant, &invoice_id, &Address::generate(&env));
+
+    let stranger = Address::generate(&env);
+    client.transfer_invoice_payer(&stranger, &invoice_id, &stranger);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #29)")]
+fn test_transfer_payer_without_designated_payer_fails() {
+    let (env, client, _merchant, _token, invoice_id) = setup_test();
+    let payer = Address::generate(&env);
+    client.transfer_invoice_payer(&payer, &invoice_id, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_transfer_payer_on_paid_invoice_fails() {
+    let (env, client, merchant, token, invoice_id) = setup_test();
+    let payer = Address::generate(&env);
+    client.assign_payer(&merchant, &invoice_id, &payer);
+    fund(&env, &token, &payer);
+    client.pay_invoice(&payer, &invoice_id);
+
+    client.transfer_invoice_payer(&payer, &invoice_id, &Address::generate(&env));
+}
+
+#[test]
+fn test_assign_payers_sets_each_designated_payer() {
+    let (env, client, merchant, token, first_id) = setup_test();
+    let second_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Second"),
+        &500,
+        &token,
+        &None,
+    );
+    let first_payer = Address::generate(&env);
+    let second_payer = Address::generate(&env);
+
+    client.assign_payers(
+        &merchant,
+        &vec![
+            &env,
+            (first_id, first_payer.clone()),
+            (second_id, second_payer.clone()),
+        ],
+    );
+
+    assert_eq!(
+        client.get_invoice(&first_id).designated_payer,
+        Some(first_payer.clone())
+    );
+    assert_eq!(
+        client.get_invoice(&second_id).designated_payer,
+        Some(second_payer.clone())
+    );
+    assert_eq!(
+        client.get_payer_outstanding_invoices(&first_payer),
+        vec![&env, first_id]
+    );
+    assert_eq!(
+        client.get_payer_outstanding_invoices(&second_payer),
+        vec![&env, second_id]
+    );
+}
+
+#[test]
+fn test_assign_payers_reverts_batch_on_ownership_mismatch() {
+    let (env, client, merchant, token, own_id) = setup_test();
+    let other_merchant = Address::generate(&env);
+    client.register_merchant(&other_merchant, &None, &None);
+    let foreign_id = client.create_invoice(
+        &other_merchant,
+        &String::from_str(&env, "Foreign"),
+        &1000,
+        &token,
+        &None,
+    );
+    let payer = Address::generate(&env);
+
+    let result = client.try_assign_payers(
+        &merchant,
+        &vec![&env, (own_id, payer.clone()), (foreign_id, payer.clone())],
+    );
+
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            ContractError::NotAuthorized as u32
+        )))
+    );
+    assert_eq!(client.get_invoice(&own_id).designated_payer, None);
+    assert_eq!(client.get_payer_outstanding_invoices(&payer).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_assign_payers_rejects_oversized_batch() {
+    let (env, client, merchant, _token, invoice_id) = setup_test();
+    let payer = Address::generate(&env);
+    let mut assignments = Vec::new(&env);
+    for _ in 0..=MAX_PAYER_ASSIGN_BATCH {
+        assignments.push_back((invoice_id, payer.clone()));
+    }
+
+    client.assign_payers(&merchant, &assignments);
+}
@@ -30,7 +30,7 @@ fn test_create_draft_invoice() {
     let (env, client, _contract_id, _admin, token) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let description = String::from_str(&env, "Draft Invoice");
     let amount: i128 = 1000;
@@ -54,7 +54,7 @@ fn test_pay_draft_invoice_fails() {
     let (env, client, _contract_id, _admin, token) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let description = String::from_str(&env, "Draft Invoice");
     let invoice_id = client.create_invoice_draft(&merchant, &description, &1000, &token, &None);
@@ -72,7 +72,7 @@ fn test_finalize_invoice_success() {
     let (env, client, _contract_id, _admin, token) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let description = String::from_str(&env, "Draft Invoice");
     let invoice_id = client.create_invoice_draft(&merchant, &description, &1000, &token, &None);
@@ -95,7 +95,7 @@ fn test_pay_finalized_invoice_success() {
 
     // Register merchant
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Create merchant account
     let merchant_account = env.register(MerchantAccount, ());
@@ -128,13 +128,13 @@ fn test_finalize_invoice_non_owner_fails() {
     let (env, client, _contract_id, _admin, token) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let description = String::from_str(&env, "Draft Invoice");
     let invoice_id = client.create_invoice_draft(&merchant, &description, &1000, &token, &None);
 
     let other_merchant = Address::generate(&env);
-    client.register_merchant(&other_merchant);
+    client.register_merchant(&other_merchant, &None, &None);
 
     // Try to finalize with different merchant (should panic with NotAuthorized = #1)
     client.finalize_invoice(&other_merchant, &invoice_id);
@@ -146,7 +146,7 @@ fn test_finalize_non_draft_invoice_fails() {
     let (env, client, _contract_id, _admin, token) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let description = String::from_str(&env, "Standard Invoice");
     let invoice_id = client.create_invoice(&merchant, &description, &1000, &token, &None);
@@ -0,0 +1,199 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::DustPolicy;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, vec, Address, Env, String};
+
+struct Setup {
+    env: Env,
+    client: ShadeClient<'static>,
+    admin: Address,
+    token: Address,
+    merchant: Address,
+    merchant_account: Address,
+}
+
+// 5% fee, so an invoice of 1_010 owes 50.5: one stroop of dust.
+fn setup_test(policy: Option<DustPolicy>) -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ShadeClient::new(&env, &env.register(Shade, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    client.set_fee(&admin, &token, &500);
+    if let Some(policy) = policy {
+        client.set_dust_policy(&admin, &policy);
+    }
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account = Address::generate(&env);
+    client.set_merchant_account(&merchant, &merchant_account);
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+        merchant,
+        merchant_account,
+    }
+}
+
+fn pay(s: &Setup, amount: i128) -> Address {
+    let invoice_id = s.client.create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Dust"),
+        &amount,
+        &s.token,
+        &None,
+    );
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&payer, &amount);
+    s.client.pay_invoice(&payer, &invoice_id);
+    payer
+}
+
+fn balance(s: &Setup, holder: &Address) -> i128 {
+    token::TokenClient::new(&s.env, &s.token).balance(holder)
+}
+
+#[test]
+fn test_dust_goes_to_fee_pool_by_default() {
+    let s = setup_test(None);
+    assert_eq!(s.client.get_dust_policy(), DustPolicy::ToFeePool);
+
+    let payer = pay(&s, 1_010);
+
+    assert_eq!(balance(&s, &s.admin), 51);
+    assert_eq!(balance(&s, &s.merchant_account), 959);
+    assert_eq!(balance(&s, &payer), 0);
+    assert_eq!(s.client.get_invoice(&1).fee_paid, 51);
+}
+
+#[test]
+fn test_dust_goes_to_merchant() {
+    let s = setup_test(Some(DustPolicy::ToMerchant));
+
+    let payer = pay(&s, 1_010);
+
+    assert_eq!(balance(&s, &s.admin), 50);
+    assert_eq!(balance(&s, &s.merchant_account), 960);
+    assert_eq!(balance(&s, &payer), 0);
+}
+
+#[test]
+fn test_dust_stays_with_payer() {
+    let s = setup_test(Some(DustPolicy::ToPayer));
+
+    let payer = pay(&s, 1_010);
+
+    assert_eq!(balance(&s, &s.admin), 50);
+    assert_eq!(balance(&s, &s.merchant_account), 959);
+    assert_eq!(balance(&s, &payer), 1);
+    assert_eq!(s.client.get_invoice(&1).amount_paid, 1_010);
+}
+
+#[test]
+fn test_even_fee_leaves_no_dust_under_any_policy() {
+    for policy in [
+        DustPolicy::ToMerchant,
+        DustPolicy::ToFeePool,
+        DustPolicy::ToPayer,
+    ] {
+        let s = setup_test(Some(policy));
+        let payer = pay(&s, 1_000);
+
+        assert_eq!(balance(&s, &s.admin), 50);
+        assert_eq!(balance(&s, &s.merchant_account), 950);
+        assert_eq!(balance(&s, &payer), 0);
+    }
+}
+
+#[test]
+fn test_dust_policy_applies_per_split_share() {
+    let s = setup_test(Some(DustPolicy::ToPayer));
+    let partner = Address::generate(&s.env);
+    s.client.register_merchant(&partner, &None, &None);
+    let partner_account = Address::generate(&s.env);
+    s.client.set_merchant_account(&partner, &partner_account);
+
+    // each 505 share owes 25.25
+    let invoice_id = s.client.create_split_invoice(
+        &s.merchant,
+        &vec![&s.env, (1, 505), (2, 505)],
+        &s.token,
+        &String::from_str(&s.env, "Split dust"),
+    );
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&payer, &1_010);
+    s.client.pay_invoice(&payer, &invoice_id);
+
+    assert_eq!(balance(&s, &s.merchant_account), 479);
+    assert_eq!(balance(&s, &partner_account), 479);
+    assert_eq!(balance(&s, &s.admin), 50);
+    assert_eq!(balance(&s, &payer), 2);
+}
+
+#[test]
+fn test_dust_returned_to_payer_from_escrow() {
+    let s = setup_test(Some(DustPolicy::ToPayer));
+    let invoice_id = s.client.create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Escrowed dust"),
+        &1_010,
+        &s.token,
+        &None,
+    );
+    s.client
+        .set_requires_confirmation(&s.merchant, &invoice_id, &true);
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&payer, &1_010);
+    s.client.pay_invoice(&payer, &invoice_id);
+    assert_eq!(balance(&s, &payer), 0);
+
+    s.client.confirm_receipt(&payer, &invoice_id);
+
+    assert_eq!(balance(&s, &s.merchant_account), 959);
+    assert_eq!(balance(&s, &s.admin), 50);
+    assert_eq!(balance(&s, &payer), 1);
+}
+
+#[test]
+fn test_quote_total_excludes_payer_dust() {
+    let s = setup_test(Some(DustPolicy::ToPayer));
+    let invoice_id = s.client.create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Quoted dust"),
+        &1_010,
+        &s.token,
+        &None,
+    );
+
+    let quote = s
+        .client
+        .quote_payment(&invoice_id, &s.token, &Address::generate(&s.env));
+    assert_eq!(quote.base_fee, 50);
+    assert_eq!(quote.total, 1_009);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_dust_policy_requires_admin() {
+    let s = setup_test(None);
+    s.client
+        .set_dust_policy(&Address::generate(&s.env), &DustPolicy::ToPayer);
+}
+
+#[test]
+fn test_set_dust_policy_updates_policy() {
+    let s = setup_test(None);
+    s.client.set_dust_policy(&s.admin, &DustPolicy::ToMerchant);
+    assert_eq!(s.client.get_dust_policy(), DustPolicy::ToMerchant);
+}
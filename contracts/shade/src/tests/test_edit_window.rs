@@ -0,0 +1,155 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String};
+
+const WINDOW: u64 = 3_600;
+
+struct Setup {
+    env: Env,
+    client: ShadeClient<'static>,
+    admin: Address,
+    token: Address,
+    merchant: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+        merchant,
+    }
+}
+
+fn create_invoice(s: &Setup) -> u64 {
+    s.client.create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Edit window"),
+        &1_000,
+        &s.token,
+        &None,
+    )
+}
+
+fn advance(s: &Setup, secs: u64) {
+    s.env
+        .ledger()
+        .with_mut(|li| li.timestamp = li.timestamp.saturating_add(secs));
+}
+
+#[test]
+fn test_edit_window_defaults_to_unlimited() {
+    let s = setup_test();
+    assert_eq!(s.client.get_edit_window(), 0);
+
+    let invoice_id = create_invoice(&s);
+    advance(&s, 365 * 24 * 60 * 60);
+    s.client
+        .amend_invoice(&s.merchant, &invoice_id, &Some(2_000), &None);
+
+    assert_eq!(s.client.get_invoice(&invoice_id).amount, 2_000);
+}
+
+#[test]
+fn test_set_edit_window_updates_value() {
+    let s = setup_test();
+    s.client.set_edit_window(&s.admin, &WINDOW);
+    assert_eq!(s.client.get_edit_window(), WINDOW);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_edit_window_requires_admin() {
+    let s = setup_test();
+    let stranger = Address::generate(&s.env);
+    s.client.set_edit_window(&stranger, &WINDOW);
+}
+
+#[test]
+fn test_edits_allowed_within_window() {
+    let s = setup_test();
+    s.client.set_edit_window(&s.admin, &WINDOW);
+    let invoice_id = create_invoice(&s);
+    advance(&s, WINDOW - 1);
+
+    let description = String::from_str(&s.env, "Updated");
+    s.client
+        .amend_invoice(&s.merchant, &invoice_id, &None, &Some(description.clone()));
+    let new_token = s
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&s.env))
+        .address();
+    s.client.add_accepted_token(&s.admin, &new_token);
+    s.client
+        .change_invoice_token(&s.merchant, &invoice_id, &new_token);
+
+    let invoice = s.client.get_invoice(&invoice_id);
+    assert_eq!(invoice.description, description);
+    assert_eq!(invoice.token, new_token);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #59)")]
+fn test_amend_after_window_fails() {
+    let s = setup_test();
+    s.client.set_edit_window(&s.admin, &WINDOW);
+    let invoice_id = create_invoice(&s);
+    advance(&s, WINDOW);
+
+    s.client
+        .amend_invoice(&s.merchant, &invoice_id, &Some(2_000), &None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #59)")]
+fn test_change_token_after_window_fails() {
+    let s = setup_test();
+    s.client.set_edit_window(&s.admin, &WINDOW);
+    let invoice_id = create_invoice(&s);
+    let new_token = s
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&s.env))
+        .address();
+    s.client.add_accepted_token(&s.admin, &new_token);
+    advance(&s, WINDOW + 1);
+
+    s.client
+        .change_invoice_token(&s.merchant, &invoice_id, &new_token);
+}
+
+#[test]
+fn test_clearing_window_restores_edits() {
+    let s = setup_test();
+    s.client.set_edit_window(&s.admin, &WINDOW);
+    let invoice_id = create_invoice(&s);
+    advance(&s, WINDOW * 2);
+
+    let result = s
+        .client
+        .try_amend_invoice(&s.merchant, &invoice_id, &Some(2_000), &None);
+    assert!(result.is_err());
+
+    s.client.set_edit_window(&s.admin, &0);
+    s.client
+        .amend_invoice(&s.merchant, &invoice_id, &Some(2_000), &None);
+    assert_eq!(s.client.get_invoice(&invoice_id).amount, 2_000);
+}
@@ -0,0 +1,131 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    // 5% fee, accrued in the contract's own fee pool
+    client.set_fee(&admin, &token, &500);
+    client.set_fee_accrual(&admin, &true);
+
+    // Collect some fees into the contract.
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Fees"),
+        &1000,
+        &token,
+        &None,
+    );
+    let customer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&customer, &1000);
+    client.pay_invoice(&customer, &invoice_id);
+
+    (env, client, contract_id, admin, token)
+}
+
+#[test]
+fn test_emergency_withdraw_sweeps_balance_while_paused() {
+    let (env, client, contract_id, admin, token) = setup_test();
+    let token_client = token::TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&contract_id), 50);
+
+    client.pause(&admin);
+    let recovery = Address::generate(&env);
+    client.emergency_withdraw(&admin, &token, &recovery);
+
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(token_client.balance(&recovery), 50);
+}
+
+#[test]
+fn test_emergency_withdraw_sweeps_holdbacks() {
+    let (env, client, contract_id, admin, token) = setup_test();
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+    // hold back 20% of the second merchant's net
+    client.set_merchant_holdback_bps(&admin, &2, &2_000);
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Held"),
+        &1000,
+        &token,
+        &None,
+    );
+    let customer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&customer, &1000);
+    client.pay_invoice(&customer, &invoice_id);
+    let token_client = token::TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&contract_id), 50 + 50 + 190);
+
+    client.pause(&admin);
+    let recovery = Address::generate(&env);
+    client.emergency_withdraw(&admin, &token, &recovery);
+
+    assert_eq!(token_client.balance(&recovery), 290);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(client.get_pool_totals(&token), (0, 0));
+    assert_eq!(client.get_holdback(&2, &token), 0);
+}
+
+#[test]
+fn test_emergency_withdraw_writes_off_escrowed_invoices() {
+    let (env, client, contract_id, admin, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &client.get_merchant(&1).address,
+        &String::from_str(&env, "Escrow"),
+        &1000,
+        &token,
+        &None,
+    );
+    client.set_requires_confirmation(&client.get_merchant(&1).address, &invoice_id, &true);
+    let customer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&customer, &1000);
+    client.pay_invoice(&customer, &invoice_id);
+    assert_eq!(client.get_pool_totals(&token), (1000, 50));
+
+    client.pause(&admin);
+    let recovery = Address::generate(&env);
+    client.emergency_withdraw(&admin, &token, &recovery);
+
+    let token_client = token::TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&recovery), 1050);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(client.get_pool_totals(&token), (0, 0));
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::WrittenOff);
+    assert_eq!(invoice.escrowed_amount, 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn test_emergency_withdraw_fails_while_unpaused() {
+    let (env, client, _contract_id, admin, token) = setup_test();
+    client.emergency_withdraw(&admin, &token, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_emergency_withdraw_non_admin_fails() {
+    let (env, client, _contract_id, admin, token) = setup_test();
+    client.pause(&admin);
+
+    let stranger = Address::generate(&env);
+    client.emergency_withdraw(&stranger, &token, &stranger);
+}
@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+use crate::components::invoice::MAX_ENCRYPTED_DATA_LEN;
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, Bytes, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, u64) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Private order"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    (env, client, merchant, token, invoice_id)
+}
+
+#[test]
+fn test_encrypted_data_stored_and_returned_opaquely() {
+    let (env, client, merchant, _token, invoice_id) = setup_test();
+    assert_eq!(client.get_invoice(&invoice_id).encrypted_data, None);
+
+    let blob = Bytes::from_slice(&env, &[0xde, 0xad, 0xbe, 0xef, 0x00, 0x01]);
+    client.set_encrypted_data(&merchant, &invoice_id, &Some(blob.clone()));
+    assert_eq!(client.get_invoice(&invoice_id).encrypted_data, Some(blob));
+
+    client.set_encrypted_data(&merchant, &invoice_id, &None);
+    assert_eq!(client.get_invoice(&invoice_id).encrypted_data, None);
+}
+
+#[test]
+fn test_encrypted_data_at_size_limit_accepted() {
+    let (env, client, merchant, _token, invoice_id) = setup_test();
+    let blob = Bytes::from_slice(&env, &[7u8; MAX_ENCRYPTED_DATA_LEN as usize]);
+
+    client.set_encrypted_data(&merchant, &invoice_id, &Some(blob.clone()));
+    assert_eq!(client.get_invoice(&invoice_id).encrypted_data, Some(blob));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #47)")]
+fn test_encrypted_data_over_size_limit_fails() {
+    let (env, client, merchant, _token, invoice_id) = setup_test();
+    let blob = Bytes::from_slice(&env, &[7u8; MAX_ENCRYPTED_DATA_LEN as usize + 1]);
+
+    client.set_encrypted_data(&merchant, &invoice_id, &Some(blob));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_encrypted_data_non_owner_fails() {
+    let (env, client, _merchant, _token, invoice_id) = setup_test();
+    let stranger = Address::generate(&env);
+    client.register_merchant(&stranger, &None, &None);
+
+    client.set_encrypted_data(
+        &stranger,
+        &invoice_id,
+        &Some(Bytes::from_slice(&env, &[1, 2, 3])),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_encrypted_data_on_paid_invoice_fails() {
+    let (env, client, merchant, token, invoice_id) = setup_test();
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    client.set_encrypted_data(
+        &merchant,
+        &invoice_id,
+        &Some(Bytes::from_slice(&env, &[1, 2, 3])),
+    );
+}
@@ -0,0 +1,235 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{token, Address, Env, Error, String};
+
+struct Ctx {
+    env: Env,
+    client: ShadeClient<'static>,
+    admin: Address,
+    merchant: Address,
+    merchant_account: Address,
+    payer: Address,
+    token: Address,
+    invoice_id: u64,
+}
+
+fn setup_funded() -> Ctx {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    let merchant_account = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &merchant_account);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Escrowed goods"),
+        &1000,
+        &token,
+        &None,
+    );
+    client.set_requires_confirmation(&merchant, &invoice_id, &true);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    Ctx {
+        env,
+        client,
+        admin,
+        merchant,
+        merchant_account,
+        payer,
+        token,
+        invoice_id,
+    }
+}
+
+fn balance(ctx: &Ctx, who: &Address) -> i128 {
+    token::TokenClient::new(&ctx.env, &ctx.token).balance(who)
+}
+
+fn assert_contract_error<T: core::fmt::Debug>(
+    result: Result<T, Result<Error, soroban_sdk::InvokeError>>,
+    expected: ContractError,
+) {
+    assert_eq!(
+        result.unwrap_err(),
+        Ok(Error::from_contract_error(expected as u32))
+    );
+}
+
+#[test]
+fn test_payment_is_held_in_escrow() {
+    let ctx = setup_funded();
+
+    let invoice = ctx.client.get_invoice(&ctx.invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Funded);
+    assert_eq!(invoice.payer, Some(ctx.payer.clone()));
+    assert_eq!(invoice.funded_at, Some(1_000));
+    assert_eq!(invoice.amount_paid, 0);
+    assert_eq!(balance(&ctx, &ctx.client.address), 1000);
+    assert_eq!(balance(&ctx, &ctx.merchant_account), 0);
+}
+
+#[test]
+fn test_confirm_receipt_releases_to_merchant() {
+    let ctx = setup_funded();
+
+    ctx.client.confirm_receipt(&ctx.payer, &ctx.invoice_id);
+
+    let invoice = ctx.client.get_invoice(&ctx.invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.amount_paid, 1000);
+    assert_eq!(balance(&ctx, &ctx.merchant_account), 1000);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+}
+
+#[test]
+fn test_confirm_receipt_by_other_address_fails() {
+    let ctx = setup_funded();
+    let stranger = Address::generate(&ctx.env);
+
+    assert_contract_error(
+        ctx.client.try_confirm_receipt(&stranger, &ctx.invoice_id),
+        ContractError::NotAuthorized,
+    );
+}
+
+#[test]
+fn test_confirm_receipt_requires_funded_invoice() {
+    let ctx = setup_funded();
+    ctx.client.confirm_receipt(&ctx.payer, &ctx.invoice_id);
+
+    assert_contract_error(
+        ctx.client.try_confirm_receipt(&ctx.payer, &ctx.invoice_id),
+        ContractError::InvalidInvoiceStatus,
+    );
+}
+
+#[test]
+fn test_claim_after_timeout() {
+    let ctx = setup_funded();
+    let timeout = ctx.client.get_receipt_timeout();
+
+    ctx.env.ledger().set_timestamp(1_000 + timeout - 1);
+    assert_contract_error(
+        ctx.client
+            .try_claim_after_timeout(&ctx.merchant, &ctx.invoice_id),
+        ContractError::ReleaseTooEarly,
+    );
+
+    ctx.env.ledger().set_timestamp(1_000 + timeout);
+    ctx.client
+        .claim_after_timeout(&ctx.merchant, &ctx.invoice_id);
+
+    let invoice = ctx.client.get_invoice(&ctx.invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(balance(&ctx, &ctx.merchant_account), 1000);
+}
+
+#[test]
+fn test_set_receipt_timeout() {
+    let ctx = setup_funded();
+    ctx.client.set_receipt_timeout(&ctx.admin, &100);
+    assert_eq!(ctx.client.get_receipt_timeout(), 100);
+
+    ctx.env.ledger().set_timestamp(1_100);
+    ctx.client
+        .claim_after_timeout(&ctx.merchant, &ctx.invoice_id);
+    assert_eq!(
+        ctx.client.get_invoice(&ctx.invoice_id).status,
+        InvoiceStatus::Paid
+    );
+
+    assert_contract_error(
+        ctx.client.try_set_receipt_timeout(&ctx.admin, &0),
+        ContractError::InvalidAmount,
+    );
+}
+
+#[test]
+fn test_dispute_resolved_with_refund() {
+    let ctx = setup_funded();
+    ctx.client.dispute_invoice(&ctx.payer, &ctx.invoice_id);
+    assert_eq!(
+        ctx.client.get_invoice(&ctx.invoice_id).status,
+        InvoiceStatus::Disputed
+    );
+
+    // A disputed invoice can no longer be confirmed or claimed.
+    assert_contract_error(
+        ctx.client.try_confirm_receipt(&ctx.payer, &ctx.invoice_id),
+        ContractError::InvalidInvoiceStatus,
+    );
+
+    ctx.client
+        .resolve_dispute(&ctx.admin, &ctx.invoice_id, &false);
+
+    let invoice = ctx.client.get_invoice(&ctx.invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Refunded);
+    assert_eq!(balance(&ctx, &ctx.payer), 1000);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+    assert_eq!(ctx.client.get_outstanding_liability(&ctx.token), 0);
+}
+
+#[test]
+fn test_dispute_resolved_for_merchant() {
+    let ctx = setup_funded();
+    ctx.client.dispute_invoice(&ctx.payer, &ctx.invoice_id);
+    ctx.client
+        .resolve_dispute(&ctx.admin, &ctx.invoice_id, &true);
+
+    let invoice = ctx.client.get_invoice(&ctx.invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(balance(&ctx, &ctx.merchant_account), 1000);
+}
+
+#[test]
+fn test_dispute_after_deadline_fails() {
+    let ctx = setup_funded();
+    let timeout = ctx.client.get_receipt_timeout();
+    ctx.env.ledger().set_timestamp(1_000 + timeout);
+
+    assert_contract_error(
+        ctx.client.try_dispute_invoice(&ctx.payer, &ctx.invoice_id),
+        ContractError::InvoiceExpired,
+    );
+}
+
+#[test]
+fn test_escrow_rejects_partial_payment() {
+    let ctx = setup_funded();
+    let invoice_id = ctx.client.create_invoice(
+        &ctx.merchant,
+        &String::from_str(&ctx.env, "Partial"),
+        &1000,
+        &ctx.token,
+        &None,
+    );
+    ctx.client
+        .set_requires_confirmation(&ctx.merchant, &invoice_id, &true);
+    token::StellarAssetClient::new(&ctx.env, &ctx.token).mint(&ctx.payer, &500);
+
+    assert_contract_error(
+        ctx.client
+            .try_pay_invoice_partial(&ctx.payer, &invoice_id, &500),
+        ContractError::InvalidAmount,
+    );
+}
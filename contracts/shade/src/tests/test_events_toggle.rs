@@ -0,0 +1,125 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{token, vec, Address, Env, IntoVal, String, Symbol, Val, Vec};
+
+struct Setup {
+    env: Env,
+    client: ShadeClient<'static>,
+    contract_id: Address,
+    admin: Address,
+    token: Address,
+    merchant: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    Setup {
+        env,
+        client,
+        contract_id,
+        admin,
+        token,
+        merchant,
+    }
+}
+
+fn create_invoice(s: &Setup) -> u64 {
+    s.client.create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Events"),
+        &1_000,
+        &s.token,
+        &None,
+    )
+}
+
+// Topics of the events the last call published from the Shade contract.
+fn contract_event_topics(s: &Setup) -> Vec<Vec<Val>> {
+    let mut topics = Vec::new(&s.env);
+    for (contract_id, event_topics, _) in s.env.events().all().iter() {
+        if contract_id == s.contract_id {
+            topics.push_back(event_topics);
+        }
+    }
+    topics
+}
+
+#[test]
+fn test_events_enabled_by_default() {
+    let s = setup_test();
+    assert!(s.client.are_events_enabled());
+
+    create_invoice(&s);
+    assert!(!contract_event_topics(&s).is_empty());
+}
+
+#[test]
+fn test_disabled_events_skip_optional_events() {
+    let s = setup_test();
+    s.client.set_events_enabled(&s.admin, &false);
+    assert!(!s.client.are_events_enabled());
+
+    let invoice_id = create_invoice(&s);
+
+    assert!(contract_event_topics(&s).is_empty());
+    assert_eq!(
+        s.client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Pending
+    );
+}
+
+#[test]
+fn test_disabled_events_keep_settlement_event() {
+    let s = setup_test();
+    s.client.set_events_enabled(&s.admin, &false);
+    let invoice_id = create_invoice(&s);
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&payer, &1_000);
+
+    s.client.pay_invoice(&payer, &invoice_id);
+
+    let invoice_paid: Vec<Val> = vec![
+        &s.env,
+        Symbol::new(&s.env, "invoice_paid_event").into_val(&s.env),
+    ];
+    assert_eq!(contract_event_topics(&s), vec![&s.env, invoice_paid]);
+    assert_eq!(
+        s.client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Paid
+    );
+}
+
+#[test]
+fn test_reenabling_restores_events() {
+    let s = setup_test();
+    s.client.set_events_enabled(&s.admin, &false);
+    s.client.set_events_enabled(&s.admin, &true);
+
+    create_invoice(&s);
+    assert!(!contract_event_topics(&s).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_events_enabled_requires_admin() {
+    let s = setup_test();
+    s.client.set_events_enabled(&s.merchant, &false);
+}
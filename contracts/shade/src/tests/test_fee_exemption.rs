@@ -0,0 +1,175 @@
+#![cfg(test)]
+
+use crate::components::admin::MAX_FEE_EXEMPTION_BATCH;
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{token, vec, Address, Env, String, Vec};
+
+fn setup_test() -> (
+    Env,
+    ShadeClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    // 5% fee
+    client.set_fee(&admin, &token, &500);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account = Address::generate(&env);
+    client.set_merchant_account(&merchant, &merchant_account);
+
+    (
+        env,
+        client,
+        contract_id,
+        admin,
+        token,
+        merchant,
+        merchant_account,
+    )
+}
+
+fn create_and_pay(
+    env: &Env,
+    client: &ShadeClient,
+    merchant: &Address,
+    token: &Address,
+    payer: &Address,
+) {
+    let invoice_id =
+        client.create_invoice(merchant, &String::from_str(env, "VIP"), &1000, token, &None);
+    token::StellarAssetClient::new(env, token).mint(payer, &1000);
+    client.pay_invoice(payer, &invoice_id);
+}
+
+#[test]
+fn test_add_and_remove_fee_exempt_payer() {
+    let (env, client, _contract_id, admin, _token, _merchant, _account) = setup_test();
+    let payer = Address::generate(&env);
+    assert!(!client.is_fee_exempt(&payer));
+
+    client.add_fee_exempt_payer(&admin, &payer);
+    assert!(client.is_fee_exempt(&payer));
+
+    client.remove_fee_exempt_payer(&admin, &payer);
+    assert!(!client.is_fee_exempt(&payer));
+}
+
+#[test]
+fn test_exempt_payer_is_charged_no_fee() {
+    let (env, client, _contract_id, admin, token, merchant, merchant_account) = setup_test();
+    let vip = Address::generate(&env);
+    client.add_fee_exempt_payer(&admin, &vip);
+
+    create_and_pay(&env, &client, &merchant, &token, &vip);
+
+    let token_client = token::TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&merchant_account), 1000);
+    assert_eq!(token_client.balance(&admin), 0);
+    assert_eq!(client.get_invoice(&1).fee_paid, 0);
+}
+
+#[test]
+fn test_normal_payer_is_charged_fee() {
+    let (env, client, _contract_id, admin, token, merchant, merchant_account) = setup_test();
+    client.add_fee_exempt_payer(&admin, &Address::generate(&env));
+
+    let payer = Address::generate(&env);
+    create_and_pay(&env, &client, &merchant, &token, &payer);
+
+    let token_client = token::TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&merchant_account), 950);
+    assert_eq!(token_client.balance(&admin), 50);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_add_fee_exempt_payer_non_admin_fails() {
+    let (env, client, _contract_id, _admin, _token, _merchant, _account) = setup_test();
+    client.add_fee_exempt_payer(&Address::generate(&env), &Address::generate(&env));
+}
+
+#[test]
+fn test_batch_add_then_remove_subset_of_fee_exempt_payers() {
+    let (env, client, _contract_id, admin, _token, _merchant, _account) = setup_test();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+
+    client.add_fee_exempt_payers(
+        &admin,
+        &vec![&env, alice.clone(), bob.clone(), carol.clone()],
+    );
+    assert!(client.is_fee_exempt(&alice));
+    assert!(client.is_fee_exempt(&bob));
+    assert!(client.is_fee_exempt(&carol));
+
+    client.remove_fee_exempt_payers(&admin, &vec![&env, alice.clone(), carol.clone()]);
+    assert!(!client.is_fee_exempt(&alice));
+    assert!(client.is_fee_exempt(&bob));
+    assert!(!client.is_fee_exempt(&carol));
+}
+
+#[test]
+fn test_batch_fee_exemption_skips_duplicates_and_no_ops() {
+    let (env, client, _contract_id, admin, _token, _merchant, _account) = setup_test();
+    let vip = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.add_fee_exempt_payer(&admin, &vip);
+
+    client.add_fee_exempt_payers(
+        &admin,
+        &vec![&env, vip.clone(), other.clone(), other.clone()],
+    );
+    // only `other` changed, and only once
+    assert_eq!(env.events().all().len(), 1);
+    assert!(client.is_fee_exempt(&other));
+
+    client.remove_fee_exempt_payers(&admin, &vec![&env, other.clone(), other.clone()]);
+    assert_eq!(env.events().all().len(), 1);
+    assert!(!client.is_fee_exempt(&other));
+    assert!(client.is_fee_exempt(&vip));
+}
+
+#[test]
+fn test_batch_fee_exemption_accepts_empty_list() {
+    let (env, client, _contract_id, admin, _token, _merchant, _account) = setup_test();
+    client.add_fee_exempt_payers(&admin, &Vec::new(&env));
+    client.remove_fee_exempt_payers(&admin, &Vec::new(&env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_batch_fee_exemption_oversized_fails() {
+    let (env, client, _contract_id, admin, _token, _merchant, _account) = setup_test();
+    let mut payers = Vec::new(&env);
+    for _ in 0..=MAX_FEE_EXEMPTION_BATCH {
+        payers.push_back(Address::generate(&env));
+    }
+    client.add_fee_exempt_payers(&admin, &payers);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_batch_fee_exemption_non_admin_fails() {
+    let (env, client, _contract_id, _admin, _token, _merchant, _account) = setup_test();
+    client.remove_fee_exempt_payers(
+        &Address::generate(&env),
+        &vec![&env, Address::generate(&env)],
+    );
+}
@@ -1,11 +1,11 @@
 #![cfg(test)]
 
 use crate::components::admin as admin_component;
-use crate::errors::ContractError;
+use crate::errors::{ContractError, ExtendedError};
 use crate::shade::Shade;
 use crate::shade::ShadeClient;
 use soroban_sdk::testutils::{Address as _, Events as _};
-use soroban_sdk::{Address, Env, Map, Symbol, TryIntoVal, Val};
+use soroban_sdk::{token, Address, Env, Map, String, Symbol, TryIntoVal, Val};
 
 fn setup_with_accepted_token(env: &Env) -> (Address, ShadeClient<'_>, Address) {
     env.mock_all_auths();
@@ -127,3 +127,270 @@ fn test_get_fee_default_zero() {
 
     assert_eq!(client.get_fee(&token), 0);
 }
+
+#[test]
+fn test_set_fee_at_ceiling() {
+    let env = Env::default();
+    let (admin, client, token) = setup_with_accepted_token(&env);
+
+    client.set_fee_ceiling(&admin, &token, &300);
+    assert_eq!(client.get_fee_ceiling(&token), Some(300));
+
+    client.set_fee(&admin, &token, &300);
+    assert_eq!(client.get_fee(&token), 300);
+}
+
+#[test]
+fn test_set_fee_above_ceiling() {
+    let env = Env::default();
+    let (admin, client, token) = setup_with_accepted_token(&env);
+
+    client.set_fee_ceiling(&admin, &token, &300);
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::FeeAboveCeiling as u32);
+
+    let result = client.try_set_fee(&admin, &token, &301);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+    assert_eq!(client.get_fee(&token), 0);
+}
+
+#[test]
+fn test_propose_fee_above_ceiling() {
+    let env = Env::default();
+    let (admin, client, token) = setup_with_accepted_token(&env);
+
+    client.set_fee_ceiling(&admin, &token, &300);
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::FeeAboveCeiling as u32);
+
+    let result = client.try_propose_fee(&admin, &token, &301);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_set_fee_ceiling_unauthorized() {
+    let env = Env::default();
+    let (_admin, client, token) = setup_with_accepted_token(&env);
+
+    let non_admin = Address::generate(&env);
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::NotAuthorized as u32);
+
+    let result = client.try_set_fee_ceiling(&non_admin, &token, &300);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+    assert_eq!(client.get_fee_ceiling(&token), None);
+}
+
+#[test]
+fn test_update_fee_config_applies_all_fields() {
+    let env = Env::default();
+    let (admin, client, token) = setup_with_accepted_token(&env);
+    let old_recipient = Address::generate(&env);
+    client.update_fee_config(&admin, &token, &5, &100, &old_recipient);
+
+    let recipient = Address::generate(&env);
+    client.update_fee_config(&admin, &token, &10, &250, &recipient);
+    let events = env.events().all();
+
+    assert_eq!(client.get_flat_fee(&token), 10);
+    assert_eq!(client.get_fee(&token), 250);
+    assert_eq!(client.get_fee_recipient(&token), Some(recipient.clone()));
+
+    let (_, topics, data) = events.get(events.len() - 1).unwrap();
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(event_name, Symbol::new(&env, "fee_config_updated"));
+
+    let data_map: Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+    let field = |name: &str| data_map.get(Symbol::new(&env, name)).unwrap();
+    let old_fee: i128 = field("old_fee").try_into_val(&env).unwrap();
+    let new_bps: u32 = field("new_bps").try_into_val(&env).unwrap();
+    let old_recipient_in_event: Option<Address> =
+        field("old_recipient").try_into_val(&env).unwrap();
+    assert_eq!(old_fee, 5);
+    assert_eq!(new_bps, 250);
+    assert_eq!(old_recipient_in_event, Some(old_recipient));
+}
+
+#[test]
+fn test_update_fee_config_invalid_bps_reverts_everything() {
+    let env = Env::default();
+    let (admin, client, token) = setup_with_accepted_token(&env);
+
+    let expected_error = soroban_sdk::Error::from_contract_error(ExtendedError::InvalidFee as u32);
+
+    let result =
+        client.try_update_fee_config(&admin, &token, &10, &10_001, &Address::generate(&env));
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+    assert_eq!(client.get_flat_fee(&token), 0);
+    assert_eq!(client.get_fee(&token), 0);
+    assert_eq!(client.get_fee_recipient(&token), None);
+}
+
+#[test]
+fn test_update_fee_config_above_ceiling() {
+    let env = Env::default();
+    let (admin, client, token) = setup_with_accepted_token(&env);
+    client.set_fee_ceiling(&admin, &token, &300);
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::FeeAboveCeiling as u32);
+
+    let result = client.try_update_fee_config(&admin, &token, &0, &301, &Address::generate(&env));
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_update_fee_config_routes_fees_to_recipient() {
+    let env = Env::default();
+    let (admin, client, token) = setup_with_accepted_token(&env);
+    let recipient = Address::generate(&env);
+    // 5% plus a flat 10
+    client.update_fee_config(&admin, &token, &10, &500, &recipient);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account = Address::generate(&env);
+    client.set_merchant_account(&merchant, &merchant_account);
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Routed"),
+        &1000,
+        &token,
+        &None,
+    );
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    let token_client = token::TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&recipient), 60);
+    assert_eq!(token_client.balance(&merchant_account), 940);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_pool_totals(&token), (0, 0));
+}
+
+#[test]
+fn test_update_fee_config_unauthorized() {
+    let env = Env::default();
+    let (_admin, client, token) = setup_with_accepted_token(&env);
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::NotAuthorized as u32);
+
+    let non_admin = Address::generate(&env);
+    let result = client.try_update_fee_config(&non_admin, &token, &10, &100, &non_admin);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn pay_new_invoice(env: &Env, client: &ShadeClient, token: &Address, amount: i128) -> i128 {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(env));
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(env, "Tiered"),
+        &amount,
+        token,
+        &None,
+    );
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&payer, &amount);
+    client.pay_invoice(&payer, &invoice_id);
+    client.get_invoice(&invoice_id).fee_paid
+}
+
+fn three_tiers(env: &Env) -> soroban_sdk::Vec<(i128, u32)> {
+    soroban_sdk::vec![env, (10_000, 300), (100_000, 200), (1_000_000, 100)]
+}
+
+#[test]
+fn test_fee_tier_middle_tier_applies() {
+    let env = Env::default();
+    let (admin, client, token) = setup_with_accepted_token(&env);
+    client.set_fee(&admin, &token, &500);
+    client.set_fee_tiers(&admin, &token, &three_tiers(&env));
+    assert_eq!(client.get_fee_tiers(&token), three_tiers(&env));
+
+    // 200_000 reaches the 100_000 tier (2%) but not the 1_000_000 one.
+    assert_eq!(pay_new_invoice(&env, &client, &token, 200_000), 4_000);
+}
+
+#[test]
+fn test_fee_tier_below_all_tiers_uses_default_fee() {
+    let env = Env::default();
+    let (admin, client, token) = setup_with_accepted_token(&env);
+    client.set_fee(&admin, &token, &500);
+    client.set_fee_tiers(&admin, &token, &three_tiers(&env));
+
+    assert_eq!(pay_new_invoice(&env, &client, &token, 1_000), 50);
+}
+
+#[test]
+fn test_fee_tier_selected_by_invoice_amount_for_partials() {
+    let env = Env::default();
+    let (admin, client, token) = setup_with_accepted_token(&env);
+    client.set_fee(&admin, &token, &500);
+    client.set_fee_tiers(&admin, &token, &three_tiers(&env));
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Tiered"),
+        &100_000,
+        &token,
+        &None,
+    );
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &100_000);
+    client.pay_invoice_partial(&payer, &invoice_id, &5_000);
+
+    assert_eq!(client.get_invoice(&invoice_id).fee_paid, 100);
+}
+
+#[test]
+fn test_clearing_fee_tiers_restores_default_fee() {
+    let env = Env::default();
+    let (admin, client, token) = setup_with_accepted_token(&env);
+    client.set_fee(&admin, &token, &500);
+    client.set_fee_tiers(&admin, &token, &three_tiers(&env));
+    client.set_fee_tiers(&admin, &token, &soroban_sdk::Vec::new(&env));
+
+    assert_eq!(client.get_fee_tiers(&token).len(), 0);
+    assert_eq!(pay_new_invoice(&env, &client, &token, 200_000), 10_000);
+}
+
+#[test]
+fn test_set_fee_tiers_unsorted_fails() {
+    let env = Env::default();
+    let (admin, client, token) = setup_with_accepted_token(&env);
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::InvalidAmount as u32);
+
+    let unsorted = soroban_sdk::vec![&env, (100_000, 200), (10_000, 300)];
+    let result = client.try_set_fee_tiers(&admin, &token, &unsorted);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+
+    let duplicate = soroban_sdk::vec![&env, (10_000, 300), (10_000, 200)];
+    let result = client.try_set_fee_tiers(&admin, &token, &duplicate);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+    assert_eq!(client.get_fee_tiers(&token).len(), 0);
+}
+
+#[test]
+fn test_set_fee_tiers_above_ceiling_fails() {
+    let env = Env::default();
+    let (admin, client, token) = setup_with_accepted_token(&env);
+    client.set_fee_ceiling(&admin, &token, &250);
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::FeeAboveCeiling as u32);
+
+    let result = client.try_set_fee_tiers(&admin, &token, &three_tiers(&env));
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::Health;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, admin)
+}
+
+#[test]
+fn test_health_of_fresh_contract() {
+    let (_env, client, _admin) = setup_test();
+    assert_eq!(
+        client.get_health(),
+        Health {
+            total_invoices: 0,
+            open_invoices: 0,
+            total_merchants: 0,
+            active_merchants: 0,
+            paused: false,
+            accepted_token_count: 0,
+        }
+    );
+}
+
+#[test]
+fn test_health_tracks_merchants_and_tokens() {
+    let (env, client, admin) = setup_test();
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    client.register_merchant(&Address::generate(&env), &None, &None);
+    client.register_merchant(&Address::generate(&env), &None, &None);
+    client.set_merchant_status(&admin, &2, &false);
+    // Repeating a status change must not skew the counter.
+    client.set_merchant_status(&admin, &2, &false);
+    client.pause(&admin);
+
+    let health = client.get_health();
+    assert_eq!(health.total_merchants, 2);
+    assert_eq!(health.active_merchants, 1);
+    assert_eq!(health.accepted_token_count, 1);
+    assert!(health.paused);
+}
+
+#[test]
+fn test_health_tracks_invoice_lifecycle() {
+    let (env, client, admin) = setup_test();
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    let description = String::from_str(&env, "Health");
+    let paid = client.create_invoice(&merchant, &description, &1000, &token, &None);
+    let voided = client.create_invoice(&merchant, &description, &1000, &token, &None);
+    let partial = client.create_invoice(&merchant, &description, &1000, &token, &None);
+    let draft = client.create_invoice_draft(&merchant, &description, &1000, &token, &None);
+
+    let health = client.get_health();
+    assert_eq!(health.total_invoices, 4);
+    assert_eq!(health.open_invoices, 3);
+
+    let customer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&customer, &2000);
+    client.pay_invoice(&customer, &paid);
+    client.void_invoice(&merchant, &voided);
+    client.pay_invoice_partial(&customer, &partial, &400);
+    assert_eq!(client.get_health().open_invoices, 1);
+
+    client.finalize_invoice(&merchant, &draft);
+    assert_eq!(client.get_health().open_invoices, 2);
+
+    client.pay_invoice(&customer, &partial);
+    assert_eq!(client.get_health().open_invoices, 1);
+}
@@ -0,0 +1,169 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, Env, String};
+
+const MERCHANT_ID: u64 = 1;
+
+struct Setup {
+    env: Env,
+    client: ShadeClient<'static>,
+    contract_id: Address,
+    admin: Address,
+    token: Address,
+    merchant: Address,
+    merchant_account: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    // 5% fee
+    client.set_fee(&admin, &token, &500);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account = Address::generate(&env);
+    client.set_merchant_account(&merchant, &merchant_account);
+
+    Setup {
+        env,
+        client,
+        contract_id,
+        admin,
+        token,
+        merchant,
+        merchant_account,
+    }
+}
+
+fn pay(s: &Setup, amount: i128) {
+    let invoice_id = s.client.create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Holdback"),
+        &amount,
+        &s.token,
+        &None,
+    );
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&payer, &amount);
+    s.client.pay_invoice(&payer, &invoice_id);
+}
+
+fn balance(s: &Setup, holder: &Address) -> i128 {
+    token::TokenClient::new(&s.env, &s.token).balance(holder)
+}
+
+#[test]
+fn test_no_holdback_by_default() {
+    let s = setup_test();
+    assert_eq!(s.client.get_merchant_holdback_bps(&MERCHANT_ID), 0);
+
+    pay(&s, 1_000);
+
+    assert_eq!(balance(&s, &s.merchant_account), 950);
+    assert_eq!(s.client.get_holdback(&MERCHANT_ID, &s.token), 0);
+}
+
+#[test]
+fn test_settlement_splits_net_and_holdback() {
+    let s = setup_test();
+    // hold back 20% of the merchant's net
+    s.client
+        .set_merchant_holdback_bps(&s.admin, &MERCHANT_ID, &2_000);
+
+    pay(&s, 1_000);
+
+    assert_eq!(balance(&s, &s.merchant_account), 760);
+    assert_eq!(s.client.get_holdback(&MERCHANT_ID, &s.token), 190);
+    assert_eq!(balance(&s, &s.contract_id), 190);
+    assert_eq!(balance(&s, &s.admin), 50);
+    assert_eq!(s.client.get_pool_totals(&s.token), (190, 0));
+}
+
+#[test]
+fn test_release_holdback_transfers_held_amount() {
+    let s = setup_test();
+    s.client
+        .set_merchant_holdback_bps(&s.admin, &MERCHANT_ID, &2_000);
+    pay(&s, 1_000);
+    pay(&s, 2_000);
+    assert_eq!(s.client.get_holdback(&MERCHANT_ID, &s.token), 570);
+
+    s.client
+        .release_holdback(&s.admin, &MERCHANT_ID, &s.token, &200);
+    assert_eq!(s.client.get_holdback(&MERCHANT_ID, &s.token), 370);
+    assert_eq!(s.client.get_pool_totals(&s.token), (370, 0));
+    assert_eq!(balance(&s, &s.merchant_account), 2_280 + 200);
+
+    s.client
+        .release_holdback(&s.admin, &MERCHANT_ID, &s.token, &370);
+    assert_eq!(s.client.get_holdback(&MERCHANT_ID, &s.token), 0);
+    assert_eq!(s.client.get_pool_totals(&s.token), (0, 0));
+    assert_eq!(balance(&s, &s.merchant_account), 2_850);
+}
+
+#[test]
+fn test_zero_holdback_restores_full_payouts() {
+    let s = setup_test();
+    s.client
+        .set_merchant_holdback_bps(&s.admin, &MERCHANT_ID, &2_000);
+    pay(&s, 1_000);
+
+    s.client
+        .set_merchant_holdback_bps(&s.admin, &MERCHANT_ID, &0);
+    pay(&s, 1_000);
+
+    assert_eq!(balance(&s, &s.merchant_account), 760 + 950);
+    assert_eq!(s.client.get_holdback(&MERCHANT_ID, &s.token), 190);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_release_more_than_held_fails() {
+    let s = setup_test();
+    s.client
+        .set_merchant_holdback_bps(&s.admin, &MERCHANT_ID, &2_000);
+    pay(&s, 1_000);
+
+    s.client
+        .release_holdback(&s.admin, &MERCHANT_ID, &s.token, &191);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #58)")]
+fn test_holdback_above_full_net_fails() {
+    let s = setup_test();
+    s.client
+        .set_merchant_holdback_bps(&s.admin, &MERCHANT_ID, &10_001);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_holdback_requires_admin() {
+    let s = setup_test();
+    s.client
+        .set_merchant_holdback_bps(&s.merchant, &MERCHANT_ID, &2_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_release_holdback_requires_admin() {
+    let s = setup_test();
+    s.client
+        .set_merchant_holdback_bps(&s.admin, &MERCHANT_ID, &2_000);
+    pay(&s, 1_000);
+
+    s.client
+        .release_holdback(&s.merchant, &MERCHANT_ID, &s.token, &190);
+}
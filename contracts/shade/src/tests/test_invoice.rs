@@ -86,7 +86,7 @@ fn test_create_and_get_invoice_success() {
     let (env, client, contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -115,7 +115,7 @@ fn test_create_multiple_invoices() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token1 = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -156,11 +156,11 @@ fn test_create_multiple_invoices() {
 #[should_panic(expected = "HostError: Error(Contract, #8)")]
 #[test]
 fn test_get_invoice_not_found() {
-    let (_env, client, _contract_id, admin) = setup_test();
+    let (_env, client, _contract_id, _admin) = setup_test();
     client.get_invoice(&999);
 }
 
-#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
 #[test]
 fn test_create_invoice_unregistered_merchant() {
     let (env, client, _contract_id, admin) = setup_test();
@@ -176,13 +176,44 @@ fn test_create_invoice_unregistered_merchant() {
     client.create_invoice(&unregistered_merchant, &description, &amount, &token, &None);
 }
 
+#[test]
+fn test_create_invoice_unregistered_merchant_leaves_no_invoice() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let result = client.try_create_invoice(
+        &Address::generate(&env),
+        &String::from_str(&env, "Orphan"),
+        &1000,
+        &token,
+        &None,
+    );
+    assert!(result.is_err());
+    assert!(client.try_get_invoice(&1).is_err());
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Registered"),
+        &1000,
+        &token,
+        &None,
+    );
+    assert_eq!(invoice_id, 1);
+    assert_eq!(client.get_invoice(&invoice_id).merchant_id, 1);
+}
+
 #[should_panic(expected = "HostError: Error(Contract, #7)")]
 #[test]
 fn test_create_invoice_invalid_amount() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -194,11 +225,38 @@ fn test_create_invoice_invalid_amount() {
     client.create_invoice(&merchant, &description, &amount, &token, &None);
 }
 
+#[test]
+fn test_create_invoice_rejects_non_positive_amounts() {
+    let (env, client, _contract_id, admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    let description = String::from_str(&env, "Test Invoice");
+
+    for amount in [0_i128, -100] {
+        let result = client.try_create_invoice(&merchant, &description, &amount, &token, &None);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                crate::errors::ContractError::InvalidAmount as u32
+            )))
+        );
+    }
+
+    let invoice_id = client.create_invoice(&merchant, &description, &100, &token, &None);
+    assert_eq!(client.get_invoice(&invoice_id).amount, 100);
+}
+
 #[test]
 fn test_refund_invoice_success_within_window() {
     let (env, client, shade_contract_id, admin) = setup_test();
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = create_test_token(&env);
     client.add_accepted_token(&admin, &token);
@@ -243,7 +301,7 @@ fn test_refund_invoice_success_within_window() {
 fn test_refund_invoice_fails_after_refund_window() {
     let (env, client, shade_contract_id, admin) = setup_test();
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = create_test_token(&env);
     client.add_accepted_token(&admin, &token);
@@ -282,7 +340,7 @@ fn test_void_invoice_success() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -309,8 +367,8 @@ fn test_refund_invoice_fails_for_non_owner() {
     let (env, client, shade_contract_id, admin) = setup_test();
     let merchant = Address::generate(&env);
     let other_merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
-    client.register_merchant(&other_merchant);
+    client.register_merchant(&merchant, &None, &None);
+    client.register_merchant(&other_merchant, &None, &None);
 
     let token = create_test_token(&env);
     client.add_accepted_token(&admin, &token);
@@ -348,7 +406,7 @@ fn test_void_invoice_non_owner() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -359,7 +417,7 @@ fn test_void_invoice_non_owner() {
 
     // Try to void with different merchant (should panic with NotAuthorized)
     let other_merchant = Address::generate(&env);
-    client.register_merchant(&other_merchant);
+    client.register_merchant(&other_merchant, &None, &None);
     client.void_invoice(&other_merchant, &invoice_id);
 }
 
@@ -370,7 +428,7 @@ fn test_void_invoice_already_paid() {
 
     // Register merchant
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Create merchant account
     let merchant_account = Address::generate(&env);
@@ -396,7 +454,7 @@ fn test_void_invoice_already_cancelled() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -419,7 +477,7 @@ fn test_pay_cancelled_invoice() {
 
     // Register merchant
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Create merchant account
     let merchant_account = Address::generate(&env);
@@ -443,10 +501,10 @@ fn test_pay_cancelled_invoice() {
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #8)")]
 fn test_void_non_existent_invoice() {
-    let (env, client, _contract_id, admin) = setup_test();
+    let (env, client, _contract_id, _admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Try to void non-existent invoice (should panic with InvoiceNotFound)
     client.void_invoice(&merchant, &999);
@@ -459,7 +517,7 @@ fn test_amend_invoice_amount_success() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -483,7 +541,7 @@ fn test_amend_invoice_description_success() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -513,7 +571,7 @@ fn test_amend_invoice_both_fields_success() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -544,7 +602,7 @@ fn test_amend_invoice_paid_fails() {
 
     // Register merchant
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Create merchant account
     let merchant_account = Address::generate(&env);
@@ -571,7 +629,7 @@ fn test_amend_invoice_cancelled_fails() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -593,7 +651,7 @@ fn test_amend_invoice_non_owner_fails() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -604,7 +662,7 @@ fn test_amend_invoice_non_owner_fails() {
 
     // Try to amend with different merchant (should panic with NotAuthorized)
     let other_merchant = Address::generate(&env);
-    client.register_merchant(&other_merchant);
+    client.register_merchant(&other_merchant, &None, &None);
     client.amend_invoice(&other_merchant, &invoice_id, &Some(2000), &None);
 }
 
@@ -614,7 +672,7 @@ fn test_amend_invoice_invalid_amount_fails() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -633,7 +691,7 @@ fn test_amend_invoice_negative_amount_fails() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -649,10 +707,10 @@ fn test_amend_invoice_negative_amount_fails() {
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #8)")]
 fn test_amend_non_existent_invoice_fails() {
-    let (env, client, _contract_id, admin) = setup_test();
+    let (env, client, _contract_id, _admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Try to amend non-existent invoice (should panic with InvoiceNotFound)
     client.amend_invoice(&merchant, &999, &Some(2000), &None);
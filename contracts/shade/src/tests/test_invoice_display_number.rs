@@ -0,0 +1,125 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+
+    (env, client, merchant, token)
+}
+
+fn create(env: &Env, client: &ShadeClient, merchant: &Address, token: &Address) -> u64 {
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Numbered"),
+        &1000,
+        token,
+        &None,
+    )
+}
+
+#[test]
+fn test_display_number_without_prefix() {
+    let (env, client, merchant, token) = setup_test();
+    let invoice_id = create(&env, &client, &merchant, &token);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.merchant_sequence, 1);
+    assert_eq!(
+        client.get_display_number(&invoice_id),
+        String::from_str(&env, "0001")
+    );
+}
+
+#[test]
+fn test_display_number_with_prefix() {
+    let (env, client, merchant, token) = setup_test();
+    client.set_merchant_prefix(&merchant, &Some(String::from_str(&env, "ACME")));
+    assert_eq!(
+        client.get_merchant(&1).invoice_prefix,
+        Some(String::from_str(&env, "ACME"))
+    );
+
+    create(&env, &client, &merchant, &token);
+    let second = create(&env, &client, &merchant, &token);
+
+    assert_eq!(
+        client.get_display_number(&second),
+        String::from_str(&env, "ACME-0002")
+    );
+}
+
+#[test]
+fn test_sequence_is_per_merchant() {
+    let (env, client, merchant, token) = setup_test();
+    let other = Address::generate(&env);
+    client.register_merchant(&other, &None, &None);
+
+    create(&env, &client, &merchant, &token);
+    create(&env, &client, &merchant, &token);
+    let other_invoice = create(&env, &client, &other, &token);
+
+    let invoice = client.get_invoice(&other_invoice);
+    assert_eq!(invoice.id, 3);
+    assert_eq!(invoice.merchant_sequence, 1);
+    assert_eq!(
+        client.get_display_number(&other_invoice),
+        String::from_str(&env, "0001")
+    );
+}
+
+#[test]
+fn test_clearing_prefix() {
+    let (env, client, merchant, token) = setup_test();
+    client.set_merchant_prefix(&merchant, &Some(String::from_str(&env, "ACME")));
+    let invoice_id = create(&env, &client, &merchant, &token);
+
+    client.set_merchant_prefix(&merchant, &None);
+
+    assert_eq!(
+        client.get_display_number(&invoice_id),
+        String::from_str(&env, "0001")
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #44)")]
+fn test_prefix_too_long_fails() {
+    let (env, client, merchant, _token) = setup_test();
+    client.set_merchant_prefix(
+        &merchant,
+        &Some(String::from_str(&env, "ABCDEFGHIJKLMNOPQ")),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #33)")]
+fn test_empty_prefix_fails() {
+    let (env, client, merchant, _token) = setup_test();
+    client.set_merchant_prefix(&merchant, &Some(String::from_str(&env, "")));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_prefix_requires_merchant() {
+    let (env, client, _merchant, _token) = setup_test();
+    client.set_merchant_prefix(
+        &Address::generate(&env),
+        &Some(String::from_str(&env, "ACME")),
+    );
+}
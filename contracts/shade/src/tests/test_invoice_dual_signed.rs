@@ -0,0 +1,216 @@
+#![cfg(test)]
+extern crate alloc;
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceTerms;
+use ed25519_dalek::{Signer, SigningKey};
+use rand::rngs::OsRng;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, String};
+
+struct DualSetup {
+    env: Env,
+    client: ShadeClient<'static>,
+    contract_id: Address,
+    merchant: Address,
+    payer: Address,
+    merchant_key: SigningKey,
+    payer_key: SigningKey,
+    terms: InvoiceTerms,
+}
+
+fn setup_test() -> DualSetup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_key = SigningKey::generate(&mut OsRng);
+    client.set_merchant_key(
+        &merchant,
+        &BytesN::from_array(&env, &merchant_key.verifying_key().to_bytes()),
+    );
+
+    let payer = Address::generate(&env);
+    let payer_key = SigningKey::generate(&mut OsRng);
+    client.set_payer_key(
+        &payer,
+        &BytesN::from_array(&env, &payer_key.verifying_key().to_bytes()),
+    );
+
+    let terms = InvoiceTerms {
+        description: String::from_str(&env, "Agreed supply contract"),
+        amount: 5000,
+        token,
+        expires_at: Some(86_400),
+    };
+
+    DualSetup {
+        env,
+        client,
+        contract_id,
+        merchant,
+        payer,
+        merchant_key,
+        payer_key,
+        terms,
+    }
+}
+
+/// Build the same message as signature_util::build_dual_message.
+fn build_test_message(s: &DualSetup, nonce: &BytesN<32>) -> alloc::vec::Vec<u8> {
+    let env = &s.env;
+    let mut msg = Bytes::new(env);
+    msg.append(&s.contract_id.clone().to_xdr(env));
+    msg.append(&Bytes::from_array(env, b"|"));
+    msg.append(&s.merchant.clone().to_xdr(env));
+    msg.append(&Bytes::from_array(env, b"|"));
+    msg.append(&s.payer.clone().to_xdr(env));
+    msg.append(&Bytes::from_array(env, b"|"));
+    msg.append(nonce.as_ref());
+    msg.append(&Bytes::from_array(env, b"|"));
+    msg.append(&Bytes::from_slice(env, &s.terms.amount.to_be_bytes()));
+    msg.append(&Bytes::from_array(env, b"|"));
+    msg.append(&s.terms.token.clone().to_xdr(env));
+    msg.append(&Bytes::from_array(env, b"|"));
+    msg.append(&Bytes::from_slice(
+        env,
+        &s.terms.expires_at.unwrap_or(0).to_be_bytes(),
+    ));
+    msg.append(&Bytes::from_array(env, b"|"));
+    msg.append(&s.terms.description.clone().to_xdr(env));
+
+    let mut result = alloc::vec![0u8; msg.len() as usize];
+    for i in 0..msg.len() {
+        result[i as usize] = msg.get(i).unwrap();
+    }
+    result
+}
+
+fn sign(s: &DualSetup, key: &SigningKey, nonce: &BytesN<32>) -> BytesN<64> {
+    let message = build_test_message(s, nonce);
+    BytesN::from_array(&s.env, &key.sign(&message).to_bytes())
+}
+
+fn nonce(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+#[test]
+fn test_dual_signed_invoice_created_with_designated_payer() {
+    let s = setup_test();
+    let nonce = nonce(&s.env, 1);
+    let merchant_sig = sign(&s, &s.merchant_key, &nonce);
+    let payer_sig = sign(&s, &s.payer_key, &nonce);
+
+    let invoice_id = s.client.create_invoice_dual_signed(
+        &s.merchant,
+        &s.payer,
+        &s.terms,
+        &merchant_sig,
+        &payer_sig,
+        &nonce,
+    );
+
+    let invoice = s.client.get_invoice(&invoice_id);
+    assert_eq!(invoice.amount, s.terms.amount);
+    assert_eq!(invoice.description, s.terms.description);
+    assert_eq!(invoice.expires_at, s.terms.expires_at);
+    assert_eq!(invoice.designated_payer, Some(s.payer.clone()));
+    let outstanding = s.client.get_payer_outstanding_invoices(&s.payer);
+    assert_eq!(outstanding.len(), 1);
+    assert_eq!(outstanding.get(0).unwrap(), invoice_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Crypto, InvalidInput)")]
+fn test_dual_signed_bad_payer_signature_fails() {
+    let s = setup_test();
+    let nonce = nonce(&s.env, 1);
+    let merchant_sig = sign(&s, &s.merchant_key, &nonce);
+    // Payer signature made with the wrong key.
+    let forged_sig = sign(&s, &SigningKey::generate(&mut OsRng), &nonce);
+
+    s.client.create_invoice_dual_signed(
+        &s.merchant,
+        &s.payer,
+        &s.terms,
+        &merchant_sig,
+        &forged_sig,
+        &nonce,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Crypto, InvalidInput)")]
+fn test_dual_signed_tampered_terms_fail() {
+    let mut s = setup_test();
+    let nonce = nonce(&s.env, 1);
+    let merchant_sig = sign(&s, &s.merchant_key, &nonce);
+    let payer_sig = sign(&s, &s.payer_key, &nonce);
+    s.terms.amount = 9000;
+
+    s.client.create_invoice_dual_signed(
+        &s.merchant,
+        &s.payer,
+        &s.terms,
+        &merchant_sig,
+        &payer_sig,
+        &nonce,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+fn test_dual_signed_replayed_nonce_fails() {
+    let s = setup_test();
+    let nonce = nonce(&s.env, 1);
+    let merchant_sig = sign(&s, &s.merchant_key, &nonce);
+    let payer_sig = sign(&s, &s.payer_key, &nonce);
+
+    s.client.create_invoice_dual_signed(
+        &s.merchant,
+        &s.payer,
+        &s.terms,
+        &merchant_sig,
+        &payer_sig,
+        &nonce,
+    );
+    s.client.create_invoice_dual_signed(
+        &s.merchant,
+        &s.payer,
+        &s.terms,
+        &merchant_sig,
+        &payer_sig,
+        &nonce,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+fn test_dual_signed_without_payer_key_fails() {
+    let mut s = setup_test();
+    s.payer = Address::generate(&s.env);
+    let nonce = nonce(&s.env, 1);
+    let merchant_sig = sign(&s, &s.merchant_key, &nonce);
+    let payer_sig = sign(&s, &s.payer_key, &nonce);
+
+    s.client.create_invoice_dual_signed(
+        &s.merchant,
+        &s.payer,
+        &s.terms,
+        &merchant_sig,
+        &payer_sig,
+        &nonce,
+    );
+}
@@ -0,0 +1,142 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use account::account::{MerchantAccount, MerchantAccountClient};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{
+    token, vec, Address, Env, IntoVal, Map, String, Symbol, TryFromVal, TryIntoVal, Val,
+};
+
+struct Setup {
+    env: Env,
+    client: ShadeClient<'static>,
+    contract_id: Address,
+    token: Address,
+    merchant: Address,
+    merchant_account: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    // 5% fee
+    client.set_fee(&admin, &token, &500);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account_id = env.register(MerchantAccount, ());
+    MerchantAccountClient::new(&env, &merchant_account_id).initialize(
+        &merchant,
+        &contract_id,
+        &1_u64,
+    );
+    client.set_merchant_account(&merchant, &merchant_account_id);
+
+    Setup {
+        env,
+        client,
+        contract_id,
+        token,
+        merchant,
+        merchant_account: merchant_account_id,
+    }
+}
+
+fn create_invoice(s: &Setup, amount: i128) -> u64 {
+    s.client.create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Lifecycle"),
+        &amount,
+        &s.token,
+        &None,
+    )
+}
+
+fn pay(s: &Setup, invoice_id: u64, amount: i128) -> Address {
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&payer, &amount);
+    s.client.pay_invoice(&payer, &invoice_id);
+    payer
+}
+
+/// Data of the single `name` event the contract emitted in the last invocation.
+fn event_data(s: &Setup, name: &str) -> Map<Symbol, Val> {
+    let topic = vec![&s.env, Symbol::new(&s.env, name).into_val(&s.env)];
+    let mut found = None;
+    for (contract_id, topics, data) in s.env.events().all().iter() {
+        if contract_id == s.contract_id && topics == topic {
+            assert!(found.is_none(), "more than one {name} event");
+            found = Some(data.try_into_val(&s.env).unwrap());
+        }
+    }
+    found.unwrap_or_else(|| panic!("no {name} event"))
+}
+
+fn field<T: TryFromVal<Env, Val>>(s: &Setup, data: &Map<Symbol, Val>, name: &str) -> T {
+    T::try_from_val(&s.env, &data.get(Symbol::new(&s.env, name)).unwrap()).unwrap()
+}
+
+#[test]
+fn test_create_invoice_emits_created_event() {
+    let s = setup_test();
+    let invoice_id = create_invoice(&s, 1_000);
+
+    let data = event_data(&s, "invoice_created_event");
+    assert_eq!(field::<u64>(&s, &data, "invoice_id"), invoice_id);
+    assert_eq!(field::<Address>(&s, &data, "merchant"), s.merchant);
+    assert_eq!(field::<i128>(&s, &data, "amount"), 1_000);
+    assert_eq!(field::<Address>(&s, &data, "token"), s.token);
+}
+
+#[test]
+fn test_pay_invoice_emits_paid_event() {
+    let s = setup_test();
+    let invoice_id = create_invoice(&s, 1_000);
+    let payer = pay(&s, invoice_id, 1_000);
+
+    let data = event_data(&s, "invoice_paid_event");
+    assert_eq!(field::<u64>(&s, &data, "invoice_id"), invoice_id);
+    assert_eq!(field::<Address>(&s, &data, "payer"), payer);
+    assert_eq!(field::<i128>(&s, &data, "amount"), 1_000);
+    assert_eq!(field::<i128>(&s, &data, "fee"), 50);
+}
+
+#[test]
+fn test_cancel_invoice_emits_cancelled_event() {
+    let s = setup_test();
+    let invoice_id = create_invoice(&s, 1_000);
+    s.client.cancel_invoice(&s.merchant, &invoice_id);
+
+    let data = event_data(&s, "invoice_cancelled_event");
+    assert_eq!(field::<u64>(&s, &data, "invoice_id"), invoice_id);
+    assert_eq!(field::<Address>(&s, &data, "merchant"), s.merchant);
+}
+
+#[test]
+fn test_refunds_emit_refund_events() {
+    let s = setup_test();
+    let invoice_id = create_invoice(&s, 1_000);
+    pay(&s, invoice_id, 1_000);
+
+    s.client.refund_invoice_partial(&invoice_id, &300);
+    let data = event_data(&s, "invoice_partially_refunded_event");
+    assert_eq!(field::<u64>(&s, &data, "invoice_id"), invoice_id);
+    assert_eq!(field::<i128>(&s, &data, "amount"), 300);
+    assert_eq!(field::<i128>(&s, &data, "total_amount_refunded"), 300);
+
+    // the merchant kept only its net, so top the account up to cover the rest
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&s.merchant_account, &50);
+    s.client.refund_invoice_partial(&invoice_id, &700);
+    let data = event_data(&s, "invoice_refunded_event");
+    assert_eq!(field::<u64>(&s, &data, "invoice_id"), invoice_id);
+    assert_eq!(field::<i128>(&s, &data, "amount"), 1_000);
+}
@@ -0,0 +1,191 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{ExpiryAction, InvoiceStatus};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    (env, client, token, merchant)
+}
+
+fn create(
+    env: &Env,
+    client: &ShadeClient,
+    merchant: &Address,
+    token: &Address,
+    expires_at: Option<u64>,
+    on_expiry: ExpiryAction,
+) -> u64 {
+    client.create_invoice_on_expiry(
+        merchant,
+        &String::from_str(env, "Monthly retainer"),
+        &1000,
+        token,
+        &expires_at,
+        &on_expiry,
+    )
+}
+
+#[test]
+fn test_default_expiry_action_is_close() {
+    let (env, client, token, merchant) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "One-off"),
+        &1000,
+        &token,
+        &Some(2_000),
+    );
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.on_expiry, ExpiryAction::Close);
+    assert_eq!(invoice.reissued_from, None);
+}
+
+#[test]
+fn test_expire_with_close_only_expires() {
+    let (env, client, token, merchant) = setup_test();
+    let invoice_id = create(
+        &env,
+        &client,
+        &merchant,
+        &token,
+        Some(2_000),
+        ExpiryAction::Close,
+    );
+
+    env.ledger().set_timestamp(2_000);
+    client.expire_invoice(&invoice_id);
+
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Expired
+    );
+    assert!(client.try_get_invoice(&2).is_err());
+    assert_eq!(client.get_health().open_invoices, 0);
+}
+
+#[test]
+fn test_expire_with_reissue_opens_linked_invoice() {
+    let (env, client, token, merchant) = setup_test();
+    let invoice_id = create(
+        &env,
+        &client,
+        &merchant,
+        &token,
+        Some(2_000),
+        ExpiryAction::Reissue,
+    );
+    assert_eq!(
+        client.get_invoice(&invoice_id).on_expiry,
+        ExpiryAction::Reissue
+    );
+
+    env.ledger().set_timestamp(2_500);
+    client.expire_invoice(&invoice_id);
+
+    let original = client.get_invoice(&invoice_id);
+    assert_eq!(original.status, InvoiceStatus::Expired);
+
+    let reissued_id = invoice_id + 1;
+    let reissued = client.get_invoice(&reissued_id);
+    assert_eq!(reissued.status, InvoiceStatus::Pending);
+    assert_eq!(reissued.reissued_from, Some(invoice_id));
+    assert_eq!(reissued.amount, original.amount);
+    assert_eq!(reissued.token, original.token);
+    assert_eq!(reissued.description, original.description);
+    assert_eq!(reissued.merchant_id, original.merchant_id);
+    assert_eq!(reissued.on_expiry, ExpiryAction::Reissue);
+    assert_eq!(reissued.date_created, 2_500);
+    // same 1_000s period as the original, starting now
+    assert_eq!(reissued.expires_at, Some(3_500));
+    assert_eq!(client.get_health().open_invoices, 1);
+    assert_eq!(client.get_outstanding_liability(&token), 1000);
+}
+
+#[test]
+fn test_maintenance_reissues_expiring_invoices() {
+    let (env, client, token, merchant) = setup_test();
+    let invoice_id = create(
+        &env,
+        &client,
+        &merchant,
+        &token,
+        Some(2_000),
+        ExpiryAction::Reissue,
+    );
+
+    env.ledger().set_timestamp(2_000);
+    let report = client.run_maintenance(&Address::generate(&env), &10);
+
+    assert_eq!(report.expired, 1);
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Expired
+    );
+    let reissued = client.get_invoice(&2);
+    assert_eq!(reissued.status, InvoiceStatus::Pending);
+    assert_eq!(reissued.reissued_from, Some(invoice_id));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #47)")]
+fn test_reissue_without_expiry_fails() {
+    let (env, client, token, merchant) = setup_test();
+    create(
+        &env,
+        &client,
+        &merchant,
+        &token,
+        None,
+        ExpiryAction::Reissue,
+    );
+}
+
+#[test]
+fn test_maintenance_skips_reissue_for_unaccepted_token() {
+    let (env, client, token, merchant) = setup_test();
+    let first = create(
+        &env,
+        &client,
+        &merchant,
+        &token,
+        Some(2_000),
+        ExpiryAction::Reissue,
+    );
+    let second = create(
+        &env,
+        &client,
+        &merchant,
+        &token,
+        Some(2_000),
+        ExpiryAction::Close,
+    );
+    client.remove_accepted_token(&client.get_admin(), &token);
+
+    env.ledger().set_timestamp(2_000);
+    let report = client.run_maintenance(&Address::generate(&env), &10);
+
+    assert_eq!(report.expired, 2);
+    assert_eq!(client.get_invoice(&first).status, InvoiceStatus::Expired);
+    assert_eq!(client.get_invoice(&second).status, InvoiceStatus::Expired);
+    assert!(client.try_get_invoice(&3).is_err());
+}
@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+
+    (env, client, admin, merchant, token)
+}
+
+#[test]
+fn test_export_import_round_trip_into_new_contract() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Backup"),
+        &1000,
+        &token,
+        &None,
+    );
+    let original = client.get_invoice(&invoice_id);
+    let data = client.export_invoice(&invoice_id);
+
+    let restored_id = env.register(Shade, ());
+    let restored = ShadeClient::new(&env, &restored_id);
+    let restored_admin = Address::generate(&env);
+    restored.initialize(&restored_admin);
+
+    assert_eq!(
+        restored.import_invoice(&restored_admin, &data, &false),
+        invoice_id
+    );
+    assert_eq!(restored.get_invoice(&invoice_id), original);
+}
+
+#[test]
+fn test_import_overwrite_replaces_invoice() {
+    let (env, client, admin, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Backup"),
+        &1000,
+        &token,
+        &None,
+    );
+    let data = client.export_invoice(&invoice_id);
+
+    client.void_invoice(&merchant, &invoice_id);
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Cancelled
+    );
+
+    client.import_invoice(&admin, &data, &true);
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Pending
+    );
+}
+
+#[test]
+fn test_import_advances_invoice_count() {
+    let (env, client, admin, merchant, token) = setup_test();
+    let description = String::from_str(&env, "Backup");
+    client.create_invoice(&merchant, &description, &1000, &token, &None);
+    let second = client.create_invoice(&merchant, &description, &1000, &token, &None);
+    let data = client.export_invoice(&second);
+
+    let restored_id = env.register(Shade, ());
+    let restored = ShadeClient::new(&env, &restored_id);
+    restored.initialize(&admin);
+    restored.add_accepted_token(&admin, &token);
+    restored.register_merchant(&merchant, &None, &None);
+    restored.import_invoice(&admin, &data, &false);
+
+    let next = restored.create_invoice(&merchant, &description, &1000, &token, &None);
+    assert_eq!(next, second + 1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #48)")]
+fn test_import_existing_id_without_overwrite_fails() {
+    let (env, client, admin, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Backup"),
+        &1000,
+        &token,
+        &None,
+    );
+    let data = client.export_invoice(&invoice_id);
+
+    client.import_invoice(&admin, &data, &false);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #47)")]
+fn test_import_non_invoice_data_fails() {
+    let (env, client, admin, _merchant, _token) = setup_test();
+    let data = String::from_str(&env, "not an invoice").to_xdr(&env);
+    client.import_invoice(&admin, &data, &false);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_import_by_non_admin_fails() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Backup"),
+        &1000,
+        &token,
+        &None,
+    );
+    let data = client.export_invoice(&invoice_id);
+
+    client.import_invoice(&Address::generate(&env), &data, &true);
+}
@@ -28,7 +28,7 @@ fn setup_invoices_at_timestamps(
     admin: &Address,
 ) -> (Address, Address, [u64; 3]) {
     let merchant = Address::generate(env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = create_token(env);
     client.add_accepted_token(admin, &token);
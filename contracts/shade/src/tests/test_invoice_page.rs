@@ -0,0 +1,101 @@
+#![cfg(test)]
+
+use crate::components::invoice::MAX_INVOICE_PAGE;
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{DataKey, Invoice};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String, Vec};
+
+fn setup_test(invoice_count: u32) -> (Env, ShadeClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+
+    for _ in 0..invoice_count {
+        client.create_invoice(
+            &merchant,
+            &String::from_str(&env, "Page"),
+            &1000,
+            &token,
+            &None,
+        );
+    }
+    (env, client, contract_id)
+}
+
+fn ids(env: &Env, page: &Vec<Invoice>) -> Vec<u64> {
+    let mut ids = Vec::new(env);
+    for invoice in page.iter() {
+        ids.push_back(invoice.id);
+    }
+    ids
+}
+
+#[test]
+fn test_get_invoices_page_walks_all_invoices() {
+    let (env, client, _contract_id) = setup_test(5);
+
+    assert_eq!(
+        ids(&env, &client.get_invoices_page(&1, &2)),
+        vec![&env, 1, 2]
+    );
+    assert_eq!(
+        ids(&env, &client.get_invoices_page(&3, &2)),
+        vec![&env, 3, 4]
+    );
+    assert_eq!(ids(&env, &client.get_invoices_page(&5, &2)), vec![&env, 5]);
+    assert!(client.get_invoices_page(&6, &2).is_empty());
+}
+
+#[test]
+fn test_get_invoices_page_treats_zero_start_as_first() {
+    let (env, client, _contract_id) = setup_test(3);
+    assert_eq!(
+        ids(&env, &client.get_invoices_page(&0, &10)),
+        vec![&env, 1, 2, 3]
+    );
+}
+
+#[test]
+fn test_get_invoices_page_skips_missing_ids() {
+    let (env, client, contract_id) = setup_test(4);
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().remove(&DataKey::Invoice(2));
+    });
+
+    assert_eq!(
+        ids(&env, &client.get_invoices_page(&1, &2)),
+        vec![&env, 1, 3]
+    );
+    assert_eq!(ids(&env, &client.get_invoices_page(&4, &2)), vec![&env, 4]);
+}
+
+#[test]
+fn test_get_invoices_page_is_empty_without_invoices() {
+    let (_env, client, _contract_id) = setup_test(0);
+    assert!(client.get_invoices_page(&1, &MAX_INVOICE_PAGE).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_get_invoices_page_rejects_limit_above_cap() {
+    let (_env, client, _contract_id) = setup_test(1);
+    client.get_invoices_page(&1, &(MAX_INVOICE_PAGE + 1));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_get_invoices_page_rejects_zero_limit() {
+    let (_env, client, _contract_id) = setup_test(1);
+    client.get_invoices_page(&1, &0);
+}
@@ -71,7 +71,7 @@ fn setup_paid_invoice(
 fn test_partial_refund_single_balance_and_status() {
     let (env, client, shade_contract_id, admin) = setup_test();
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
     let token = create_test_token(&env);
     client.add_accepted_token(&admin, &token);
     let payer = Address::generate(&env);
@@ -110,7 +110,7 @@ fn test_partial_refund_single_balance_and_status() {
 fn test_partial_refund_multiple_accumulates() {
     let (env, client, shade_contract_id, admin) = setup_test();
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
     let token = create_test_token(&env);
     client.add_accepted_token(&admin, &token);
     let payer = Address::generate(&env);
@@ -145,7 +145,7 @@ fn test_partial_refund_multiple_accumulates() {
 fn test_partial_refund_full_via_partial_transitions_to_refunded() {
     let (env, client, shade_contract_id, admin) = setup_test();
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
     let token = create_test_token(&env);
     client.add_accepted_token(&admin, &token);
     let payer = Address::generate(&env);
@@ -179,7 +179,7 @@ fn test_partial_refund_full_via_partial_transitions_to_refunded() {
 fn test_partial_refund_over_refund_panics() {
     let (env, client, shade_contract_id, admin) = setup_test();
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
     let token = create_test_token(&env);
     client.add_accepted_token(&admin, &token);
     let payer = Address::generate(&env);
@@ -204,7 +204,7 @@ fn test_partial_refund_over_refund_panics() {
 fn test_partial_refund_fails_after_seven_days() {
     let (env, client, shade_contract_id, admin) = setup_test();
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
     let token = create_test_token(&env);
     client.add_accepted_token(&admin, &token);
     let payer = Address::generate(&env);
@@ -231,7 +231,7 @@ fn test_partial_refund_fails_after_seven_days() {
 fn test_partial_refund_zero_amount_panics() {
     let (env, client, shade_contract_id, admin) = setup_test();
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
     let token = create_test_token(&env);
     client.add_accepted_token(&admin, &token);
     let payer = Address::generate(&env);
@@ -255,7 +255,7 @@ fn test_partial_refund_zero_amount_panics() {
 fn test_partial_refund_negative_amount_panics() {
     let (env, client, shade_contract_id, admin) = setup_test();
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
     let token = create_test_token(&env);
     client.add_accepted_token(&admin, &token);
     let payer = Address::generate(&env);
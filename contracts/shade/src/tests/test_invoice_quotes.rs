@@ -0,0 +1,278 @@
+#![cfg(test)]
+
+use crate::components::admin::{DEFAULT_QUOTE_VALIDITY, RATE_SCALE};
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{map, token, Address, Env, Map, String};
+
+struct QuoteSetup {
+    env: Env,
+    client: ShadeClient<'static>,
+    admin: Address,
+    merchant: Address,
+    merchant_account: Address,
+    usdc: Address,
+    eurc: Address,
+    invoice_id: u64,
+}
+
+fn setup_test() -> QuoteSetup {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let usdc = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let eurc = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &usdc);
+    client.add_accepted_token(&admin, &eurc);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account = Address::generate(&env);
+    client.set_merchant_account(&merchant, &merchant_account);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Quoted"),
+        &1000,
+        &usdc,
+        &None,
+    );
+
+    QuoteSetup {
+        env,
+        client,
+        admin,
+        merchant,
+        merchant_account,
+        usdc,
+        eurc,
+        invoice_id,
+    }
+}
+
+#[test]
+fn test_set_invoice_quotes_surfaces_on_invoice() {
+    let s = setup_test();
+    let quotes = map![&s.env, (s.eurc.clone(), 920i128)];
+
+    s.client
+        .set_invoice_quotes(&s.merchant, &s.invoice_id, &quotes, &2_000);
+
+    let invoice = s.client.get_invoice(&s.invoice_id);
+    assert_eq!(invoice.quoted_amounts, quotes);
+    assert_eq!(invoice.quote_valid_until, 2_000);
+}
+
+#[test]
+fn test_quote_is_honored_before_expiry() {
+    let s = setup_test();
+    s.client.set_invoice_quotes(
+        &s.merchant,
+        &s.invoice_id,
+        &map![&s.env, (s.eurc.clone(), 920i128)],
+        &2_000,
+    );
+
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.eurc).mint(&payer, &920);
+    s.env.ledger().set_timestamp(1_999);
+    s.client
+        .pay_invoice_with_quote(&payer, &s.invoice_id, &s.eurc);
+
+    let eurc_client = token::TokenClient::new(&s.env, &s.eurc);
+    assert_eq!(eurc_client.balance(&s.merchant_account), 920);
+    assert_eq!(eurc_client.balance(&payer), 0);
+
+    let invoice = s.client.get_invoice(&s.invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.token, s.eurc);
+    assert_eq!(invoice.amount, 920);
+    assert_eq!(invoice.quoted_amounts, Map::new(&s.env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #50)")]
+fn test_expired_quote_is_rejected() {
+    let s = setup_test();
+    s.client.set_invoice_quotes(
+        &s.merchant,
+        &s.invoice_id,
+        &map![&s.env, (s.eurc.clone(), 920i128)],
+        &2_000,
+    );
+
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.eurc).mint(&payer, &920);
+    s.env.ledger().set_timestamp(2_000);
+    s.client
+        .pay_invoice_with_quote(&payer, &s.invoice_id, &s.eurc);
+}
+
+#[test]
+fn test_requote_after_expiry_uses_new_snapshot() {
+    let s = setup_test();
+    s.client.set_invoice_quotes(
+        &s.merchant,
+        &s.invoice_id,
+        &map![&s.env, (s.eurc.clone(), 920i128)],
+        &2_000,
+    );
+
+    s.env.ledger().set_timestamp(2_500);
+    s.client.set_invoice_quotes(
+        &s.merchant,
+        &s.invoice_id,
+        &map![&s.env, (s.eurc.clone(), 950i128)],
+        &3_000,
+    );
+
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.eurc).mint(&payer, &950);
+    s.client
+        .pay_invoice_with_quote(&payer, &s.invoice_id, &s.eurc);
+
+    assert_eq!(s.client.get_invoice(&s.invoice_id).amount, 950);
+    assert_eq!(
+        token::TokenClient::new(&s.env, &s.eurc).balance(&s.merchant_account),
+        950
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+fn test_unquoted_token_is_rejected() {
+    let s = setup_test();
+    s.client.set_invoice_quotes(
+        &s.merchant,
+        &s.invoice_id,
+        &map![&s.env, (s.eurc.clone(), 920i128)],
+        &2_000,
+    );
+
+    let payer = Address::generate(&s.env);
+    s.client
+        .pay_invoice_with_quote(&payer, &s.invoice_id, &s.usdc);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+fn test_quote_for_unaccepted_token_is_rejected() {
+    let s = setup_test();
+    let unaccepted = s
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&s.env))
+        .address();
+
+    s.client.set_invoice_quotes(
+        &s.merchant,
+        &s.invoice_id,
+        &map![&s.env, (unaccepted, 920i128)],
+        &2_000,
+    );
+}
+
+fn create_quoted(s: &QuoteSetup) -> u64 {
+    s.client.create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Snapshot"),
+        &1000,
+        &s.usdc,
+        &None,
+    )
+}
+
+#[test]
+fn test_creation_snapshots_quotes_from_conversion_rate() {
+    let s = setup_test();
+    // 1 USDC buys 0.92 EURC
+    s.client
+        .set_conversion_rate(&s.admin, &s.usdc, &s.eurc, &(RATE_SCALE * 92 / 100));
+
+    let invoice = s.client.get_invoice(&create_quoted(&s));
+
+    assert_eq!(
+        invoice.quoted_amounts,
+        map![&s.env, (s.eurc.clone(), 920i128)]
+    );
+    assert_eq!(invoice.quote_valid_until, 1_000 + DEFAULT_QUOTE_VALIDITY);
+}
+
+#[test]
+fn test_snapshot_is_honored_before_expiry() {
+    let s = setup_test();
+    s.client
+        .set_conversion_rate(&s.admin, &s.usdc, &s.eurc, &(RATE_SCALE * 92 / 100));
+    let invoice_id = create_quoted(&s);
+    s.client
+        .set_conversion_rate(&s.admin, &s.usdc, &s.eurc, &(RATE_SCALE * 95 / 100));
+
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.eurc).mint(&payer, &950);
+    s.env
+        .ledger()
+        .set_timestamp(1_000 + DEFAULT_QUOTE_VALIDITY - 1);
+    s.client
+        .pay_invoice_with_quote(&payer, &invoice_id, &s.eurc);
+
+    assert_eq!(s.client.get_invoice(&invoice_id).amount, 920);
+    assert_eq!(token::TokenClient::new(&s.env, &s.eurc).balance(&payer), 30);
+}
+
+#[test]
+fn test_expired_snapshot_is_requoted_at_current_rate() {
+    let s = setup_test();
+    s.client
+        .set_conversion_rate(&s.admin, &s.usdc, &s.eurc, &(RATE_SCALE * 92 / 100));
+    let invoice_id = create_quoted(&s);
+    s.client
+        .set_conversion_rate(&s.admin, &s.usdc, &s.eurc, &(RATE_SCALE * 95 / 100));
+
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.eurc).mint(&payer, &950);
+    s.env.ledger().set_timestamp(1_000 + DEFAULT_QUOTE_VALIDITY);
+    s.client
+        .pay_invoice_with_quote(&payer, &invoice_id, &s.eurc);
+
+    assert_eq!(s.client.get_invoice(&invoice_id).amount, 950);
+    assert_eq!(
+        token::TokenClient::new(&s.env, &s.eurc).balance(&s.merchant_account),
+        950
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_quote_not_covering_fee_is_rejected() {
+    let s = setup_test();
+    s.client.set_fee(&s.admin, &s.eurc, &10_000);
+
+    s.client.set_invoice_quotes(
+        &s.merchant,
+        &s.invoice_id,
+        &map![&s.env, (s.eurc.clone(), 920i128)],
+        &2_000,
+    );
+}
+
+#[test]
+fn test_quote_is_checked_against_fee_amount_not_bps() {
+    let s = setup_test();
+    // 5% fee: a 300 quote pays 15 in fees
+    s.client.set_fee(&s.admin, &s.eurc, &500);
+
+    let quotes = map![&s.env, (s.eurc.clone(), 300i128)];
+    s.client
+        .set_invoice_quotes(&s.merchant, &s.invoice_id, &quotes, &2_000);
+
+    assert_eq!(s.client.get_invoice(&s.invoice_id).quoted_amounts, quotes);
+}
@@ -0,0 +1,133 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, Env, String};
+
+fn setup_test() -> (
+    Env,
+    ShadeClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let old_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let new_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &old_token);
+    client.add_accepted_token(&admin, &new_token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    (env, client, admin, merchant, old_token, new_token)
+}
+
+fn create(env: &Env, client: &ShadeClient, merchant: &Address, token: &Address) -> u64 {
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Migrated"),
+        &1000,
+        token,
+        &None,
+    )
+}
+
+#[test]
+fn test_reissue_cancels_and_recreates_pending_invoices() {
+    let (env, client, _admin, merchant, old_token, new_token) = setup_test();
+    let first = create(&env, &client, &merchant, &old_token);
+    let other_token = create(&env, &client, &merchant, &new_token);
+    let second = create(&env, &client, &merchant, &old_token);
+    let other_merchant = Address::generate(&env);
+    client.register_merchant(&other_merchant, &None, &None);
+    let foreign = create(&env, &client, &other_merchant, &old_token);
+
+    let (reissued, _) = client.reissue_merchant_invoices(&merchant, &old_token, &new_token, &0);
+
+    assert_eq!(reissued.len(), 2);
+    for (old_id, new_id) in [first, second].into_iter().zip(reissued.iter()) {
+        let old_invoice = client.get_invoice(&old_id);
+        let new_invoice = client.get_invoice(&new_id);
+        assert_eq!(old_invoice.status, InvoiceStatus::Cancelled);
+        assert_eq!(new_invoice.status, InvoiceStatus::Pending);
+        assert_eq!(new_invoice.token, new_token);
+        assert_eq!(new_invoice.amount, old_invoice.amount);
+        assert_eq!(new_invoice.description, old_invoice.description);
+        assert_eq!(new_invoice.merchant_id, old_invoice.merchant_id);
+    }
+    assert_eq!(
+        client.get_invoice(&other_token).status,
+        InvoiceStatus::Pending
+    );
+    assert_eq!(client.get_invoice(&foreign).token, old_token);
+
+    let (again, next) = client.reissue_merchant_invoices(&merchant, &old_token, &new_token, &0);
+    assert_eq!(again.len(), 0);
+    assert_eq!(next, None);
+}
+
+#[test]
+fn test_reissued_invoice_uses_new_token_fee() {
+    let (env, client, admin, merchant, old_token, new_token) = setup_test();
+    // 5% fee on the new token only
+    client.set_fee(&admin, &new_token, &500);
+    create(&env, &client, &merchant, &old_token);
+
+    let (reissued, _) = client.reissue_merchant_invoices(&merchant, &old_token, &new_token, &0);
+    let new_id = reissued.get(0).unwrap();
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &new_token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &new_id);
+    assert_eq!(client.get_invoice(&new_id).fee_paid, 50);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_reissue_fails_when_amount_does_not_cover_new_fee() {
+    let (env, client, admin, merchant, old_token, new_token) = setup_test();
+    create(&env, &client, &merchant, &old_token);
+    client.set_fee(&admin, &new_token, &2000);
+
+    client.reissue_merchant_invoices(&merchant, &old_token, &new_token, &0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+fn test_reissue_to_unaccepted_token_fails() {
+    let (env, client, _admin, merchant, old_token, _new_token) = setup_test();
+    create(&env, &client, &merchant, &old_token);
+    let unaccepted = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    client.reissue_merchant_invoices(&merchant, &old_token, &unaccepted, &0);
+}
+
+#[test]
+fn test_reissue_resumes_from_cursor() {
+    let (env, client, _admin, merchant, old_token, new_token) = setup_test();
+    let first = create(&env, &client, &merchant, &old_token);
+    let second = create(&env, &client, &merchant, &old_token);
+
+    let (reissued, next) = client.reissue_merchant_invoices(&merchant, &old_token, &new_token, &1);
+
+    assert_eq!(reissued.len(), 1);
+    assert_eq!(next, None);
+    assert_eq!(client.get_invoice(&first).status, InvoiceStatus::Pending);
+    assert_eq!(client.get_invoice(&second).status, InvoiceStatus::Cancelled);
+}
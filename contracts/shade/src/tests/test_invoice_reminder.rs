@@ -0,0 +1,164 @@
+#![cfg(test)]
+
+use crate::components::invoice::MAX_INVOICE_PAGE;
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address, Env, String, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+
+    (env, client, admin, merchant, token)
+}
+
+#[test]
+fn test_record_reminder_increments_count_and_stamps_time() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Reminder"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.reminder_count, 0);
+    assert_eq!(invoice.last_reminder_at, 0);
+
+    env.ledger().set_timestamp(5_000);
+    client.record_reminder(&merchant, &invoice_id);
+
+    env.ledger().set_timestamp(9_000);
+    client.record_reminder(&merchant, &invoice_id);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.reminder_count, 2);
+    assert_eq!(invoice.last_reminder_at, 9_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_record_reminder_by_other_merchant_fails() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Reminder"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    let other_merchant = Address::generate(&env);
+    client.register_merchant(&other_merchant, &None, &None);
+    client.record_reminder(&other_merchant, &invoice_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_record_reminder_on_paid_invoice_fails() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    let merchant_account = Address::generate(&env);
+    client.set_merchant_account(&merchant, &merchant_account);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Reminder"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    let customer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&customer, &1000);
+    client.pay_invoice(&customer, &invoice_id);
+
+    client.record_reminder(&merchant, &invoice_id);
+}
+
+#[test]
+fn test_get_invoices_due_for_reminder() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    let description = String::from_str(&env, "Reminder");
+
+    env.ledger().set_timestamp(1_000);
+    let first = client.create_invoice(&merchant, &description, &1000, &token, &None);
+    let second = client.create_invoice(&merchant, &description, &1000, &token, &None);
+    let voided = client.create_invoice(&merchant, &description, &1000, &token, &None);
+    client.void_invoice(&merchant, &voided);
+
+    // Nothing is due before the interval has elapsed since creation.
+    env.ledger().set_timestamp(1_500);
+    assert_eq!(
+        client.get_invoices_due_for_reminder(&1_000, &1, &MAX_INVOICE_PAGE),
+        Vec::new(&env)
+    );
+
+    // A reminder on `first` resets its clock; `second` becomes due.
+    client.record_reminder(&merchant, &first);
+    env.ledger().set_timestamp(2_000);
+    let mut expected = Vec::new(&env);
+    expected.push_back(second);
+    assert_eq!(
+        client.get_invoices_due_for_reminder(&1_000, &1, &MAX_INVOICE_PAGE),
+        expected
+    );
+
+    env.ledger().set_timestamp(2_500);
+    let mut expected = Vec::new(&env);
+    expected.push_back(first);
+    expected.push_back(second);
+    assert_eq!(
+        client.get_invoices_due_for_reminder(&1_000, &1, &MAX_INVOICE_PAGE),
+        expected
+    );
+}
+
+#[test]
+fn test_get_invoices_due_for_reminder_pages_by_id() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    let description = String::from_str(&env, "Reminder");
+    let mut ids = Vec::new(&env);
+    for _ in 0..5 {
+        ids.push_back(client.create_invoice(&merchant, &description, &1000, &token, &None));
+    }
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+
+    let first_page = client.get_invoices_due_for_reminder(&1_000, &1, &2);
+    assert_eq!(first_page, ids.slice(0..2));
+    let second_page = client.get_invoices_due_for_reminder(&1_000, &3, &2);
+    assert_eq!(second_page, ids.slice(2..4));
+    let last_page = client.get_invoices_due_for_reminder(&1_000, &5, &2);
+    assert_eq!(last_page, ids.slice(4..5));
+    assert_eq!(
+        client.get_invoices_due_for_reminder(&1_000, &6, &2),
+        Vec::new(&env)
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_get_invoices_due_for_reminder_rejects_oversized_limit() {
+    let (_env, client, _admin, _merchant, _token) = setup_test();
+    client.get_invoices_due_for_reminder(&1_000, &1, &(MAX_INVOICE_PAGE + 1));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_get_invoices_due_for_reminder_rejects_zero_limit() {
+    let (_env, client, _admin, _merchant, _token) = setup_test();
+    client.get_invoices_due_for_reminder(&1_000, &1, &0);
+}
@@ -0,0 +1,44 @@
+#![cfg(test)]
+
+use crate::components::invoice::RENT_BYTES_PER_TAG;
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::Env;
+
+fn setup_test() -> (Env, ShadeClient<'static>) {
+    let env = Env::default();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    (env, client)
+}
+
+#[test]
+fn test_estimate_invoice_rent_pins_representative_shapes() {
+    let (_env, client) = setup_test();
+
+    assert_eq!(client.estimate_invoice_rent(&0, &false, &0), 1216);
+    assert_eq!(client.estimate_invoice_rent(&64, &false, &0), 1280);
+    assert_eq!(client.estimate_invoice_rent(&256, &true, &0), 1560);
+    assert_eq!(client.estimate_invoice_rent(&256, &true, &5), 1760);
+}
+
+#[test]
+fn test_estimate_invoice_rent_grows_with_shape() {
+    let (_env, client) = setup_test();
+    let base = client.estimate_invoice_rent(&0, &false, &0);
+
+    // descriptions are padded to whole 4-byte words
+    assert_eq!(client.estimate_invoice_rent(&1, &false, &0), base + 4);
+    assert_eq!(client.estimate_invoice_rent(&4, &false, &0), base + 4);
+    assert_eq!(client.estimate_invoice_rent(&5, &false, &0), base + 8);
+    assert!(client.estimate_invoice_rent(&0, &true, &0) > base);
+    assert_eq!(
+        client.estimate_invoice_rent(&0, &false, &3),
+        base + 3 * RENT_BYTES_PER_TAG
+    );
+}
+
+#[test]
+fn test_estimate_invoice_rent_needs_no_initialization() {
+    let (_env, client) = setup_test();
+    assert!(client.try_estimate_invoice_rent(&16, &true, &1).is_ok());
+}
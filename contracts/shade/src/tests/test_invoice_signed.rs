@@ -65,6 +65,7 @@ fn generate_keypair() -> TestKeypair {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn sign_invoice(
     env: &Env,
     contract_id: &Address,
@@ -113,7 +114,7 @@ fn test_create_invoice_signed_manager_success() {
     client.grant_role(&admin, &manager, &Role::Manager);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let keypair = generate_keypair();
     let pub_key = BytesN::from_array(&env, &keypair.public_key_bytes);
@@ -161,7 +162,7 @@ fn test_create_invoice_signed_admin_success() {
     let (env, client, contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let keypair = generate_keypair();
     let pub_key = BytesN::from_array(&env, &keypair.public_key_bytes);
@@ -210,7 +211,7 @@ fn test_create_invoice_signed_guest_unauthorized() {
 
     let guest = Address::generate(&env);
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let keypair = generate_keypair();
     let pub_key = BytesN::from_array(&env, &keypair.public_key_bytes);
@@ -254,7 +255,7 @@ fn test_create_invoice_signed_operator_unauthorized() {
     client.grant_role(&admin, &operator, &Role::Operator);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let keypair = generate_keypair();
     let pub_key = BytesN::from_array(&env, &keypair.public_key_bytes);
@@ -298,7 +299,7 @@ fn test_create_invoice_signed_invalid_amount_zero() {
     client.grant_role(&admin, &manager, &Role::Manager);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let keypair = generate_keypair();
     let pub_key = BytesN::from_array(&env, &keypair.public_key_bytes);
@@ -335,7 +336,7 @@ fn test_create_invoice_signed_invalid_amount_negative() {
     client.grant_role(&admin, &manager, &Role::Manager);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let keypair = generate_keypair();
     let pub_key = BytesN::from_array(&env, &keypair.public_key_bytes);
@@ -363,7 +364,7 @@ fn test_create_invoice_signed_invalid_amount_negative() {
 
 /// Test Case 7: Merchant Validation - Unregistered Merchant
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
 fn test_create_invoice_signed_unregistered_merchant() {
     let (env, client, _contract_id, admin) = setup_test();
 
@@ -403,8 +404,8 @@ fn test_create_invoice_signed_multiple_invoices() {
     let merchant1 = Address::generate(&env);
     let merchant2 = Address::generate(&env);
 
-    client.register_merchant(&merchant1);
-    client.register_merchant(&merchant2);
+    client.register_merchant(&merchant1, &None, &None);
+    client.register_merchant(&merchant2, &None, &None);
 
     let keypair1 = generate_keypair();
     let keypair2 = generate_keypair();
@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+
+    (env, client, merchant, token)
+}
+
+fn create(env: &Env, client: &ShadeClient, merchant: &Address, token: &Address) -> u64 {
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Dashboard"),
+        &1000,
+        token,
+        &None,
+    )
+}
+
+#[test]
+fn test_get_statuses_mixed_ids() {
+    let (env, client, merchant, token) = setup_test();
+    let pending = create(&env, &client, &merchant, &token);
+    let cancelled = create(&env, &client, &merchant, &token);
+    client.void_invoice(&merchant, &cancelled);
+
+    let statuses = client.get_statuses(&vec![&env, cancelled, 99, pending], &false);
+
+    assert_eq!(
+        statuses,
+        vec![
+            &env,
+            Some(InvoiceStatus::Cancelled),
+            None,
+            Some(InvoiceStatus::Pending)
+        ]
+    );
+}
+
+#[test]
+fn test_get_statuses_empty_input() {
+    let (env, client, _merchant, _token) = setup_test();
+    assert_eq!(client.get_statuses(&Vec::new(&env), &true).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #8)")]
+fn test_get_statuses_strict_rejects_missing_id() {
+    let (env, client, merchant, token) = setup_test();
+    let pending = create(&env, &client, &merchant, &token);
+
+    client.get_statuses(&vec![&env, pending, 99], &true);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_get_statuses_rejects_oversized_input() {
+    let (env, client, _merchant, _token) = setup_test();
+    let mut ids = Vec::new(&env);
+    for id in 1..=101u64 {
+        ids.push_back(id);
+    }
+
+    client.get_statuses(&ids, &false);
+}
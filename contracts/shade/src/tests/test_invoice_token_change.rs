@@ -0,0 +1,159 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{token, vec, Address, Env, IntoVal, Map, String, Symbol, TryIntoVal, Val};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+
+    (env, client, contract_id, admin, merchant)
+}
+
+fn create_test_token(env: &Env) -> Address {
+    env.register_stellar_asset_contract_v2(Address::generate(env))
+        .address()
+}
+
+#[test]
+fn test_change_invoice_token_success() {
+    let (env, client, contract_id, admin, merchant) = setup_test();
+    let old_token = create_test_token(&env);
+    let new_token = create_test_token(&env);
+    client.add_accepted_token(&admin, &old_token);
+    client.add_accepted_token(&admin, &new_token);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Wrong currency"),
+        &1000,
+        &old_token,
+        &None,
+    );
+
+    client.change_invoice_token(&merchant, &invoice_id, &new_token);
+
+    let events = env.events().all();
+    let (event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
+    assert_eq!(event_contract_id, contract_id);
+    assert_eq!(
+        topics,
+        vec![&env, Symbol::new(&env, "invoice_updated").into_val(&env)]
+    );
+    let data_map: Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+    let old_token_in_event: Address = data_map
+        .get(Symbol::new(&env, "old_token"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    let new_token_in_event: Address = data_map
+        .get(Symbol::new(&env, "new_token"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(old_token_in_event, old_token);
+    assert_eq!(new_token_in_event, new_token);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.token, new_token);
+}
+
+#[test]
+fn test_changed_invoice_is_paid_in_new_token() {
+    let (env, client, _contract_id, admin, merchant) = setup_test();
+    let old_token = create_test_token(&env);
+    let new_token = create_test_token(&env);
+    client.add_accepted_token(&admin, &old_token);
+    client.add_accepted_token(&admin, &new_token);
+    client.set_fee(&admin, &new_token, &500);
+
+    let merchant_account = Address::generate(&env);
+    client.set_merchant_account(&merchant, &merchant_account);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Wrong currency"),
+        &1000,
+        &old_token,
+        &None,
+    );
+    client.change_invoice_token(&merchant, &invoice_id, &new_token);
+
+    let customer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &new_token).mint(&customer, &1000);
+    client.pay_invoice(&customer, &invoice_id);
+
+    let new_token_client = token::TokenClient::new(&env, &new_token);
+    assert_eq!(new_token_client.balance(&merchant_account), 950);
+    assert_eq!(new_token_client.balance(&admin), 50);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+fn test_change_invoice_token_to_unaccepted_token_fails() {
+    let (env, client, _contract_id, admin, merchant) = setup_test();
+    let old_token = create_test_token(&env);
+    client.add_accepted_token(&admin, &old_token);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Wrong currency"),
+        &1000,
+        &old_token,
+        &None,
+    );
+
+    let unaccepted = create_test_token(&env);
+    client.change_invoice_token(&merchant, &invoice_id, &unaccepted);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_change_invoice_token_by_non_owner_fails() {
+    let (env, client, _contract_id, admin, merchant) = setup_test();
+    let old_token = create_test_token(&env);
+    let new_token = create_test_token(&env);
+    client.add_accepted_token(&admin, &old_token);
+    client.add_accepted_token(&admin, &new_token);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Wrong currency"),
+        &1000,
+        &old_token,
+        &None,
+    );
+
+    let other_merchant = Address::generate(&env);
+    client.register_merchant(&other_merchant, &None, &None);
+    client.change_invoice_token(&other_merchant, &invoice_id, &new_token);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_change_invoice_token_on_cancelled_invoice_fails() {
+    let (env, client, _contract_id, admin, merchant) = setup_test();
+    let old_token = create_test_token(&env);
+    let new_token = create_test_token(&env);
+    client.add_accepted_token(&admin, &old_token);
+    client.add_accepted_token(&admin, &new_token);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Wrong currency"),
+        &1000,
+        &old_token,
+        &None,
+    );
+    client.void_invoice(&merchant, &invoice_id);
+
+    client.change_invoice_token(&merchant, &invoice_id, &new_token);
+}
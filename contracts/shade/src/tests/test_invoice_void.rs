@@ -1,5 +1,6 @@
 #![cfg(test)]
 
+use crate::components::invoice::{MAX_CANCEL_BATCH, MAX_CANCEL_SCAN};
 use crate::shade::{Shade, ShadeClient};
 use crate::types::InvoiceStatus;
 use soroban_sdk::testutils::Address as _;
@@ -24,7 +25,7 @@ fn test_void_invoice_success() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -55,7 +56,7 @@ fn test_void_invoice_unauthorized_random_address() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -78,10 +79,10 @@ fn test_void_invoice_unauthorized_different_merchant() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant1 = Address::generate(&env);
-    client.register_merchant(&merchant1);
+    client.register_merchant(&merchant1, &None, &None);
 
     let merchant2 = Address::generate(&env);
-    client.register_merchant(&merchant2);
+    client.register_merchant(&merchant2, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -117,7 +118,7 @@ fn test_void_invoice_already_paid() {
 
     // Register merchant
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Create merchant account
     let merchant_account = Address::generate(&env);
@@ -160,7 +161,7 @@ fn test_pay_voided_invoice() {
 
     // Register merchant
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Create merchant account
     let merchant_account = Address::generate(&env);
@@ -189,7 +190,7 @@ fn test_void_invoice_already_cancelled() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -211,10 +212,10 @@ fn test_void_invoice_already_cancelled() {
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #8)")]
 fn test_void_nonexistent_invoice() {
-    let (env, client, _contract_id, admin) = setup_test();
+    let (env, client, _contract_id, _admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Try to void non-existent invoice (should panic with InvoiceNotFound)
     client.void_invoice(&merchant, &999);
@@ -241,7 +242,7 @@ fn test_void_refunded_invoice() {
     client.set_fee(&admin, &token, &500);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let merchant_account_id = env.register(account::account::MerchantAccount, ());
     let merchant_account = account::account::MerchantAccountClient::new(&env, &merchant_account_id);
@@ -271,7 +272,7 @@ fn test_void_invoice_state_isolation() {
     let (env, client, _contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -298,3 +299,123 @@ fn test_void_invoice_state_isolation() {
     let invoice_3 = client.get_invoice(&invoice_id_3);
     assert_eq!(invoice_3.status, InvoiceStatus::Pending);
 }
+
+fn setup_pending_invoice() -> (Env, ShadeClient<'static>, Address, Address, u64) {
+    let (env, client, _contract_id, admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    let description = String::from_str(&env, "Mistaken Invoice");
+    let invoice_id = client.create_invoice(&merchant, &description, &1000, &token, &None);
+
+    (env, client, merchant, token, invoice_id)
+}
+
+#[test]
+fn test_cancel_invoice_success() {
+    let (_env, client, merchant, _token, invoice_id) = setup_pending_invoice();
+
+    client.cancel_invoice(&merchant, &invoice_id);
+
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Cancelled
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_cancel_invoice_already_paid() {
+    let (env, client, merchant, token, invoice_id) = setup_pending_invoice();
+
+    let payer = Address::generate(&env);
+    soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    client.cancel_invoice(&merchant, &invoice_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_cancel_invoice_by_non_owner() {
+    let (env, client, _merchant, _token, invoice_id) = setup_pending_invoice();
+
+    let other_merchant = Address::generate(&env);
+    client.register_merchant(&other_merchant, &None, &None);
+
+    client.cancel_invoice(&other_merchant, &invoice_id);
+}
+
+#[test]
+fn test_cancel_all_pending_only_touches_callers_pending_invoices() {
+    let (env, client, merchant, token, first_id) = setup_pending_invoice();
+    let description = String::from_str(&env, "Another");
+    let paid_id = client.create_invoice(&merchant, &description, &1000, &token, &None);
+    let second_id = client.create_invoice(&merchant, &description, &500, &token, &None);
+    let payer = Address::generate(&env);
+    soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &paid_id);
+
+    let other_merchant = Address::generate(&env);
+    client.register_merchant(&other_merchant, &None, &None);
+    let other_id = client.create_invoice(&other_merchant, &description, &1000, &token, &None);
+
+    let (cancelled, next) = client.cancel_all_pending(&merchant, &0);
+
+    assert_eq!(cancelled, soroban_sdk::vec![&env, first_id, second_id]);
+    assert_eq!(next, None);
+    assert_eq!(
+        client.get_invoice(&first_id).status,
+        InvoiceStatus::Cancelled
+    );
+    assert_eq!(
+        client.get_invoice(&second_id).status,
+        InvoiceStatus::Cancelled
+    );
+    assert_eq!(client.get_invoice(&paid_id).status, InvoiceStatus::Paid);
+    assert_eq!(client.get_invoice(&other_id).status, InvoiceStatus::Pending);
+    assert_eq!(client.cancel_all_pending(&merchant, &0).0.len(), 0);
+}
+
+#[test]
+fn test_cancel_all_pending_is_bounded_per_call() {
+    let (env, client, merchant, token, _invoice_id) = setup_pending_invoice();
+    let description = String::from_str(&env, "Bulk");
+    for _ in 0..MAX_CANCEL_BATCH {
+        client.create_invoice(&merchant, &description, &1000, &token, &None);
+    }
+
+    let (cancelled, next) = client.cancel_all_pending(&merchant, &0);
+    assert_eq!(cancelled.len(), MAX_CANCEL_BATCH);
+    assert_eq!(next, Some(MAX_CANCEL_BATCH));
+
+    let (cancelled, next) = client.cancel_all_pending(&merchant, &MAX_CANCEL_BATCH);
+    assert_eq!(cancelled.len(), 1);
+    assert_eq!(next, None);
+}
+
+#[test]
+fn test_cancel_all_pending_bounds_reads_per_call() {
+    let (env, client, merchant, token, first_id) = setup_pending_invoice();
+    client.cancel_invoice(&merchant, &first_id);
+    let description = String::from_str(&env, "Settled");
+    for _ in 1..MAX_CANCEL_SCAN {
+        let invoice_id = client.create_invoice(&merchant, &description, &1000, &token, &None);
+        client.cancel_invoice(&merchant, &invoice_id);
+    }
+    let pending_id = client.create_invoice(&merchant, &description, &1000, &token, &None);
+
+    let (cancelled, next) = client.cancel_all_pending(&merchant, &0);
+    assert_eq!(cancelled.len(), 0);
+    assert_eq!(next, Some(MAX_CANCEL_SCAN));
+
+    let (cancelled, next) = client.cancel_all_pending(&merchant, &MAX_CANCEL_SCAN);
+    assert_eq!(cancelled, soroban_sdk::vec![&env, pending_id]);
+    assert_eq!(next, None);
+}
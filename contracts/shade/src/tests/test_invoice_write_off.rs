@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{token, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    (env, client, merchant, token)
+}
+
+fn create(env: &Env, client: &ShadeClient, merchant: &Address, token: &Address) -> u64 {
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Uncollectable"),
+        &1000,
+        token,
+        &Some(2_000),
+    )
+}
+
+#[test]
+fn test_write_off_pending_invoice() {
+    let (env, client, merchant, token) = setup_test();
+    let invoice_id = create(&env, &client, &merchant, &token);
+
+    env.ledger().set_timestamp(1_500);
+    client.write_off_invoice(&merchant, &invoice_id);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::WrittenOff);
+    assert_eq!(invoice.written_off_at, Some(1_500));
+    assert_eq!(client.get_health().open_invoices, 0);
+    assert_eq!(client.get_outstanding_liability(&token), 0);
+    assert_eq!(client.get_merchant_success_rate(&1), 0);
+}
+
+#[test]
+fn test_write_off_expired_invoice() {
+    let (env, client, merchant, token) = setup_test();
+    let invoice_id = create(&env, &client, &merchant, &token);
+    env.ledger().set_timestamp(2_000);
+    client.expire_invoice(&invoice_id);
+
+    client.write_off_invoice(&merchant, &invoice_id);
+
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::WrittenOff
+    );
+    assert_eq!(client.get_outstanding_liability(&token), 0);
+}
+
+#[test]
+fn test_written_off_invoice_cannot_be_paid() {
+    let (env, client, merchant, token) = setup_test();
+    let invoice_id = create(&env, &client, &merchant, &token);
+    client.write_off_invoice(&merchant, &invoice_id);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+
+    assert!(client.try_pay_invoice(&payer, &invoice_id).is_err());
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::WrittenOff
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_write_off_paid_invoice_fails() {
+    let (env, client, merchant, token) = setup_test();
+    let invoice_id = create(&env, &client, &merchant, &token);
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    client.write_off_invoice(&merchant, &invoice_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_write_off_by_other_merchant_fails() {
+    let (env, client, merchant, token) = setup_test();
+    let invoice_id = create(&env, &client, &merchant, &token);
+    let other = Address::generate(&env);
+    client.register_merchant(&other, &None, &None);
+
+    client.write_off_invoice(&other, &invoice_id);
+}
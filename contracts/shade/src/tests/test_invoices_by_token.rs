@@ -0,0 +1,125 @@
+#![cfg(test)]
+
+use crate::components::invoice::MAX_INVOICE_PAGE;
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let usdc = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let eurc = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &usdc);
+    client.add_accepted_token(&admin, &eurc);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+
+    (env, client, merchant, usdc, eurc)
+}
+
+fn create(env: &Env, client: &ShadeClient, merchant: &Address, token: &Address) -> u64 {
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Reconcile"),
+        &1000,
+        token,
+        &None,
+    )
+}
+
+#[test]
+fn test_get_invoices_by_token_separates_currencies() {
+    let (env, client, merchant, usdc, eurc) = setup_test();
+    let first = create(&env, &client, &merchant, &usdc);
+    let second = create(&env, &client, &merchant, &eurc);
+    let third = create(&env, &client, &merchant, &usdc);
+
+    assert_eq!(
+        client.get_invoices_by_token(&usdc, &0, &10),
+        vec![&env, first, third]
+    );
+    assert_eq!(
+        client.get_invoices_by_token(&eurc, &0, &10),
+        vec![&env, second]
+    );
+    assert!(client
+        .get_invoices_by_token(&Address::generate(&env), &0, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_get_invoices_by_token_pages_through_index() {
+    let (env, client, merchant, usdc, _eurc) = setup_test();
+    for _ in 0..5 {
+        create(&env, &client, &merchant, &usdc);
+    }
+
+    assert_eq!(
+        client.get_invoices_by_token(&usdc, &0, &2),
+        vec![&env, 1, 2]
+    );
+    assert_eq!(
+        client.get_invoices_by_token(&usdc, &2, &2),
+        vec![&env, 3, 4]
+    );
+    assert_eq!(client.get_invoices_by_token(&usdc, &4, &2), vec![&env, 5]);
+    assert!(client.get_invoices_by_token(&usdc, &5, &2).is_empty());
+}
+
+#[test]
+fn test_get_invoices_by_token_follows_token_change() {
+    let (env, client, merchant, usdc, eurc) = setup_test();
+    let moved = create(&env, &client, &merchant, &usdc);
+    let stayed = create(&env, &client, &merchant, &usdc);
+
+    client.change_invoice_token(&merchant, &moved, &eurc);
+
+    assert_eq!(
+        client.get_invoices_by_token(&usdc, &0, &10),
+        vec![&env, stayed]
+    );
+    assert_eq!(
+        client.get_invoices_by_token(&eurc, &0, &10),
+        vec![&env, moved]
+    );
+}
+
+#[test]
+fn test_get_invoices_by_token_follows_reissue() {
+    let (env, client, merchant, usdc, eurc) = setup_test();
+    let original = create(&env, &client, &merchant, &usdc);
+
+    let (reissued, _) = client.reissue_merchant_invoices(&merchant, &usdc, &eurc, &0);
+
+    // the cancelled original stays listed under the token it was issued in
+    assert_eq!(
+        client.get_invoices_by_token(&usdc, &0, &10),
+        vec![&env, original]
+    );
+    assert_eq!(client.get_invoices_by_token(&eurc, &0, &10), reissued);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_get_invoices_by_token_rejects_limit_above_cap() {
+    let (_env, client, _merchant, usdc, _eurc) = setup_test();
+    client.get_invoices_by_token(&usdc, &0, &(MAX_INVOICE_PAGE + 1));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_get_invoices_by_token_rejects_zero_limit() {
+    let (_env, client, _merchant, usdc, _eurc) = setup_test();
+    client.get_invoices_by_token(&usdc, &0, &0);
+}
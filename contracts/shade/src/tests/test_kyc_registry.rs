@@ -0,0 +1,133 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, token, Address, Env, String};
+
+#[contract]
+struct MockKycRegistry;
+
+#[contractimpl]
+impl MockKycRegistry {
+    pub fn verify(env: Env, payer: Address) {
+        env.storage().persistent().set(&payer, &true);
+    }
+
+    pub fn is_verified(env: Env, payer: Address) -> bool {
+        env.storage().persistent().get(&payer).unwrap_or(false)
+    }
+}
+
+struct KycSetup {
+    env: Env,
+    client: ShadeClient<'static>,
+    admin: Address,
+    registry: MockKycRegistryClient<'static>,
+    token: Address,
+    invoice_id: u64,
+}
+
+fn setup_test() -> KycSetup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Regulated"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    let registry_id = env.register(MockKycRegistry, ());
+    let registry = MockKycRegistryClient::new(&env, &registry_id);
+
+    KycSetup {
+        env,
+        client,
+        admin,
+        registry,
+        token,
+        invoice_id,
+    }
+}
+
+fn fund_payer(s: &KycSetup) -> Address {
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&payer, &1000);
+    payer
+}
+
+#[test]
+fn test_no_registry_applies_no_check() {
+    let s = setup_test();
+    assert_eq!(s.client.get_kyc_registry(), None);
+
+    s.client.pay_invoice(&fund_payer(&s), &s.invoice_id);
+    assert_eq!(
+        s.client.get_invoice(&s.invoice_id).status,
+        InvoiceStatus::Paid
+    );
+}
+
+#[test]
+fn test_verified_payer_can_pay() {
+    let s = setup_test();
+    s.client.set_kyc_registry(&s.admin, &s.registry.address);
+    assert_eq!(
+        s.client.get_kyc_registry(),
+        Some(s.registry.address.clone())
+    );
+
+    let payer = fund_payer(&s);
+    s.registry.verify(&payer);
+    s.client.pay_invoice(&payer, &s.invoice_id);
+    assert_eq!(
+        s.client.get_invoice(&s.invoice_id).status,
+        InvoiceStatus::Paid
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #52)")]
+fn test_unverified_payer_is_rejected() {
+    let s = setup_test();
+    s.client.set_kyc_registry(&s.admin, &s.registry.address);
+
+    s.client.pay_invoice(&fund_payer(&s), &s.invoice_id);
+}
+
+#[test]
+fn test_cleared_registry_lifts_check() {
+    let s = setup_test();
+    s.client.set_kyc_registry(&s.admin, &s.registry.address);
+    s.client.clear_kyc_registry(&s.admin);
+    assert_eq!(s.client.get_kyc_registry(), None);
+
+    s.client.pay_invoice(&fund_payer(&s), &s.invoice_id);
+    assert_eq!(
+        s.client.get_invoice(&s.invoice_id).status,
+        InvoiceStatus::Paid
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_kyc_registry_non_admin_fails() {
+    let s = setup_test();
+    s.client
+        .set_kyc_registry(&Address::generate(&s.env), &s.registry.address);
+}
@@ -0,0 +1,152 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{token, vec, Address, Env, IntoVal, Map, String, Symbol, TryIntoVal, Val};
+
+fn setup_test() -> (
+    Env,
+    ShadeClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    (env, client, contract_id, admin, token, merchant)
+}
+
+fn create_invoice(
+    env: &Env,
+    client: &ShadeClient,
+    merchant: &Address,
+    token: &Address,
+    amount: i128,
+) -> u64 {
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Large order"),
+        &amount,
+        token,
+        &None,
+    )
+}
+
+fn new_payer(env: &Env, token: &Address, amount: i128) -> Address {
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&payer, &amount);
+    payer
+}
+
+/// Returns `(invoice_id, amount, threshold)` for every `large_payment` event
+/// emitted by the contract in the last invocation.
+fn large_payments(env: &Env, contract_id: &Address) -> soroban_sdk::Vec<(u64, i128, i128)> {
+    let topic = vec![env, Symbol::new(env, "large_payment").into_val(env)];
+    let mut payments = soroban_sdk::Vec::new(env);
+    for (event_contract_id, topics, data) in env.events().all().iter() {
+        if event_contract_id != *contract_id || topics != topic {
+            continue;
+        }
+        let data_map: Map<Symbol, Val> = data.try_into_val(env).unwrap();
+        let field = |name: &str| data_map.get(Symbol::new(env, name)).unwrap();
+        let invoice_id: u64 = field("invoice_id").try_into_val(env).unwrap();
+        let amount: i128 = field("amount").try_into_val(env).unwrap();
+        let threshold: i128 = field("threshold").try_into_val(env).unwrap();
+        payments.push_back((invoice_id, amount, threshold));
+    }
+    payments
+}
+
+#[test]
+fn test_large_payment_event_fires_at_or_above_threshold() {
+    let (env, client, contract_id, admin, token, merchant) = setup_test();
+    client.set_large_payment_threshold(&admin, &token, &5_000);
+    assert_eq!(client.get_large_payment_threshold(&token), 5_000);
+
+    let at_threshold = create_invoice(&env, &client, &merchant, &token, 5_000);
+    client.pay_invoice(&new_payer(&env, &token, 5_000), &at_threshold);
+    assert_eq!(
+        large_payments(&env, &contract_id),
+        vec![&env, (at_threshold, 5_000, 5_000)]
+    );
+
+    let above = create_invoice(&env, &client, &merchant, &token, 8_000);
+    client.pay_invoice(&new_payer(&env, &token, 8_000), &above);
+    assert_eq!(
+        large_payments(&env, &contract_id),
+        vec![&env, (above, 8_000, 5_000)]
+    );
+}
+
+#[test]
+fn test_large_payment_event_skipped_below_threshold() {
+    let (env, client, contract_id, admin, token, merchant) = setup_test();
+    client.set_large_payment_threshold(&admin, &token, &5_000);
+
+    let invoice_id = create_invoice(&env, &client, &merchant, &token, 4_999);
+    client.pay_invoice(&new_payer(&env, &token, 4_999), &invoice_id);
+
+    assert!(large_payments(&env, &contract_id).is_empty());
+}
+
+#[test]
+fn test_large_payment_event_waits_for_full_settlement() {
+    let (env, client, contract_id, admin, token, merchant) = setup_test();
+    client.set_large_payment_threshold(&admin, &token, &5_000);
+    let invoice_id = create_invoice(&env, &client, &merchant, &token, 6_000);
+    let payer = new_payer(&env, &token, 6_000);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &3_000);
+    assert!(large_payments(&env, &contract_id).is_empty());
+
+    client.pay_invoice_partial(&payer, &invoice_id, &3_000);
+    assert_eq!(
+        large_payments(&env, &contract_id),
+        vec![&env, (invoice_id, 6_000, 5_000)]
+    );
+}
+
+#[test]
+fn test_large_payment_event_disabled_by_default_and_by_zero() {
+    let (env, client, contract_id, admin, token, merchant) = setup_test();
+    assert_eq!(client.get_large_payment_threshold(&token), 0);
+
+    let invoice_id = create_invoice(&env, &client, &merchant, &token, 1_000_000);
+    client.pay_invoice(&new_payer(&env, &token, 1_000_000), &invoice_id);
+    assert!(large_payments(&env, &contract_id).is_empty());
+
+    client.set_large_payment_threshold(&admin, &token, &5_000);
+    client.set_large_payment_threshold(&admin, &token, &0);
+    let invoice_id = create_invoice(&env, &client, &merchant, &token, 1_000_000);
+    client.pay_invoice(&new_payer(&env, &token, 1_000_000), &invoice_id);
+    assert!(large_payments(&env, &contract_id).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_set_large_payment_threshold_rejects_negative() {
+    let (_env, client, _contract_id, admin, token, _merchant) = setup_test();
+    client.set_large_payment_threshold(&admin, &token, &-1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_large_payment_threshold_requires_admin() {
+    let (env, client, _contract_id, _admin, token, _merchant) = setup_test();
+    client.set_large_payment_threshold(&Address::generate(&env), &token, &5_000);
+}
@@ -0,0 +1,221 @@
+#![cfg(test)]
+
+use crate::components::invoice::SECONDS_PER_DAY;
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger};
+use soroban_sdk::{token, Address, Env, FromVal, String, Symbol};
+
+const DUE_DATE: u64 = 100_000;
+
+struct Setup {
+    env: Env,
+    client: ShadeClient<'static>,
+    admin: Address,
+    token: Address,
+    merchant: Address,
+    merchant_account: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account = Address::generate(&env);
+    client.set_merchant_account(&merchant, &merchant_account);
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+        merchant,
+        merchant_account,
+    }
+}
+
+// 1% per full day overdue on 10_000.
+fn create_late_fee_invoice(s: &Setup) -> u64 {
+    s.client.create_invoice_with_late_fee(
+        &s.merchant,
+        &String::from_str(&s.env, "Late fee"),
+        &10_000,
+        &s.token,
+        &DUE_DATE,
+        &100,
+    )
+}
+
+fn set_time(s: &Setup, timestamp: u64) {
+    s.env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+fn funded_payer(s: &Setup, amount: i128) -> Address {
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&payer, &amount);
+    payer
+}
+
+#[test]
+fn test_no_late_fee_before_or_on_due_date() {
+    let s = setup_test();
+    let invoice_id = create_late_fee_invoice(&s);
+
+    assert_eq!(s.client.get_amount_due(&invoice_id), 10_000);
+    set_time(&s, DUE_DATE);
+    assert_eq!(s.client.get_amount_due(&invoice_id), 10_000);
+
+    let payer = funded_payer(&s, 10_000);
+    s.client.pay_invoice(&payer, &invoice_id);
+
+    let invoice = s.client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.amount_paid, 10_000);
+}
+
+#[test]
+fn test_late_fee_accrues_per_full_day_overdue() {
+    let s = setup_test();
+    let invoice_id = create_late_fee_invoice(&s);
+
+    set_time(&s, DUE_DATE + SECONDS_PER_DAY - 1);
+    assert_eq!(s.client.get_amount_due(&invoice_id), 10_000);
+    set_time(&s, DUE_DATE + SECONDS_PER_DAY);
+    assert_eq!(s.client.get_amount_due(&invoice_id), 10_100);
+    set_time(&s, DUE_DATE + 3 * SECONDS_PER_DAY + 5);
+    assert_eq!(s.client.get_amount_due(&invoice_id), 10_300);
+}
+
+#[test]
+fn test_late_fee_is_capped() {
+    let s = setup_test();
+    let invoice_id = create_late_fee_invoice(&s);
+
+    // default cap is 10%, reached after ten days
+    set_time(&s, DUE_DATE + 30 * SECONDS_PER_DAY);
+    assert_eq!(s.client.get_amount_due(&invoice_id), 11_000);
+
+    s.client.set_max_late_fee_bps(&s.admin, &250);
+    assert_eq!(s.client.get_amount_due(&invoice_id), 10_250);
+}
+
+#[test]
+fn test_payment_charges_accrued_late_fee() {
+    let s = setup_test();
+    let invoice_id = create_late_fee_invoice(&s);
+    set_time(&s, DUE_DATE + 2 * SECONDS_PER_DAY);
+
+    let payer = funded_payer(&s, 10_200);
+    s.client.pay_invoice(&payer, &invoice_id);
+
+    let invoice = s.client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.amount, 10_200);
+    assert_eq!(invoice.amount_paid, 10_200);
+    let token_client = token::TokenClient::new(&s.env, &s.token);
+    assert_eq!(token_client.balance(&s.merchant_account), 10_200);
+    assert_eq!(token_client.balance(&payer), 0);
+    assert_eq!(s.client.get_amount_due(&invoice_id), 0);
+}
+
+#[test]
+fn test_quote_includes_accrued_late_fee() {
+    let s = setup_test();
+    let invoice_id = create_late_fee_invoice(&s);
+    set_time(&s, DUE_DATE + 4 * SECONDS_PER_DAY);
+
+    let quote = s
+        .client
+        .quote_payment(&invoice_id, &s.token, &Address::generate(&s.env));
+    assert_eq!(quote.principal, 10_400);
+    assert_eq!(quote.total, 10_400);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_overdue_partial_payment_fails() {
+    let s = setup_test();
+    let invoice_id = create_late_fee_invoice(&s);
+    set_time(&s, DUE_DATE + SECONDS_PER_DAY);
+
+    let payer = funded_payer(&s, 10_000);
+    s.client.pay_invoice_partial(&payer, &invoice_id, &10_000);
+}
+
+#[test]
+fn test_late_fee_applies_to_remaining_balance_after_early_partial() {
+    let s = setup_test();
+    let invoice_id = create_late_fee_invoice(&s);
+    let payer = funded_payer(&s, 10_500);
+    s.client.pay_invoice_partial(&payer, &invoice_id, &4_000);
+
+    set_time(&s, DUE_DATE + 5 * SECONDS_PER_DAY);
+    assert_eq!(s.client.get_amount_due(&invoice_id), 6_500);
+    s.client.pay_invoice(&payer, &invoice_id);
+
+    let invoice = s.client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.amount_paid, 10_500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_zero_late_fee_rate_fails() {
+    let s = setup_test();
+    s.client.create_invoice_with_late_fee(
+        &s.merchant,
+        &String::from_str(&s.env, "Late fee"),
+        &10_000,
+        &s.token,
+        &DUE_DATE,
+        &0,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #47)")]
+fn test_past_due_date_fails() {
+    let s = setup_test();
+    set_time(&s, DUE_DATE + 1);
+    create_late_fee_invoice(&s);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_max_late_fee_requires_admin() {
+    let s = setup_test();
+    s.client
+        .set_max_late_fee_bps(&Address::generate(&s.env), &500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #58)")]
+fn test_max_late_fee_above_10000_bps_rejected() {
+    let s = setup_test();
+    s.client.set_max_late_fee_bps(&s.admin, &10_001);
+}
+
+#[test]
+fn test_set_max_late_fee_emits_event() {
+    let s = setup_test();
+    s.client.set_max_late_fee_bps(&s.admin, &250);
+
+    let events = s.env.events().all();
+    let (contract_id, topics, _) = events.last().unwrap();
+    assert_eq!(contract_id, s.client.address);
+    assert_eq!(
+        Symbol::from_val(&s.env, &topics.get(0).unwrap()),
+        Symbol::new(&s.env, "max_late_fee_set_event")
+    );
+}
@@ -0,0 +1,69 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{token, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    (env, client, merchant, token)
+}
+
+#[test]
+fn test_invoice_records_ledger_sequences() {
+    let (env, client, merchant, token) = setup_test();
+    env.ledger().set_sequence_number(100);
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Audited"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.ledger_created, 100);
+    assert_eq!(invoice.ledger_paid, None);
+
+    env.ledger().set_sequence_number(150);
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.ledger_created, 100);
+    assert_eq!(invoice.ledger_paid, Some(150));
+}
+
+#[test]
+fn test_partial_payment_leaves_ledger_paid_unset() {
+    let (env, client, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Audited"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice_partial(&payer, &invoice_id, &400);
+
+    assert_eq!(client.get_invoice(&invoice_id).ledger_paid, None);
+}
@@ -0,0 +1,329 @@
+#![cfg(test)]
+
+use crate::components::maintenance::{ARCHIVE_AFTER, MAX_MAINTENANCE_SCAN};
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceFilter, InvoiceStatus};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{token, Address, Env, FromVal, String, Symbol};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    (env, client, admin, token, merchant)
+}
+
+fn create(
+    env: &Env,
+    client: &ShadeClient,
+    merchant: &Address,
+    token: &Address,
+    expires_at: Option<u64>,
+) -> u64 {
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Keeper"),
+        &1000,
+        token,
+        &expires_at,
+    )
+}
+
+fn pay(env: &Env, client: &ShadeClient, token: &Address, invoice_id: u64) {
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+}
+
+fn no_filter() -> InvoiceFilter {
+    InvoiceFilter {
+        status: None,
+        merchant: None,
+        min_amount: None,
+        max_amount: None,
+        start_date: None,
+        end_date: None,
+    }
+}
+
+#[test]
+fn test_run_maintenance_expires_overdue_pending_invoices() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    let overdue = create(&env, &client, &merchant, &token, Some(2_000));
+    let current = create(&env, &client, &merchant, &token, Some(10_000));
+    let open_ended = create(&env, &client, &merchant, &token, None);
+    assert_eq!(client.get_health().open_invoices, 3);
+
+    env.ledger().set_timestamp(2_000);
+    let report = client.run_maintenance(&Address::generate(&env), &10);
+
+    assert_eq!(report.expired, 1);
+    assert_eq!(report.archived, 0);
+    assert_eq!(report.scanned, 3);
+    assert_eq!(client.get_invoice(&overdue).status, InvoiceStatus::Expired);
+    assert_eq!(client.get_invoice(&current).status, InvoiceStatus::Pending);
+    assert_eq!(
+        client.get_invoice(&open_ended).status,
+        InvoiceStatus::Pending
+    );
+    assert_eq!(client.get_health().open_invoices, 2);
+}
+
+#[test]
+fn test_run_maintenance_archives_long_settled_invoices() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    let settled = create(&env, &client, &merchant, &token, None);
+    pay(&env, &client, &token, settled);
+    let recent = create(&env, &client, &merchant, &token, None);
+
+    env.ledger().set_timestamp(1_000 + ARCHIVE_AFTER);
+    pay(&env, &client, &token, recent);
+    let report = client.run_maintenance(&Address::generate(&env), &10);
+
+    assert_eq!(report.archived, 1);
+    assert!(client.get_invoice(&settled).archived);
+    assert!(!client.get_invoice(&recent).archived);
+    assert_eq!(client.get_invoice(&settled).status, InvoiceStatus::Paid);
+
+    let listed = client.get_invoices(&no_filter());
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed.get(0).unwrap().id, recent);
+}
+
+#[test]
+fn test_run_maintenance_respects_limit_and_resumes() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    for _ in 0..3 {
+        create(&env, &client, &merchant, &token, Some(2_000));
+    }
+
+    env.ledger().set_timestamp(2_000);
+    let caller = Address::generate(&env);
+    let first = client.run_maintenance(&caller, &2);
+    assert_eq!(first.expired, 2);
+    assert_eq!(client.get_invoice(&3).status, InvoiceStatus::Pending);
+
+    let second = client.run_maintenance(&caller, &2);
+    assert_eq!(second.expired, 1);
+    assert_eq!(client.get_invoice(&3).status, InvoiceStatus::Expired);
+
+    let third = client.run_maintenance(&caller, &2);
+    assert_eq!(third.expired + third.archived, 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_expired_invoice_cannot_be_paid() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    let invoice_id = create(&env, &client, &merchant, &token, Some(2_000));
+
+    env.ledger().set_timestamp(2_000);
+    client.run_maintenance(&Address::generate(&env), &10);
+    pay(&env, &client, &token, invoice_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_run_maintenance_zero_limit_fails() {
+    let (env, client, _admin, _token, _merchant) = setup_test();
+    client.run_maintenance(&Address::generate(&env), &0);
+}
+
+#[test]
+fn test_get_expirable_lists_only_overdue_pending_invoices() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    let overdue = create(&env, &client, &merchant, &token, Some(2_000));
+    create(&env, &client, &merchant, &token, Some(10_000));
+    create(&env, &client, &merchant, &token, None);
+    let paid = create(&env, &client, &merchant, &token, Some(2_000));
+    pay(&env, &client, &token, paid);
+
+    assert_eq!(client.get_expirable(&1, &10).len(), 0);
+
+    env.ledger().set_timestamp(2_000);
+    let expirable = client.get_expirable(&1, &10);
+    assert_eq!(expirable.len(), 1);
+    assert_eq!(expirable.get(0).unwrap(), overdue);
+
+    client.run_maintenance(&Address::generate(&env), &10);
+    assert_eq!(client.get_expirable(&1, &10).len(), 0);
+}
+
+#[test]
+fn test_get_expirable_respects_limit() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    for _ in 0..3 {
+        create(&env, &client, &merchant, &token, Some(2_000));
+    }
+
+    env.ledger().set_timestamp(2_000);
+    let expirable = client.get_expirable(&1, &2);
+    assert_eq!(expirable.len(), 2);
+    assert_eq!(expirable.get(0).unwrap(), 1);
+    assert_eq!(expirable.get(1).unwrap(), 2);
+}
+
+#[test]
+fn test_get_expirable_scans_from_start_id() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    for _ in 0..3 {
+        create(&env, &client, &merchant, &token, Some(2_000));
+    }
+
+    env.ledger().set_timestamp(2_000);
+    let expirable = client.get_expirable(&3, &10);
+    assert_eq!(expirable.len(), 1);
+    assert_eq!(expirable.get(0).unwrap(), 3);
+}
+
+#[test]
+fn test_get_expirable_caps_scan() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    for _ in 0..MAX_MAINTENANCE_SCAN {
+        create(&env, &client, &merchant, &token, None);
+    }
+    let overdue = create(&env, &client, &merchant, &token, Some(2_000));
+
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(client.get_expirable(&1, &10).len(), 0);
+    let expirable = client.get_expirable(&(MAX_MAINTENANCE_SCAN + 1), &10);
+    assert_eq!(expirable.get(0).unwrap(), overdue);
+}
+
+#[test]
+fn test_auto_archive_delay_defaults_to_archive_after() {
+    let (_env, client, _admin, _token, _merchant) = setup_test();
+    assert_eq!(client.get_auto_archive_delay(), ARCHIVE_AFTER);
+}
+
+#[test]
+fn test_custom_auto_archive_delay_archives_only_after_elapsed() {
+    let (env, client, admin, token, merchant) = setup_test();
+    client.set_auto_archive_delay(&admin, &3_600);
+    let settled = create(&env, &client, &merchant, &token, None);
+    pay(&env, &client, &token, settled);
+    let caller = Address::generate(&env);
+
+    env.ledger().set_timestamp(1_000 + 3_599);
+    assert_eq!(client.run_maintenance(&caller, &10).archived, 0);
+    assert!(!client.get_invoice(&settled).archived);
+
+    env.ledger().set_timestamp(1_000 + 3_600);
+    assert_eq!(client.run_maintenance(&caller, &10).archived, 1);
+    let invoice = client.get_invoice(&settled);
+    assert!(invoice.archived);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+}
+
+#[test]
+fn test_zero_auto_archive_delay_disables_archival() {
+    let (env, client, admin, token, merchant) = setup_test();
+    client.set_auto_archive_delay(&admin, &0);
+    let settled = create(&env, &client, &merchant, &token, None);
+    pay(&env, &client, &token, settled);
+
+    env.ledger().set_timestamp(1_000 + ARCHIVE_AFTER);
+    assert_eq!(
+        client
+            .run_maintenance(&Address::generate(&env), &10)
+            .archived,
+        0
+    );
+    assert!(!client.get_invoice(&settled).archived);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_auto_archive_delay_non_admin_fails() {
+    let (env, client, _admin, _token, _merchant) = setup_test();
+    client.set_auto_archive_delay(&Address::generate(&env), &3_600);
+}
+
+#[test]
+fn test_expire_invoice_after_due_date() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    let invoice_id = create(&env, &client, &merchant, &token, Some(1_500));
+
+    env.ledger().set_timestamp(1_500);
+    client.expire_invoice(&invoice_id);
+
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Expired
+    );
+    assert_eq!(client.get_health().open_invoices, 0);
+    assert_eq!(client.get_outstanding_liability(&token), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #27)")]
+fn test_payment_after_due_date_rejected() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    let invoice_id = create(&env, &client, &merchant, &token, Some(1_500));
+
+    env.ledger().set_timestamp(1_501);
+    pay(&env, &client, &token, invoice_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_expire_invoice_before_due_date_fails() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    let invoice_id = create(&env, &client, &merchant, &token, Some(1_500));
+
+    client.expire_invoice(&invoice_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_expire_paid_invoice_fails() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    let invoice_id = create(&env, &client, &merchant, &token, Some(1_500));
+    pay(&env, &client, &token, invoice_id);
+
+    env.ledger().set_timestamp(2_000);
+    client.expire_invoice(&invoice_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #8)")]
+fn test_expire_missing_invoice_fails() {
+    let (_env, client, _admin, _token, _merchant) = setup_test();
+    client.expire_invoice(&42);
+}
+
+#[test]
+fn test_run_maintenance_publishes_invoice_expired_events() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    create(&env, &client, &merchant, &token, Some(2_000));
+    create(&env, &client, &merchant, &token, Some(2_000));
+
+    env.ledger().set_timestamp(2_000);
+    client.run_maintenance(&Address::generate(&env), &10);
+
+    let expired_topic = Symbol::new(&env, "invoice_expired_event");
+    let expired_events = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(contract_id, topics, _)| {
+            *contract_id == client.address
+                && Symbol::from_val(&env, &topics.get(0).unwrap()) == expired_topic
+        })
+        .count();
+    assert_eq!(expired_events, 2);
+}
@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{token, Address, Env, FromVal, String, Symbol};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, u64) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Instalments"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+
+    (env, client, admin, payer, invoice_id)
+}
+
+#[test]
+fn test_partials_unlimited_by_default() {
+    let (_env, client, _admin, payer, invoice_id) = setup_test();
+    assert_eq!(client.get_max_partials(), 0);
+
+    for _ in 0..5 {
+        client.pay_invoice_partial(&payer, &invoice_id, &100);
+    }
+    assert_eq!(client.get_partial_count(&invoice_id), 5);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #56)")]
+fn test_partial_beyond_limit_is_rejected() {
+    let (_env, client, admin, payer, invoice_id) = setup_test();
+    client.set_max_partials(&admin, &2);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &100);
+    client.pay_invoice_partial(&payer, &invoice_id, &100);
+    client.pay_invoice_partial(&payer, &invoice_id, &100);
+}
+
+#[test]
+fn test_settling_payment_allowed_at_limit() {
+    let (_env, client, admin, payer, invoice_id) = setup_test();
+    client.set_max_partials(&admin, &2);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &100);
+    client.pay_invoice_partial(&payer, &invoice_id, &100);
+    assert_eq!(client.get_partial_count(&invoice_id), 2);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &800);
+    assert_eq!(client.get_invoice(&invoice_id).status, InvoiceStatus::Paid);
+    assert_eq!(client.get_partial_count(&invoice_id), 2);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_max_partials_non_admin_fails() {
+    let (env, client, _admin, _payer, _invoice_id) = setup_test();
+    client.set_max_partials(&Address::generate(&env), &2);
+}
+
+#[test]
+fn test_set_max_partials_emits_event() {
+    let (env, client, admin, _payer, _invoice_id) = setup_test();
+    client.set_max_partials(&admin, &2);
+
+    let events = env.events().all();
+    let (contract_id, topics, _) = events.last().unwrap();
+    assert_eq!(contract_id, client.address);
+    assert_eq!(
+        Symbol::from_val(&env, &topics.get(0).unwrap()),
+        Symbol::new(&env, "max_partials_set_event")
+    );
+}
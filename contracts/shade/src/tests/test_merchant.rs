@@ -1,10 +1,11 @@
 #![cfg(test)]
 
+use crate::components::merchant::MAX_MERCHANT_NAME_LEN;
 use crate::errors::ContractError;
 use crate::shade::{Shade, ShadeClient};
 use crate::types::DataKey;
 use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, String};
 
 fn setup_test() -> (Env, ShadeClient<'static>, Address) {
     let env = Env::default();
@@ -24,7 +25,7 @@ fn test_register_merchant_successfully() {
     let (env, client, contract_id) = setup_test();
     let merchant = Address::generate(&env);
 
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let merchant_data = client.get_merchant(&1u64);
     assert_eq!(merchant_data.id, 1);
@@ -48,8 +49,8 @@ fn test_register_multiple_merchants_with_unique_ids() {
     let merchant_1 = Address::generate(&env);
     let merchant_2 = Address::generate(&env);
 
-    client.register_merchant(&merchant_1);
-    client.register_merchant(&merchant_2);
+    client.register_merchant(&merchant_1, &None, &None);
+    client.register_merchant(&merchant_2, &None, &None);
 
     let merchant_data_1 = client.get_merchant(&1u64);
     let merchant_data_2 = client.get_merchant(&2u64);
@@ -73,11 +74,11 @@ fn test_register_duplicate_merchant_fails() {
     let (env, client, _contract_id) = setup_test();
     let merchant = Address::generate(&env);
 
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let expected_error =
         soroban_sdk::Error::from_contract_error(ContractError::MerchantAlreadyRegistered as u32);
-    let result = client.try_register_merchant(&merchant);
+    let result = client.try_register_merchant(&merchant, &None, &None);
 
     assert!(matches!(result, Err(Ok(err)) if err == expected_error));
 }
@@ -87,7 +88,7 @@ fn test_get_merchant_lookup_valid_id() {
     let (env, client, _contract_id) = setup_test();
     let merchant = Address::generate(&env);
 
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let merchant_data = client.get_merchant(&1u64);
     assert_eq!(merchant_data.id, 1);
@@ -115,8 +116,95 @@ fn test_is_merchant_returns_true_for_registered_and_false_for_unknown() {
     let registered_merchant = Address::generate(&env);
     let unknown_merchant = Address::generate(&env);
 
-    client.register_merchant(&registered_merchant);
+    client.register_merchant(&registered_merchant, &None, &None);
 
     assert!(client.is_merchant(&registered_merchant));
     assert!(!client.is_merchant(&unknown_merchant));
 }
+
+#[test]
+fn test_register_merchant_metadata_round_trips() {
+    let (env, client, _contract_id) = setup_test();
+    let merchant = Address::generate(&env);
+    let name = String::from_str(&env, "Corner Coffee");
+    let contact = String::from_str(&env, "billing@corner.coffee");
+
+    client.register_merchant(&merchant, &Some(name.clone()), &Some(contact.clone()));
+
+    let merchant_data = client.get_merchant(&1u64);
+    assert_eq!(merchant_data.address, merchant);
+    assert_eq!(merchant_data.name, name);
+    assert_eq!(merchant_data.contact, contact);
+}
+
+#[test]
+fn test_register_merchant_leaves_metadata_empty() {
+    let (env, client, _contract_id) = setup_test();
+    let merchant = Address::generate(&env);
+
+    client.register_merchant(&merchant, &None, &None);
+
+    let merchant_data = client.get_merchant(&1u64);
+    assert!(merchant_data.name.is_empty());
+    assert!(merchant_data.contact.is_empty());
+}
+
+#[test]
+fn test_register_merchant_with_empty_metadata() {
+    let (env, client, _contract_id) = setup_test();
+    let merchant = Address::generate(&env);
+    let empty = String::from_str(&env, "");
+
+    client.register_merchant(&merchant, &Some(empty.clone()), &Some(empty));
+
+    assert!(client.get_merchant(&1u64).name.is_empty());
+}
+
+#[test]
+fn test_register_merchant_with_overlong_name_fails() {
+    let (env, client, _contract_id) = setup_test();
+    let merchant = Address::generate(&env);
+    let name = String::from_str(&env, &"n".repeat(MAX_MERCHANT_NAME_LEN as usize + 1));
+
+    let result = client.try_register_merchant(&merchant, &Some(name), &None);
+
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            ContractError::DescriptionTooLong as u32
+        )))
+    );
+    assert!(!client.is_merchant(&merchant));
+}
+
+#[test]
+fn test_get_merchant_id_for_registered_address() {
+    let (env, client, _contract_id) = setup_test();
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.register_merchant(&first, &None, &None);
+    client.register_merchant(&second, &None, &None);
+
+    assert_eq!(client.get_merchant_id(&first), 1);
+    assert_eq!(client.get_merchant_id(&second), 2);
+    assert_eq!(
+        client
+            .get_merchant(&client.get_merchant_id(&second))
+            .address,
+        second
+    );
+}
+
+#[test]
+fn test_get_merchant_id_for_unknown_address_fails() {
+    let (env, client, _contract_id) = setup_test();
+
+    let result = client.try_get_merchant_id(&Address::generate(&env));
+
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            ContractError::MerchantNotFound as u32
+        )))
+    );
+}
@@ -53,7 +53,7 @@ fn test_successful_activation() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let merchant_id = 1u64;
     assert!(client.is_merchant_active(&merchant_id));
@@ -94,7 +94,7 @@ fn test_successful_deactivation() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let merchant_id = 1u64;
     assert!(client.is_merchant_active(&merchant_id));
@@ -130,7 +130,7 @@ fn test_unauthorized_status_change() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let merchant_id = 1u64;
 
@@ -206,3 +206,146 @@ fn test_is_merchant_active_zero_id() {
     let zero_merchant_id = 0u64;
     client.is_merchant_active(&zero_merchant_id);
 }
+
+#[test]
+fn test_deactivate_merchants_skips_unknown_and_inactive() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    for _ in 0..3 {
+        client.register_merchant(&Address::generate(&env), &None, &None);
+    }
+    client.set_merchant_status(&admin, &2, &false);
+
+    let changed = client.deactivate_merchants(&admin, &soroban_sdk::vec![&env, 1, 2, 3, 99]);
+    let events = env.events().all();
+
+    assert_eq!(changed, soroban_sdk::vec![&env, 1, 3]);
+    assert!(!client.is_merchant_active(&1));
+    assert!(!client.is_merchant_active(&2));
+    assert!(!client.is_merchant_active(&3));
+    assert_eq!(client.get_health().active_merchants, 0);
+
+    let deactivated_topic = Symbol::new(&env, "merchant_deactivated");
+    let mut deactivated_events = 0;
+    for (event_contract_id, topics, _) in events.iter() {
+        let event_name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+        if event_contract_id == contract_id && event_name == deactivated_topic {
+            deactivated_events += 1;
+        }
+    }
+    assert_eq!(deactivated_events, 2);
+}
+
+#[test]
+fn test_deactivate_merchants_oversized_batch_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let mut merchant_ids = soroban_sdk::Vec::new(&env);
+    for merchant_id in 0..=merchant_component::MAX_DEACTIVATION_BATCH as u64 {
+        merchant_ids.push_back(merchant_id);
+    }
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::InvalidAmount as u32);
+    let result = client.try_deactivate_merchants(&admin, &merchant_ids);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_deactivate_merchants_non_admin_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.register_merchant(&Address::generate(&env), &None, &None);
+
+    client.deactivate_merchants(&Address::generate(&env), &soroban_sdk::vec![&env, 1]);
+}
+
+fn setup_invoicing() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+
+    (env, client, admin, token, merchant)
+}
+
+fn create_invoice(env: &Env, client: &ShadeClient, merchant: &Address, token: &Address) -> u64 {
+    client.create_invoice(
+        merchant,
+        &soroban_sdk::String::from_str(env, "Suspended"),
+        &1000,
+        token,
+        &None,
+    )
+}
+
+#[test]
+fn test_deactivate_merchant_blocks_invoice_creation_until_reactivated() {
+    let (env, client, admin, token, merchant) = setup_invoicing();
+
+    client.deactivate_merchant(&admin, &1);
+    assert!(!client.is_merchant_active(&1));
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::MerchantNotActive as u32);
+    let result = client.try_create_invoice(
+        &merchant,
+        &soroban_sdk::String::from_str(&env, "Suspended"),
+        &1000,
+        &token,
+        &None,
+    );
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+
+    client.reactivate_merchant(&admin, &1);
+    assert!(client.is_merchant_active(&1));
+    assert_eq!(create_invoice(&env, &client, &merchant, &token), 1);
+}
+
+#[test]
+fn test_deactivate_merchant_keeps_history() {
+    let (env, client, admin, token, merchant) = setup_invoicing();
+    let invoice_id = create_invoice(&env, &client, &merchant, &token);
+
+    client.deactivate_merchant(&admin, &1);
+
+    assert_eq!(client.get_merchant(&1).address, merchant);
+    assert_eq!(client.get_invoice(&invoice_id).merchant_id, 1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_reactivate_merchant_non_admin_fails() {
+    let (env, client, admin, _token, _merchant) = setup_invoicing();
+    client.deactivate_merchant(&admin, &1);
+    client.reactivate_merchant(&Address::generate(&env), &1);
+}
@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+
+    (env, client, admin, token, merchant)
+}
+
+fn create(env: &Env, client: &ShadeClient, merchant: &Address, token: &Address) -> u64 {
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Burst"),
+        &1000,
+        token,
+        &None,
+    )
+}
+
+#[test]
+fn test_cooldown_disabled_by_default() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    assert_eq!(client.get_merchant_cooldown(), 0);
+
+    create(&env, &client, &merchant, &token);
+    create(&env, &client, &merchant, &token);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #53)")]
+fn test_creation_inside_cooldown_fails() {
+    let (env, client, admin, token, merchant) = setup_test();
+    client.set_merchant_cooldown(&admin, &60);
+
+    create(&env, &client, &merchant, &token);
+    env.ledger().set_timestamp(1_059);
+    create(&env, &client, &merchant, &token);
+}
+
+#[test]
+fn test_creation_at_cooldown_edge_succeeds() {
+    let (env, client, admin, token, merchant) = setup_test();
+    client.set_merchant_cooldown(&admin, &60);
+    assert_eq!(client.get_merchant_cooldown(), 60);
+
+    create(&env, &client, &merchant, &token);
+    env.ledger().set_timestamp(1_060);
+    let invoice_id = create(&env, &client, &merchant, &token);
+    assert_eq!(invoice_id, 2);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #53)")]
+fn test_draft_inside_cooldown_fails() {
+    let (env, client, admin, token, merchant) = setup_test();
+    client.set_merchant_cooldown(&admin, &60);
+
+    create(&env, &client, &merchant, &token);
+    client.create_invoice_draft(
+        &merchant,
+        &String::from_str(&env, "Burst"),
+        &1000,
+        &token,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #53)")]
+fn test_split_invoice_inside_cooldown_fails() {
+    let (env, client, admin, token, merchant) = setup_test();
+    client.set_merchant_cooldown(&admin, &60);
+
+    create(&env, &client, &merchant, &token);
+    client.create_split_invoice(
+        &merchant,
+        &vec![&env, (1, 1000)],
+        &token,
+        &String::from_str(&env, "Burst"),
+    );
+}
+
+#[test]
+fn test_cooldown_is_per_merchant() {
+    let (env, client, admin, token, merchant) = setup_test();
+    client.set_merchant_cooldown(&admin, &60);
+
+    let other_merchant = Address::generate(&env);
+    client.register_merchant(&other_merchant, &None, &None);
+
+    create(&env, &client, &merchant, &token);
+    create(&env, &client, &other_merchant, &token);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_merchant_cooldown_non_admin_fails() {
+    let (env, client, _admin, _token, _merchant) = setup_test();
+    client.set_merchant_cooldown(&Address::generate(&env), &60);
+}
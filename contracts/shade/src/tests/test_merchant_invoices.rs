@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    (env, client, admin, token)
+}
+
+// Merchant ids are assigned sequentially from 1.
+fn register(env: &Env, client: &ShadeClient, merchant_id: u64) -> (Address, u64) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant, &None, &None);
+    assert_eq!(client.get_merchant(&merchant_id).address, merchant);
+    (merchant, merchant_id)
+}
+
+fn create(env: &Env, client: &ShadeClient, merchant: &Address, token: &Address) -> u64 {
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Order"),
+        &1000,
+        token,
+        &None,
+    )
+}
+
+#[test]
+fn test_get_invoices_by_merchant_lists_each_merchant_separately() {
+    let (env, client, _admin, token) = setup_test();
+    let (alice, alice_id) = register(&env, &client, 1);
+    let (bob, bob_id) = register(&env, &client, 2);
+
+    let first = create(&env, &client, &alice, &token);
+    let second = create(&env, &client, &bob, &token);
+    let third = create(&env, &client, &alice, &token);
+    let fourth = create(&env, &client, &bob, &token);
+    let fifth = create(&env, &client, &alice, &token);
+
+    assert_eq!(
+        client.get_invoices_by_merchant(&alice_id),
+        vec![&env, first, third, fifth]
+    );
+    assert_eq!(
+        client.get_invoices_by_merchant(&bob_id),
+        vec![&env, second, fourth]
+    );
+}
+
+#[test]
+fn test_get_invoices_by_merchant_is_empty_without_invoices() {
+    let (env, client, _admin, _token) = setup_test();
+    let (_merchant, merchant_id) = register(&env, &client, 1);
+
+    assert!(client.get_invoices_by_merchant(&merchant_id).is_empty());
+    assert!(client.get_invoices_by_merchant(&99).is_empty());
+}
+
+#[test]
+fn test_get_invoices_by_merchant_includes_drafts_and_reissues() {
+    let (env, client, admin, token) = setup_test();
+    let (merchant, merchant_id) = register(&env, &client, 1);
+    let new_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &new_token);
+
+    let pending = create(&env, &client, &merchant, &token);
+    let draft = client.create_invoice_draft(
+        &merchant,
+        &String::from_str(&env, "Draft"),
+        &1000,
+        &token,
+        &None,
+    );
+    let (reissued, _) = client.reissue_merchant_invoices(&merchant, &token, &new_token, &0);
+
+    assert_eq!(
+        client.get_invoices_by_merchant(&merchant_id),
+        vec![&env, pending, draft, reissued.get(0).unwrap()]
+    );
+}
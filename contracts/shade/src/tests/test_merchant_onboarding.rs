@@ -0,0 +1,129 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::Role;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, admin)
+}
+
+#[test]
+fn test_registration_is_open_by_default() {
+    let (env, client, _admin) = setup_test();
+    assert!(client.is_open_registration());
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    assert!(client.is_merchant(&merchant));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_closed_registration_rejects_self_registration() {
+    let (env, client, admin) = setup_test();
+    client.set_open_registration(&admin, &false);
+    assert!(!client.is_open_registration());
+
+    client.register_merchant(&Address::generate(&env), &None, &None);
+}
+
+#[test]
+fn test_onboarder_registers_merchant_when_closed() {
+    let (env, client, admin) = setup_test();
+    client.set_open_registration(&admin, &false);
+
+    let onboarder = Address::generate(&env);
+    client.grant_role(&admin, &onboarder, &Role::Onboarder);
+
+    let merchant = Address::generate(&env);
+    client.onboard_merchant(&onboarder, &merchant);
+    assert!(client.is_merchant(&merchant));
+
+    let other_merchant = Address::generate(&env);
+    client.onboard_merchant(&admin, &other_merchant);
+    assert!(client.is_merchant(&other_merchant));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_revoked_onboarder_cannot_onboard() {
+    let (env, client, admin) = setup_test();
+    let onboarder = Address::generate(&env);
+    client.grant_role(&admin, &onboarder, &Role::Onboarder);
+    client.revoke_role(&admin, &onboarder, &Role::Onboarder);
+
+    client.onboard_merchant(&onboarder, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_open_registration_non_admin_fails() {
+    let (env, client, _admin) = setup_test();
+    client.set_open_registration(&Address::generate(&env), &false);
+}
+
+fn create_invoice_for(env: &Env, client: &ShadeClient, admin: &Address, merchant: &Address) -> u64 {
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.add_accepted_token(admin, &token);
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Applicant"),
+        &1000,
+        &token,
+        &None,
+    )
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #55)")]
+fn test_pending_applicant_cannot_create_invoice() {
+    let (env, client, admin) = setup_test();
+    client.set_open_registration(&admin, &false);
+
+    let applicant = Address::generate(&env);
+    client.apply_as_merchant(&applicant);
+    assert!(client.has_pending_application(&applicant));
+    assert!(!client.is_merchant(&applicant));
+
+    create_invoice_for(&env, &client, &admin, &applicant);
+}
+
+#[test]
+fn test_approved_applicant_can_create_invoice() {
+    let (env, client, admin) = setup_test();
+    client.set_open_registration(&admin, &false);
+    let onboarder = Address::generate(&env);
+    client.grant_role(&admin, &onboarder, &Role::Onboarder);
+
+    let applicant = Address::generate(&env);
+    client.apply_as_merchant(&applicant);
+    client.approve_merchant_application(&onboarder, &applicant);
+
+    assert!(!client.has_pending_application(&applicant));
+    assert!(client.is_merchant(&applicant));
+    assert_eq!(create_invoice_for(&env, &client, &admin, &applicant), 1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_unknown_address_cannot_create_invoice() {
+    let (env, client, admin) = setup_test();
+    create_invoice_for(&env, &client, &admin, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_approving_without_application_fails() {
+    let (env, client, admin) = setup_test();
+    client.approve_merchant_application(&admin, &Address::generate(&env));
+}
@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::MerchantState;
+use account::account::{MerchantAccount, MerchantAccountClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account_id = env.register(MerchantAccount, ());
+    MerchantAccountClient::new(&env, &merchant_account_id).initialize(
+        &merchant,
+        &contract_id,
+        &1_u64,
+    );
+    client.set_merchant_account(&merchant, &merchant_account_id);
+
+    (env, client, admin, merchant)
+}
+
+#[test]
+fn test_merchant_state_unknown_for_unregistered_address() {
+    let (env, client, _admin, _merchant) = setup_test();
+    assert_eq!(
+        client.get_merchant_state(&Address::generate(&env)),
+        MerchantState::Unknown
+    );
+}
+
+#[test]
+fn test_merchant_state_active_after_registration() {
+    let (_env, client, _admin, merchant) = setup_test();
+    assert_eq!(client.get_merchant_state(&merchant), MerchantState::Active);
+}
+
+#[test]
+fn test_merchant_state_inactive_after_deactivation() {
+    let (_env, client, admin, merchant) = setup_test();
+    client.set_merchant_status(&admin, &1, &false);
+    assert_eq!(
+        client.get_merchant_state(&merchant),
+        MerchantState::Inactive
+    );
+
+    client.set_merchant_status(&admin, &1, &true);
+    assert_eq!(client.get_merchant_state(&merchant), MerchantState::Active);
+}
+
+#[test]
+fn test_merchant_state_paused_when_account_restricted() {
+    let (_env, client, admin, merchant) = setup_test();
+    client.restrict_merchant_account(&admin, &merchant, &true);
+    assert_eq!(client.get_merchant_state(&merchant), MerchantState::Paused);
+
+    client.restrict_merchant_account(&admin, &merchant, &false);
+    assert_eq!(client.get_merchant_state(&merchant), MerchantState::Active);
+}
+
+#[test]
+fn test_merchant_state_closed_when_inactive_and_restricted() {
+    let (_env, client, admin, merchant) = setup_test();
+    client.set_merchant_status(&admin, &1, &false);
+    client.restrict_merchant_account(&admin, &merchant, &true);
+    assert_eq!(client.get_merchant_state(&merchant), MerchantState::Closed);
+}
+
+#[test]
+fn test_merchant_state_ignores_account_that_is_not_a_contract() {
+    let (env, client, _admin, _merchant) = setup_test();
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    assert_eq!(client.get_merchant_state(&merchant), MerchantState::Active);
+}
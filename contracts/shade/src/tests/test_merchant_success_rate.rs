@@ -0,0 +1,112 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use account::account::{MerchantAccount, MerchantAccountClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{token, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let shade_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &shade_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    client.set_fee(&admin, &token, &0);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account_id = env.register(MerchantAccount, ());
+    MerchantAccountClient::new(&env, &merchant_account_id).initialize(&merchant, &shade_id, &1_u64);
+    client.set_merchant_account(&merchant, &merchant_account_id);
+
+    (env, client, merchant, token)
+}
+
+fn create(env: &Env, client: &ShadeClient, merchant: &Address, token: &Address) -> u64 {
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Reputation"),
+        &1000,
+        token,
+        &Some(5_000),
+    )
+}
+
+fn pay(env: &Env, client: &ShadeClient, token: &Address, invoice_id: u64) {
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+}
+
+#[test]
+fn test_success_rate_is_zero_without_terminal_invoices() {
+    let (env, client, merchant, token) = setup_test();
+    assert_eq!(client.get_merchant_success_rate(&1), 0);
+
+    create(&env, &client, &merchant, &token);
+    assert_eq!(client.get_merchant_success_rate(&1), 0);
+}
+
+#[test]
+fn test_success_rate_all_paid() {
+    let (env, client, merchant, token) = setup_test();
+    let invoice_id = create(&env, &client, &merchant, &token);
+    pay(&env, &client, &token, invoice_id);
+
+    assert_eq!(client.get_merchant_success_rate(&1), 10_000);
+}
+
+#[test]
+fn test_success_rate_across_mixed_outcomes() {
+    let (env, client, merchant, token) = setup_test();
+
+    let paid = create(&env, &client, &merchant, &token);
+    pay(&env, &client, &token, paid);
+
+    let refunded = create(&env, &client, &merchant, &token);
+    pay(&env, &client, &token, refunded);
+    client.refund_invoice(&merchant, &refunded);
+
+    let cancelled = create(&env, &client, &merchant, &token);
+    client.void_invoice(&merchant, &cancelled);
+
+    // Still pending, so it does not count towards the rate.
+    let _open = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Open"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    // 1 paid out of paid + refunded + cancelled.
+    assert_eq!(client.get_merchant_success_rate(&1), 3_333);
+
+    let expired = create(&env, &client, &merchant, &token);
+    env.ledger().set_timestamp(6_000);
+    client.run_maintenance(&Address::generate(&env), &10);
+    assert_eq!(
+        client.get_invoice(&expired).status,
+        crate::types::InvoiceStatus::Expired
+    );
+
+    assert_eq!(client.get_merchant_success_rate(&1), 2_500);
+}
+
+#[test]
+fn test_partial_refund_still_counts_as_paid() {
+    let (env, client, merchant, token) = setup_test();
+    let invoice_id = create(&env, &client, &merchant, &token);
+    pay(&env, &client, &token, invoice_id);
+
+    client.refund_invoice_partial(&invoice_id, &100);
+
+    assert_eq!(client.get_merchant_success_rate(&1), 10_000);
+}
@@ -49,7 +49,7 @@ fn test_merchant_sets_and_gets_tokens() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token_admin = Address::generate(&env);
     let token1 = env
@@ -88,7 +88,7 @@ fn test_merchant_cannot_set_unaccepted_global_token() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = Address::generate(&env); // Not in global whitelist
 
@@ -113,7 +113,7 @@ fn test_invoice_creation_with_merchant_whitelist() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token_admin = Address::generate(&env);
     let token1 = env
@@ -165,7 +165,7 @@ fn test_empty_merchant_whitelist_defaults_to_global() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -214,7 +214,7 @@ fn test_duplicate_tokens_are_deduplicated() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -244,7 +244,7 @@ fn test_merchant_can_overwrite_whitelist() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token_admin = Address::generate(&env);
     let token1 = env
@@ -291,7 +291,7 @@ fn test_remove_merchant_accepted_token() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token_admin = Address::generate(&env);
     let token1 = env
@@ -331,7 +331,7 @@ fn test_remove_nonexistent_token_fails() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token_admin = Address::generate(&env);
     let token1 = env
@@ -368,7 +368,7 @@ fn test_inactive_merchant_cannot_set_tokens() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Deactivate the merchant
     client.set_merchant_status(&admin, &1, &false);
@@ -399,7 +399,7 @@ fn test_is_token_accepted_for_merchant_public() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token_admin = Address::generate(&env);
     let token1 = env
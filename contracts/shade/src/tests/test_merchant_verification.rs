@@ -55,7 +55,7 @@ fn test_successful_merchant_verification() {
     let (env, client, contract_id, admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let merchant_id = 1u64;
     let expected_timestamp = env.ledger().timestamp();
@@ -81,7 +81,7 @@ fn test_non_admin_cannot_verify_merchant() {
     let (env, client, _contract_id, _admin) = setup_test();
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let non_admin = Address::generate(&env);
     let expected_error =
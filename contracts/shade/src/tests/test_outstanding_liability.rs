@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{token, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    (env, client, admin, merchant, token)
+}
+
+fn create(
+    env: &Env,
+    client: &ShadeClient,
+    merchant: &Address,
+    token: &Address,
+    amount: i128,
+    expires_at: Option<u64>,
+) -> u64 {
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Owed"),
+        &amount,
+        token,
+        &expires_at,
+    )
+}
+
+fn funded_payer(env: &Env, token: &Address, amount: i128) -> Address {
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&payer, &amount);
+    payer
+}
+
+#[test]
+fn test_liability_tracks_creation_payment_and_cancellation() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    assert_eq!(client.get_outstanding_liability(&token), 0);
+
+    let paid = create(&env, &client, &merchant, &token, 1000, None);
+    let cancelled = create(&env, &client, &merchant, &token, 500, None);
+    assert_eq!(client.get_outstanding_liability(&token), 1500);
+
+    client.pay_invoice(&funded_payer(&env, &token, 1000), &paid);
+    assert_eq!(client.get_outstanding_liability(&token), 500);
+
+    client.void_invoice(&merchant, &cancelled);
+    assert_eq!(client.get_outstanding_liability(&token), 0);
+}
+
+#[test]
+fn test_partial_payments_reduce_liability_incrementally() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    let invoice_id = create(&env, &client, &merchant, &token, 1000, None);
+    let payer = funded_payer(&env, &token, 1000);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &300);
+    assert_eq!(client.get_outstanding_liability(&token), 700);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &200);
+    assert_eq!(client.get_outstanding_liability(&token), 500);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &500);
+    assert_eq!(client.get_outstanding_liability(&token), 0);
+}
+
+#[test]
+fn test_expiry_and_amendment_move_liability() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    create(&env, &client, &merchant, &token, 1000, Some(2_000));
+    let amended = create(&env, &client, &merchant, &token, 1000, None);
+
+    client.amend_invoice(&merchant, &amended, &Some(400), &None);
+    assert_eq!(client.get_outstanding_liability(&token), 1400);
+
+    env.ledger().set_timestamp(2_000);
+    client.run_maintenance(&Address::generate(&env), &10);
+    assert_eq!(client.get_outstanding_liability(&token), 400);
+}
+
+#[test]
+fn test_token_change_moves_liability_between_tokens() {
+    let (env, client, admin, merchant, token) = setup_test();
+    let other_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &other_token);
+    let invoice_id = create(&env, &client, &merchant, &token, 1000, None);
+
+    client.change_invoice_token(&merchant, &invoice_id, &other_token);
+
+    assert_eq!(client.get_outstanding_liability(&token), 0);
+    assert_eq!(client.get_outstanding_liability(&other_token), 1000);
+}
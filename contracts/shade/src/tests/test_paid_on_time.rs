@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{token, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    (env, client, admin, merchant, token)
+}
+
+fn create_and_pay(
+    env: &Env,
+    client: &ShadeClient,
+    merchant: &Address,
+    token: &Address,
+    due_date: Option<u64>,
+) -> u64 {
+    let invoice_id = client.create_invoice(
+        merchant,
+        &String::from_str(env, "Punctual"),
+        &1000,
+        token,
+        &due_date,
+    );
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+    invoice_id
+}
+
+#[test]
+fn test_paid_before_due_date_is_on_time() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    let invoice_id = create_and_pay(&env, &client, &merchant, &token, Some(5_000));
+
+    assert_eq!(client.was_paid_on_time(&invoice_id), Some(true));
+}
+
+#[test]
+fn test_paid_after_due_date_is_late() {
+    let (env, client, admin, merchant, token) = setup_test();
+    let invoice_id = create_and_pay(&env, &client, &merchant, &token, None);
+
+    // Payments are rejected once an invoice expires, so a late payment can only
+    // come from imported records.
+    let mut invoice = client.get_invoice(&invoice_id);
+    invoice.expires_at = Some(invoice.date_paid.unwrap() - 1);
+    client.import_invoice(&admin, &invoice.to_xdr(&env), &true);
+
+    assert_eq!(client.was_paid_on_time(&invoice_id), Some(false));
+}
+
+#[test]
+fn test_paid_after_due_date_before_expiry_is_late() {
+    let (env, client, admin, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Punctual"),
+        &1000,
+        &token,
+        &Some(50_000),
+    );
+
+    // Due well before it expires; settled after the due date has passed.
+    let mut invoice = client.get_invoice(&invoice_id);
+    invoice.due_date = Some(2_000);
+    client.import_invoice(&admin, &invoice.to_xdr(&env), &true);
+    env.ledger().set_timestamp(3_000);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    assert_eq!(client.was_paid_on_time(&invoice_id), Some(false));
+}
+
+#[test]
+fn test_late_fee_invoice_paid_after_due_date_is_late() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice_with_late_fee(
+        &merchant,
+        &String::from_str(&env, "Punctual"),
+        &1000,
+        &token,
+        &2_000,
+        &100,
+    );
+    env.ledger().set_timestamp(2_000 + 2 * 86_400);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &10_000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    assert_eq!(client.was_paid_on_time(&invoice_id), Some(false));
+}
+
+#[test]
+fn test_paid_without_due_date_is_none() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    let invoice_id = create_and_pay(&env, &client, &merchant, &token, None);
+
+    assert_eq!(client.was_paid_on_time(&invoice_id), None);
+}
+
+#[test]
+fn test_unpaid_invoice_is_none() {
+    let (env, client, _admin, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Punctual"),
+        &1000,
+        &token,
+        &Some(5_000),
+    );
+
+    assert_eq!(client.was_paid_on_time(&invoice_id), None);
+}
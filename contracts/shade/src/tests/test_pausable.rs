@@ -163,3 +163,122 @@ fn test_double_unpause_fails_with_contract_not_paused_error() {
     assert!(matches!(unpause_result, Err(Ok(err)) if err == expected_error));
     assert!(!client.is_paused());
 }
+
+fn setup_pending_invoice(
+    env: &Env,
+    client: &ShadeClient,
+    admin: &Address,
+) -> (u64, Address, Address) {
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.add_accepted_token(admin, &token);
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(env));
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &soroban_sdk::String::from_str(env, "Blocked"),
+        &1000,
+        &token,
+        &None,
+    );
+    let payer = Address::generate(env);
+    soroban_sdk::token::StellarAssetClient::new(env, &token).mint(&payer, &1000);
+    (invoice_id, token, payer)
+}
+
+#[test]
+fn test_blocked_payments_are_logged_while_paused() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let (invoice_id, token, payer) = setup_pending_invoice(&env, &client, &admin);
+
+    client.set_log_blocked_payments(&admin, &true);
+    client.pause(&admin);
+    assert!(!client.pay_invoice(&payer, &invoice_id));
+    assert!(!client.pay_invoice_partial(&payer, &invoice_id, &100));
+
+    let attempts = client.get_blocked_payment_attempts();
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(attempts.get(0).unwrap().payer, payer);
+    assert_eq!(attempts.get(0).unwrap().invoice_id, invoice_id);
+
+    // Nothing moved.
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        crate::types::InvoiceStatus::Pending
+    );
+    assert_eq!(
+        soroban_sdk::token::TokenClient::new(&env, &token).balance(&payer),
+        1000
+    );
+}
+
+#[test]
+fn test_blocked_batch_and_oldest_payments_are_logged() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let (invoice_id, token, payer) = setup_pending_invoice(&env, &client, &admin);
+    let merchant = client.get_merchant(&1).address;
+    client.assign_payer(&merchant, &invoice_id, &payer);
+
+    client.set_log_blocked_payments(&admin, &true);
+    client.pause(&admin);
+    assert!(!client.pay_invoices_batch(&payer, &soroban_sdk::vec![&env, invoice_id]));
+    assert_eq!(client.pay_oldest(&payer, &1, &1000, &token).len(), 0);
+
+    let attempts = client.get_blocked_payment_attempts();
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(attempts.get(0).unwrap().invoice_id, invoice_id);
+    assert_eq!(attempts.get(1).unwrap().invoice_id, invoice_id);
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        crate::types::InvoiceStatus::Pending
+    );
+}
+
+#[test]
+fn test_blocked_payment_log_is_bounded() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let (invoice_id, _token, payer) = setup_pending_invoice(&env, &client, &admin);
+
+    client.set_log_blocked_payments(&admin, &true);
+    client.pause(&admin);
+    for i in 0..pausable_component::MAX_BLOCKED_PAYMENTS + 5 {
+        client.pay_invoice_partial(&payer, &invoice_id, &(i as i128 + 1));
+    }
+
+    let attempts = client.get_blocked_payment_attempts();
+    assert_eq!(attempts.len(), pausable_component::MAX_BLOCKED_PAYMENTS);
+}
+
+#[test]
+fn test_paused_payment_fails_without_logging() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let (invoice_id, _token, payer) = setup_pending_invoice(&env, &client, &admin);
+
+    client.pause(&admin);
+
+    let result = client.try_pay_invoice(&payer, &invoice_id);
+    assert_eq!(
+        result.unwrap_err(),
+        Ok(soroban_sdk::Error::from_contract_error(
+            ContractError::ContractPaused as u32
+        ))
+    );
+    assert_eq!(client.get_blocked_payment_attempts().len(), 0);
+}
+
+#[test]
+fn test_logging_mode_does_not_affect_unpaused_payments() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let (invoice_id, _token, payer) = setup_pending_invoice(&env, &client, &admin);
+
+    client.set_log_blocked_payments(&admin, &true);
+    assert!(client.pay_invoice(&payer, &invoice_id));
+
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        crate::types::InvoiceStatus::Paid
+    );
+    assert_eq!(client.get_blocked_payment_attempts().len(), 0);
+}
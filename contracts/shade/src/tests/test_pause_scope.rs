@@ -0,0 +1,189 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceStatus, PauseScope};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, Env, String};
+
+struct Setup {
+    env: Env,
+    client: ShadeClient<'static>,
+    admin: Address,
+    token: Address,
+    merchant: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+        merchant,
+    }
+}
+
+fn create_invoice(s: &Setup) -> u64 {
+    s.client.create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Scoped"),
+        &1000,
+        &s.token,
+        &None,
+    )
+}
+
+fn pay(s: &Setup, invoice_id: u64) {
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&payer, &1000);
+    s.client.pay_invoice(&payer, &invoice_id);
+}
+
+#[test]
+fn test_payments_pause_leaves_invoicing_open() {
+    let s = setup_test();
+    let invoice_id = create_invoice(&s);
+    s.client.pause_scope(&s.admin, &PauseScope::Payments);
+
+    assert!(s.client.is_paused_scope(&PauseScope::Payments));
+    assert!(!s.client.is_paused_scope(&PauseScope::Invoicing));
+    assert!(!s.client.is_paused());
+
+    let second_id = create_invoice(&s);
+    s.client.cancel_invoice(&s.merchant, &second_id);
+    assert_eq!(
+        s.client.get_invoice(&second_id).status,
+        InvoiceStatus::Cancelled
+    );
+
+    let payer = Address::generate(&s.env);
+    let result = s.client.try_pay_invoice(&payer, &invoice_id);
+    assert!(result.is_err());
+    assert_eq!(
+        s.client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Pending
+    );
+}
+
+#[test]
+fn test_invoicing_pause_leaves_payments_open() {
+    let s = setup_test();
+    let invoice_id = create_invoice(&s);
+    s.client.pause_scope(&s.admin, &PauseScope::Invoicing);
+
+    let result = s.client.try_create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Blocked"),
+        &1000,
+        &s.token,
+        &None,
+    );
+    assert!(result.is_err());
+
+    pay(&s, invoice_id);
+    assert_eq!(
+        s.client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Paid
+    );
+}
+
+#[test]
+fn test_unpause_scope_reopens_payments() {
+    let s = setup_test();
+    let invoice_id = create_invoice(&s);
+    s.client.pause_scope(&s.admin, &PauseScope::Payments);
+    s.client.unpause_scope(&s.admin, &PauseScope::Payments);
+
+    assert!(!s.client.is_paused_scope(&PauseScope::Payments));
+    pay(&s, invoice_id);
+    assert_eq!(
+        s.client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Paid
+    );
+}
+
+#[test]
+fn test_all_scope_is_the_global_pause() {
+    let s = setup_test();
+    s.client.pause_scope(&s.admin, &PauseScope::All);
+
+    assert!(s.client.is_paused());
+    assert!(s.client.is_paused_scope(&PauseScope::Payments));
+    assert!(s.client.is_paused_scope(&PauseScope::Invoicing));
+
+    s.client.unpause_scope(&s.admin, &PauseScope::All);
+    assert!(!s.client.is_paused());
+    assert!(!s.client.is_paused_scope(&PauseScope::Payments));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+fn test_scoped_payment_fails_with_contract_paused() {
+    let s = setup_test();
+    let invoice_id = create_invoice(&s);
+    s.client.pause_scope(&s.admin, &PauseScope::Payments);
+    pay(&s, invoice_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+fn test_double_scope_pause_fails() {
+    let s = setup_test();
+    s.client.pause_scope(&s.admin, &PauseScope::Invoicing);
+    s.client.pause_scope(&s.admin, &PauseScope::Invoicing);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn test_unpause_unpaused_scope_fails() {
+    let s = setup_test();
+    s.client.unpause_scope(&s.admin, &PauseScope::Payments);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_non_admin_cannot_pause_scope() {
+    let s = setup_test();
+    s.client
+        .pause_scope(&Address::generate(&s.env), &PauseScope::Payments);
+}
+
+#[test]
+fn test_is_paused_scope_state_transitions_are_accurate() {
+    let s = setup_test();
+    assert!(!s.client.is_paused_scope(&PauseScope::Invoicing));
+
+    s.client.pause_scope(&s.admin, &PauseScope::Invoicing);
+    assert!(s.client.is_paused_scope(&PauseScope::Invoicing));
+
+    s.client.unpause_scope(&s.admin, &PauseScope::Invoicing);
+    assert!(!s.client.is_paused_scope(&PauseScope::Invoicing));
+}
+
+#[test]
+fn test_global_pause_reports_every_scope_paused() {
+    let s = setup_test();
+    s.client.pause(&s.admin);
+
+    assert!(s.client.is_paused_scope(&PauseScope::Payments));
+    assert!(s.client.is_paused_scope(&PauseScope::Invoicing));
+    assert!(s.client.is_paused_scope(&PauseScope::All));
+
+    s.client.unpause(&s.admin);
+    assert!(!s.client.is_paused_scope(&PauseScope::All));
+}
@@ -0,0 +1,142 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, vec, Address, Env, String};
+
+struct PayOldestSetup {
+    env: Env,
+    client: ShadeClient<'static>,
+    merchant: Address,
+    token: Address,
+    payer: Address,
+}
+
+fn setup_test() -> PayOldestSetup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &5_000);
+
+    PayOldestSetup {
+        env,
+        client,
+        merchant,
+        token,
+        payer,
+    }
+}
+
+fn create(s: &PayOldestSetup, amount: i128) -> u64 {
+    s.client.create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Owed"),
+        &amount,
+        &s.token,
+        &None,
+    )
+}
+
+// Three invoices of 300, 500 and 400, assigned out of creation order.
+fn assign_three(s: &PayOldestSetup) {
+    create(s, 300);
+    create(s, 500);
+    create(s, 400);
+    for invoice_id in [3u64, 1, 2] {
+        s.client.assign_payer(&s.merchant, &invoice_id, &s.payer);
+    }
+}
+
+#[test]
+fn test_pay_oldest_settles_everything_within_budget() {
+    let s = setup_test();
+    assign_three(&s);
+
+    let receipts = s.client.pay_oldest(&s.payer, &1, &1_200, &s.token);
+
+    assert_eq!(receipts.len(), 3);
+    let mut ids = soroban_sdk::Vec::new(&s.env);
+    for receipt in receipts.iter() {
+        ids.push_back(receipt.invoice_id);
+    }
+    assert_eq!(ids, vec![&s.env, 1u64, 2, 3]);
+    for receipt in receipts.iter() {
+        assert_eq!(receipt.status, InvoiceStatus::Paid);
+        assert!(receipt.receipt_hash.is_some());
+    }
+    assert!(s.client.get_payer_outstanding_invoices(&s.payer).is_empty());
+}
+
+#[test]
+fn test_pay_oldest_partially_pays_last_affordable_invoice() {
+    let s = setup_test();
+    assign_three(&s);
+
+    let receipts = s.client.pay_oldest(&s.payer, &1, &600, &s.token);
+
+    assert_eq!(receipts.len(), 2);
+    let first = receipts.get(0).unwrap();
+    assert_eq!((first.invoice_id, first.amount), (1, 300));
+    assert_eq!(first.status, InvoiceStatus::Paid);
+    let second = receipts.get(1).unwrap();
+    assert_eq!((second.invoice_id, second.amount), (2, 300));
+    assert_eq!(second.status, InvoiceStatus::PartiallyPaid);
+
+    assert_eq!(s.client.get_invoice(&2).amount_paid, 300);
+    assert_eq!(s.client.get_invoice(&3).status, InvoiceStatus::Pending);
+    assert_eq!(
+        token::TokenClient::new(&s.env, &s.token).balance(&s.payer),
+        4_400
+    );
+}
+
+#[test]
+fn test_pay_oldest_ignores_other_merchants_and_tokens() {
+    let s = setup_test();
+    create(&s, 300);
+    s.client.assign_payer(&s.merchant, &1, &s.payer);
+
+    let other_merchant = Address::generate(&s.env);
+    s.client.register_merchant(&other_merchant, &None, &None);
+    let other_invoice = s.client.create_invoice(
+        &other_merchant,
+        &String::from_str(&s.env, "Elsewhere"),
+        &200,
+        &s.token,
+        &None,
+    );
+    s.client
+        .assign_payer(&other_merchant, &other_invoice, &s.payer);
+
+    let receipts = s.client.pay_oldest(&s.payer, &1, &1_000, &s.token);
+
+    assert_eq!(receipts.len(), 1);
+    assert_eq!(receipts.get(0).unwrap().invoice_id, 1);
+    assert_eq!(
+        s.client.get_invoice(&other_invoice).status,
+        InvoiceStatus::Pending
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_pay_oldest_zero_budget_fails() {
+    let s = setup_test();
+    assign_three(&s);
+    s.client.pay_oldest(&s.payer, &1, &0, &s.token);
+}
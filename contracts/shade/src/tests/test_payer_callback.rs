@@ -0,0 +1,131 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, token, Address, Env, FromVal, String, Symbol,
+};
+
+#[contract]
+struct RecordingCallback;
+
+#[contractimpl]
+impl RecordingCallback {
+    pub fn on_payment_settled(env: Env, invoice_id: u64) {
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("settled"), &invoice_id);
+    }
+
+    pub fn last_settled(env: Env) -> Option<u64> {
+        env.storage().persistent().get(&symbol_short!("settled"))
+    }
+}
+
+#[contract]
+struct PanickingCallback;
+
+#[contractimpl]
+impl PanickingCallback {
+    pub fn on_payment_settled(_env: Env, _invoice_id: u64) {
+        panic!("downstream release failed");
+    }
+}
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address, u64) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Goods"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    (env, client, admin, merchant, token, invoice_id)
+}
+
+fn fund_payer(env: &Env, token: &Address) -> Address {
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&payer, &1000);
+    payer
+}
+
+#[test]
+fn test_callback_is_notified_on_settlement() {
+    let (env, client, _admin, merchant, token, invoice_id) = setup_test();
+    let callback_id = env.register(RecordingCallback, ());
+    let callback = RecordingCallbackClient::new(&env, &callback_id);
+    client.set_payer_callback(&merchant, &invoice_id, &Some(callback_id.clone()));
+    assert_eq!(
+        client.get_invoice(&invoice_id).payer_callback,
+        Some(callback_id)
+    );
+
+    let payer = fund_payer(&env, &token);
+    client.pay_invoice_partial(&payer, &invoice_id, &400);
+    assert_eq!(callback.last_settled(), None);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &600);
+    assert_eq!(callback.last_settled(), Some(invoice_id));
+}
+
+#[test]
+fn test_failing_callback_is_best_effort_by_default() {
+    let (env, client, _admin, merchant, token, invoice_id) = setup_test();
+    let callback_id = env.register(PanickingCallback, ());
+    client.set_payer_callback(&merchant, &invoice_id, &Some(callback_id));
+    assert!(!client.get_callback_revert_on_failure());
+
+    client.pay_invoice(&fund_payer(&env, &token), &invoice_id);
+    assert_eq!(client.get_invoice(&invoice_id).status, InvoiceStatus::Paid);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #49)")]
+fn test_failing_callback_reverts_payment_when_configured() {
+    let (env, client, admin, merchant, token, invoice_id) = setup_test();
+    let callback_id = env.register(PanickingCallback, ());
+    client.set_payer_callback(&merchant, &invoice_id, &Some(callback_id));
+    client.set_callback_revert_on_failure(&admin, &true);
+
+    client.pay_invoice(&fund_payer(&env, &token), &invoice_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_payer_callback_by_other_merchant_fails() {
+    let (env, client, _admin, _merchant, _token, invoice_id) = setup_test();
+    let other_merchant = Address::generate(&env);
+    client.register_merchant(&other_merchant, &None, &None);
+    client.set_payer_callback(&other_merchant, &invoice_id, &Some(Address::generate(&env)));
+}
+
+#[test]
+fn test_set_callback_revert_on_failure_emits_event() {
+    let (env, client, admin, _merchant, _token, _invoice_id) = setup_test();
+    client.set_callback_revert_on_failure(&admin, &true);
+
+    let events = env.events().all();
+    let (contract_id, topics, _) = events.last().unwrap();
+    assert_eq!(contract_id, client.address);
+    assert_eq!(
+        Symbol::from_val(&env, &topics.get(0).unwrap()),
+        Symbol::new(&env, "callback_revert_set_event")
+    );
+}
@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    (env, client, merchant, token)
+}
+
+#[test]
+fn test_create_invoice_with_payer_note() {
+    let (env, client, merchant, token) = setup_test();
+    let note = String::from_str(&env, "Include your order number");
+
+    let invoice_id = client.create_invoice_with_note(
+        &merchant,
+        &String::from_str(&env, "Order"),
+        &1000,
+        &token,
+        &None,
+        &Some(note.clone()),
+    );
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.payer_note, Some(note));
+    assert_eq!(invoice.description, String::from_str(&env, "Order"));
+}
+
+#[test]
+fn test_plain_invoice_has_no_payer_note() {
+    let (env, client, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Order"),
+        &1000,
+        &token,
+        &None,
+    );
+    assert_eq!(client.get_invoice(&invoice_id).payer_note, None);
+}
+
+#[test]
+fn test_set_payer_note_while_pending() {
+    let (env, client, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Order"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    let note = String::from_str(&env, "Pay before Friday");
+    client.set_payer_note(&merchant, &invoice_id, &Some(note.clone()));
+    assert_eq!(client.get_invoice(&invoice_id).payer_note, Some(note));
+
+    client.set_payer_note(&merchant, &invoice_id, &None);
+    assert_eq!(client.get_invoice(&invoice_id).payer_note, None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_set_payer_note_after_payment_fails() {
+    let (env, client, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Order"),
+        &1000,
+        &token,
+        &None,
+    );
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    client.set_payer_note(
+        &merchant,
+        &invoice_id,
+        &Some(String::from_str(&env, "Too late")),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #44)")]
+fn test_payer_note_too_long_fails() {
+    let (env, client, merchant, token) = setup_test();
+    let long_note = String::from_str(&env, &"x".repeat(257));
+
+    client.create_invoice_with_note(
+        &merchant,
+        &String::from_str(&env, "Order"),
+        &1000,
+        &token,
+        &None,
+        &Some(long_note),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_payer_note_by_other_merchant_fails() {
+    let (env, client, merchant, token) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Order"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    let other_merchant = Address::generate(&env);
+    client.register_merchant(&other_merchant, &None, &None);
+    client.set_payer_note(
+        &other_merchant,
+        &invoice_id,
+        &Some(String::from_str(&env, "Hijacked")),
+    );
+}
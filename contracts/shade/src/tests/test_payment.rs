@@ -30,6 +30,7 @@ fn setup_test_with_payment() -> (Env, ShadeClient<'static>, Address, Address, Ad
     (env, shade_client, shade_contract_id, admin, token.address())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn assert_latest_paid_event(
     env: &Env,
     contract_id: &Address,
@@ -76,11 +77,11 @@ fn assert_latest_paid_event(
 
 #[test]
 fn test_successful_payment_with_fee() {
-    let (env, shade_client, shade_contract_id, _admin, token) = setup_test_with_payment();
+    let (env, shade_client, shade_contract_id, admin, token) = setup_test_with_payment();
 
     // Register merchant
     let merchant = Address::generate(&env);
-    shade_client.register_merchant(&merchant);
+    shade_client.register_merchant(&merchant, &None, &None);
 
     // Create merchant account (using a regular address as mock)
     let merchant_account = Address::generate(&env);
@@ -113,10 +114,10 @@ fn test_successful_payment_with_fee() {
 
     // Verify balances
     let token_balance_client = token::TokenClient::new(&env, &token);
-    let shade_balance = token_balance_client.balance(&shade_contract_id);
+    let admin_balance = token_balance_client.balance(&admin);
     let merchant_balance = token_balance_client.balance(&merchant_account);
 
-    assert_eq!(shade_balance, 50); // 5% fee = 50 units
+    assert_eq!(admin_balance, 50); // 5% fee = 50 units, to the admin without a treasury
     assert_eq!(merchant_balance, 950); // 95% = 950 units
 
     // Verify invoice status
@@ -135,7 +136,7 @@ fn test_payment_with_zero_fee() {
 
     // Register merchant
     let merchant = Address::generate(&env);
-    shade_client.register_merchant(&merchant);
+    shade_client.register_merchant(&merchant, &None, &None);
 
     // Create merchant account
     let merchant_account = Address::generate(&env);
@@ -172,7 +173,7 @@ fn test_payment_with_zero_fee() {
 
 //     // Register merchant
 //     let merchant = Address::generate(&env);
-//     shade_client.register_merchant(&merchant);
+//     shade_client.register_merchant(&merchant, &None, &None);
 
 //     // Create merchant account
 //     let merchant_account = Address::generate(&env);
@@ -205,7 +206,7 @@ fn test_payment_rejects_expired_invoice() {
     let (env, shade_client, _shade_contract_id, _admin, token) = setup_test_with_payment();
 
     let merchant = Address::generate(&env);
-    shade_client.register_merchant(&merchant);
+    shade_client.register_merchant(&merchant, &None, &None);
 
     let merchant_account = Address::generate(&env);
     shade_client.set_merchant_account(&merchant, &merchant_account);
@@ -230,7 +231,7 @@ fn test_payment_invoice_already_paid() {
 
     // Register merchant
     let merchant = Address::generate(&env);
-    shade_client.register_merchant(&merchant);
+    shade_client.register_merchant(&merchant, &None, &None);
 
     // Create merchant account
     let merchant_account = Address::generate(&env);
@@ -252,6 +253,43 @@ fn test_payment_invoice_already_paid() {
     shade_client.pay_invoice(&customer, &invoice_id);
 }
 
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_payment_cancelled_invoice() {
+    let (env, shade_client, _shade_contract_id, _admin, token) = setup_test_with_payment();
+
+    let merchant = Address::generate(&env);
+    shade_client.register_merchant(&merchant, &None, &None);
+    shade_client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    let description = String::from_str(&env, "Test Invoice");
+    let invoice_id = shade_client.create_invoice(&merchant, &description, &1000, &token, &None);
+    shade_client.void_invoice(&merchant, &invoice_id);
+
+    let customer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&customer, &1000);
+    shade_client.pay_invoice(&customer, &invoice_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+fn test_payment_while_paused() {
+    let (env, shade_client, _shade_contract_id, admin, token) = setup_test_with_payment();
+
+    let merchant = Address::generate(&env);
+    shade_client.register_merchant(&merchant, &None, &None);
+    shade_client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    let description = String::from_str(&env, "Test Invoice");
+    let invoice_id = shade_client.create_invoice(&merchant, &description, &1000, &token, &None);
+
+    let customer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&customer, &1000);
+
+    shade_client.pause(&admin);
+    shade_client.pay_invoice(&customer, &invoice_id);
+}
+
 #[test]
 #[should_panic]
 fn test_payment_insufficient_funds() {
@@ -259,7 +297,7 @@ fn test_payment_insufficient_funds() {
 
     // Register merchant
     let merchant = Address::generate(&env);
-    shade_client.register_merchant(&merchant);
+    shade_client.register_merchant(&merchant, &None, &None);
 
     // Create merchant account
     let merchant_account = Address::generate(&env);
@@ -285,7 +323,7 @@ fn test_payment_token_not_accepted() {
 
     // Register merchant
     let merchant = Address::generate(&env);
-    shade_client.register_merchant(&merchant);
+    shade_client.register_merchant(&merchant, &None, &None);
 
     // Create merchant account
     let merchant_account = Address::generate(&env);
@@ -320,7 +358,7 @@ fn test_payment_merchant_account_not_set() {
 
     // Register merchant
     let merchant = Address::generate(&env);
-    shade_client.register_merchant(&merchant);
+    shade_client.register_merchant(&merchant, &None, &None);
 
     // DO NOT set merchant account - this will cause the panic
 
@@ -343,7 +381,7 @@ fn test_payment_payer_authorization() {
 
     // Register merchant
     let merchant = Address::generate(&env);
-    shade_client.register_merchant(&merchant);
+    shade_client.register_merchant(&merchant, &None, &None);
 
     // Create merchant account
     let merchant_account = Address::generate(&env);
@@ -372,7 +410,7 @@ fn test_payment_updates_invoice_timestamps() {
 
     // Register merchant
     let merchant = Address::generate(&env);
-    shade_client.register_merchant(&merchant);
+    shade_client.register_merchant(&merchant, &None, &None);
 
     // Create merchant account
     let merchant_account = Address::generate(&env);
@@ -402,14 +440,14 @@ fn test_payment_updates_invoice_timestamps() {
 
 #[test]
 fn test_fee_calculation_accuracy() {
-    let (env, shade_client, shade_contract_id, admin, token) = setup_test_with_payment();
+    let (env, shade_client, _shade_contract_id, admin, token) = setup_test_with_payment();
 
     // Test with 1% fee (100 bps)
     shade_client.set_fee(&admin, &token, &100);
 
     // Register merchant
     let merchant = Address::generate(&env);
-    shade_client.register_merchant(&merchant);
+    shade_client.register_merchant(&merchant, &None, &None);
 
     // Create merchant account
     let merchant_account = Address::generate(&env);
@@ -429,19 +467,19 @@ fn test_fee_calculation_accuracy() {
 
     // Verify balances with 1% fee
     let token_balance_client = token::TokenClient::new(&env, &token);
-    let shade_balance = token_balance_client.balance(&shade_contract_id);
+    let admin_balance = token_balance_client.balance(&admin);
     let merchant_balance = token_balance_client.balance(&merchant_account);
 
-    assert_eq!(shade_balance, 100); // 1% of 10000 = 100
+    assert_eq!(admin_balance, 100); // 1% of 10000 = 100
     assert_eq!(merchant_balance, 9900); // 99% of 10000 = 9900
 }
 
 #[test]
 fn test_partial_payment_two_equal_steps_reaches_paid() {
-    let (env, shade_client, shade_contract_id, _admin, token) = setup_test_with_payment();
+    let (env, shade_client, _shade_contract_id, admin, token) = setup_test_with_payment();
 
     let merchant = Address::generate(&env);
-    shade_client.register_merchant(&merchant);
+    shade_client.register_merchant(&merchant, &None, &None);
     let merchant_account = Address::generate(&env);
     shade_client.set_merchant_account(&merchant, &merchant_account);
 
@@ -465,19 +503,19 @@ fn test_partial_payment_two_equal_steps_reaches_paid() {
     assert!(final_invoice.date_paid.is_some());
 
     let token_balance_client = token::TokenClient::new(&env, &token);
-    let shade_balance = token_balance_client.balance(&shade_contract_id);
+    let admin_balance = token_balance_client.balance(&admin);
     let merchant_balance = token_balance_client.balance(&merchant_account);
 
-    assert_eq!(shade_balance, 50);
+    assert_eq!(admin_balance, 50);
     assert_eq!(merchant_balance, 950);
 }
 
 #[test]
 fn test_partial_payment_collects_fees_proportionally_each_step() {
-    let (env, shade_client, shade_contract_id, _admin, token) = setup_test_with_payment();
+    let (env, shade_client, _shade_contract_id, admin, token) = setup_test_with_payment();
 
     let merchant = Address::generate(&env);
-    shade_client.register_merchant(&merchant);
+    shade_client.register_merchant(&merchant, &None, &None);
     let merchant_account = Address::generate(&env);
     shade_client.set_merchant_account(&merchant, &merchant_account);
 
@@ -490,11 +528,11 @@ fn test_partial_payment_collects_fees_proportionally_each_step() {
 
     shade_client.pay_invoice_partial(&customer, &invoice_id, &500);
     let token_balance_client = token::TokenClient::new(&env, &token);
-    assert_eq!(token_balance_client.balance(&shade_contract_id), 25);
+    assert_eq!(token_balance_client.balance(&admin), 25);
     assert_eq!(token_balance_client.balance(&merchant_account), 475);
 
     shade_client.pay_invoice_partial(&customer, &invoice_id, &500);
-    assert_eq!(token_balance_client.balance(&shade_contract_id), 50);
+    assert_eq!(token_balance_client.balance(&admin), 50);
     assert_eq!(token_balance_client.balance(&merchant_account), 950);
 }
 
@@ -504,7 +542,7 @@ fn test_partial_payment_cannot_exceed_requested_amount() {
     let (env, shade_client, _shade_contract_id, _admin, token) = setup_test_with_payment();
 
     let merchant = Address::generate(&env);
-    shade_client.register_merchant(&merchant);
+    shade_client.register_merchant(&merchant, &None, &None);
     let merchant_account = Address::generate(&env);
     shade_client.set_merchant_account(&merchant, &merchant_account);
 
@@ -518,3 +556,32 @@ fn test_partial_payment_cannot_exceed_requested_amount() {
     shade_client.pay_invoice_partial(&customer, &invoice_id, &700);
     shade_client.pay_invoice_partial(&customer, &invoice_id, &400);
 }
+
+#[test]
+fn test_partial_payment_rejects_single_overpayment_and_non_positive_amounts() {
+    let (env, shade_client, _shade_contract_id, _admin, token) = setup_test_with_payment();
+
+    let merchant = Address::generate(&env);
+    shade_client.register_merchant(&merchant, &None, &None);
+    shade_client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    let description = String::from_str(&env, "Installment Invoice");
+    let invoice_id = shade_client.create_invoice(&merchant, &description, &1000, &token, &None);
+
+    let customer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&customer, &2000);
+
+    assert!(shade_client
+        .try_pay_invoice_partial(&customer, &invoice_id, &1001)
+        .is_err());
+    assert!(shade_client
+        .try_pay_invoice_partial(&customer, &invoice_id, &0)
+        .is_err());
+    assert!(shade_client
+        .try_pay_invoice_partial(&customer, &invoice_id, &-5)
+        .is_err());
+
+    let invoice = shade_client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Pending);
+    assert_eq!(invoice.amount_paid, 0);
+}
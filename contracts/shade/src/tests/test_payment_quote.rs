@@ -0,0 +1,169 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::PaymentQuote;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{map, token, Address, Env, String};
+
+struct QuoteSetup {
+    env: Env,
+    client: ShadeClient<'static>,
+    admin: Address,
+    merchant: Address,
+    merchant_account: Address,
+    usdc: Address,
+    eurc: Address,
+    invoice_id: u64,
+}
+
+fn setup_test() -> QuoteSetup {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let usdc = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let eurc = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &usdc);
+    client.add_accepted_token(&admin, &eurc);
+    // 5% base fee on both tokens, 1% extra for conversions
+    client.set_fee(&admin, &usdc, &500);
+    client.set_fee(&admin, &eurc, &500);
+    client.set_conversion_fee_bps(&admin, &100);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account = Address::generate(&env);
+    client.set_merchant_account(&merchant, &merchant_account);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Checkout"),
+        &1000,
+        &usdc,
+        &None,
+    );
+
+    QuoteSetup {
+        env,
+        client,
+        admin,
+        merchant,
+        merchant_account,
+        usdc,
+        eurc,
+        invoice_id,
+    }
+}
+
+fn fund(s: &QuoteSetup, token: &Address, amount: i128) -> Address {
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, token).mint(&payer, &amount);
+    payer
+}
+
+#[test]
+fn test_same_token_quote_matches_charges() {
+    let s = setup_test();
+    let payer = fund(&s, &s.usdc, 1000);
+
+    let quote = s.client.quote_payment(&s.invoice_id, &s.usdc, &payer);
+    assert_eq!(
+        quote,
+        PaymentQuote {
+            principal: 1000,
+            base_fee: 50,
+            conversion_fee: 0,
+            total: 1000,
+            pay_token: s.usdc.clone(),
+        }
+    );
+
+    s.client.pay_invoice(&payer, &s.invoice_id);
+    let usdc_client = token::TokenClient::new(&s.env, &s.usdc);
+    assert_eq!(usdc_client.balance(&payer), 1000 - quote.total);
+    assert_eq!(
+        usdc_client.balance(&s.merchant_account),
+        quote.principal - quote.base_fee - quote.conversion_fee
+    );
+    assert_eq!(s.client.get_invoice(&s.invoice_id).fee_paid, quote.base_fee);
+}
+
+#[test]
+fn test_cross_token_quote_matches_charges() {
+    let s = setup_test();
+    s.client.set_invoice_quotes(
+        &s.merchant,
+        &s.invoice_id,
+        &map![&s.env, (s.eurc.clone(), 920i128)],
+        &2_000,
+    );
+    let payer = fund(&s, &s.eurc, 920);
+
+    let quote = s.client.quote_payment(&s.invoice_id, &s.eurc, &payer);
+    assert_eq!(quote.principal, 920);
+    assert_eq!(quote.base_fee, 46);
+    // 1% of 920 is 9.2; the fee pool keeps the dust by default
+    assert_eq!(quote.conversion_fee, 10);
+    assert_eq!(quote.total, 920);
+
+    s.client
+        .pay_invoice_with_quote(&payer, &s.invoice_id, &s.eurc);
+    let eurc_client = token::TokenClient::new(&s.env, &s.eurc);
+    assert_eq!(eurc_client.balance(&payer), 920 - quote.total);
+    assert_eq!(eurc_client.balance(&s.merchant_account), 864);
+    assert_eq!(
+        s.client.get_invoice(&s.invoice_id).fee_paid,
+        quote.base_fee + quote.conversion_fee
+    );
+}
+
+#[test]
+fn test_quote_for_exempt_payer_has_no_fees() {
+    let s = setup_test();
+    s.client.set_invoice_quotes(
+        &s.merchant,
+        &s.invoice_id,
+        &map![&s.env, (s.eurc.clone(), 920i128)],
+        &2_000,
+    );
+    let payer = fund(&s, &s.eurc, 920);
+    s.client.add_fee_exempt_payer(&s.admin, &payer);
+
+    let quote = s.client.quote_payment(&s.invoice_id, &s.eurc, &payer);
+    assert_eq!(quote.base_fee, 0);
+    assert_eq!(quote.conversion_fee, 0);
+
+    s.client
+        .pay_invoice_with_quote(&payer, &s.invoice_id, &s.eurc);
+    assert_eq!(
+        token::TokenClient::new(&s.env, &s.eurc).balance(&s.merchant_account),
+        920
+    );
+}
+
+#[test]
+fn test_quote_covers_remaining_balance() {
+    let s = setup_test();
+    let payer = fund(&s, &s.usdc, 1000);
+    s.client.pay_invoice_partial(&payer, &s.invoice_id, &400);
+
+    let quote = s.client.quote_payment(&s.invoice_id, &s.usdc, &payer);
+    assert_eq!(quote.principal, 600);
+    assert_eq!(quote.base_fee, 30);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+fn test_quote_for_unquoted_token_fails() {
+    let s = setup_test();
+    let payer = Address::generate(&s.env);
+    s.client.quote_payment(&s.invoice_id, &s.eurc, &payer);
+}
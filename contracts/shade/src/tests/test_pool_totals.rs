@@ -0,0 +1,245 @@
+#![cfg(test)]
+
+use crate::components::escrow::DEFAULT_RECEIPT_TIMEOUT;
+use crate::errors::ExtendedError;
+use crate::shade::{Shade, ShadeClient};
+use account::account::{MerchantAccount, MerchantAccountClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{token, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    // 5% fee, accrued in the contract's own fee pool
+    client.set_fee(&admin, &token, &500);
+    client.set_fee_accrual(&admin, &true);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account_id = env.register(MerchantAccount, ());
+    MerchantAccountClient::new(&env, &merchant_account_id).initialize(
+        &merchant,
+        &contract_id,
+        &1_u64,
+    );
+    client.set_merchant_account(&merchant, &merchant_account_id);
+
+    (env, client, admin, token, merchant)
+}
+
+fn create_and_pay(
+    env: &Env,
+    client: &ShadeClient,
+    merchant: &Address,
+    token: &Address,
+    amount: i128,
+) -> u64 {
+    let invoice_id = client.create_invoice(
+        merchant,
+        &String::from_str(env, "Pool"),
+        &amount,
+        token,
+        &None,
+    );
+    let customer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&customer, &amount);
+    client.pay_invoice(&customer, &invoice_id);
+    invoice_id
+}
+
+// Pays into escrow: the invoice stays `Funded` until the payer confirms receipt.
+fn create_and_fund(
+    env: &Env,
+    client: &ShadeClient,
+    merchant: &Address,
+    token: &Address,
+    amount: i128,
+) -> (u64, Address) {
+    let invoice_id = client.create_invoice(
+        merchant,
+        &String::from_str(env, "Escrow"),
+        &amount,
+        token,
+        &None,
+    );
+    client.set_requires_confirmation(merchant, &invoice_id, &true);
+    let customer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&customer, &amount);
+    client.pay_invoice(&customer, &invoice_id);
+    (invoice_id, customer)
+}
+
+fn assert_reconciles(client: &ShadeClient, token: &Address) {
+    let (total_escrow, fee_pool) = client.get_pool_totals(token);
+    assert!(total_escrow + fee_pool <= client.get_contract_balance(token));
+}
+
+#[test]
+fn test_pool_totals_start_empty() {
+    let (_env, client, _admin, token, _merchant) = setup_test();
+    assert_eq!(client.get_pool_totals(&token), (0, 0));
+    assert_eq!(client.get_contract_balance(&token), 0);
+}
+
+#[test]
+fn test_payments_accumulate_fee_pool() {
+    let (env, client, _admin, token, merchant) = setup_test();
+
+    create_and_pay(&env, &client, &merchant, &token, 1000);
+    assert_eq!(client.get_pool_totals(&token), (0, 50));
+
+    create_and_pay(&env, &client, &merchant, &token, 2000);
+    assert_eq!(client.get_pool_totals(&token), (0, 150));
+    assert_eq!(client.get_contract_balance(&token), 150);
+    assert_reconciles(&client, &token);
+}
+
+#[test]
+fn test_funded_escrow_counts_until_confirmed() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    let (invoice_id, customer) = create_and_fund(&env, &client, &merchant, &token, 1000);
+    assert_eq!(client.get_pool_totals(&token), (1000, 0));
+    assert_eq!(client.get_contract_balance(&token), 1000);
+    assert_reconciles(&client, &token);
+
+    client.confirm_receipt(&customer, &invoice_id);
+
+    assert_eq!(client.get_pool_totals(&token), (0, 50));
+    assert_reconciles(&client, &token);
+}
+
+#[test]
+fn test_claimed_escrow_leaves_total() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    let (invoice_id, _customer) = create_and_fund(&env, &client, &merchant, &token, 1000);
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + DEFAULT_RECEIPT_TIMEOUT);
+    client.claim_after_timeout(&merchant, &invoice_id);
+
+    assert_eq!(client.get_pool_totals(&token), (0, 50));
+}
+
+#[test]
+fn test_disputed_escrow_leaves_total_on_resolution() {
+    let (env, client, admin, token, merchant) = setup_test();
+    let (refunded_id, refunded_payer) = create_and_fund(&env, &client, &merchant, &token, 1000);
+    let (released_id, released_payer) = create_and_fund(&env, &client, &merchant, &token, 2000);
+    client.dispute_invoice(&refunded_payer, &refunded_id);
+    client.dispute_invoice(&released_payer, &released_id);
+    assert_eq!(client.get_pool_totals(&token), (3000, 0));
+
+    client.resolve_dispute(&admin, &refunded_id, &false);
+    assert_eq!(client.get_pool_totals(&token), (2000, 0));
+
+    client.resolve_dispute(&admin, &released_id, &true);
+    assert_eq!(client.get_pool_totals(&token), (0, 100));
+    assert_reconciles(&client, &token);
+}
+
+#[test]
+fn test_refunds_leave_fee_pool_unchanged() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    let invoice_id = create_and_pay(&env, &client, &merchant, &token, 1000);
+
+    client.refund_invoice_partial(&invoice_id, &300);
+
+    assert_eq!(client.get_pool_totals(&token), (0, 50));
+    assert_reconciles(&client, &token);
+}
+
+#[test]
+fn test_emergency_withdraw_drains_fee_pool() {
+    let (env, client, admin, token, merchant) = setup_test();
+    create_and_pay(&env, &client, &merchant, &token, 1000);
+
+    client.pause(&admin);
+    client.emergency_withdraw(&admin, &token, &Address::generate(&env));
+
+    assert_eq!(client.get_pool_totals(&token), (0, 0));
+    assert_eq!(client.get_contract_balance(&token), 0);
+}
+
+#[test]
+fn test_direct_transfers_do_not_inflate_fee_pool() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    create_and_pay(&env, &client, &merchant, &token, 1000);
+
+    token::StellarAssetClient::new(&env, &token).mint(&client.address, &500);
+
+    assert_eq!(client.get_pool_totals(&token), (0, 50));
+    assert_eq!(client.get_contract_balance(&token), 550);
+    assert_reconciles(&client, &token);
+}
+
+#[test]
+fn test_withdraw_fees_sweeps_accrued_fees() {
+    let (env, client, admin, token, merchant) = setup_test();
+    create_and_pay(&env, &client, &merchant, &token, 1000);
+    create_and_pay(&env, &client, &merchant, &token, 2000);
+    let recipient = Address::generate(&env);
+
+    let withdrawn = client.withdraw_fees(&admin, &token, &recipient);
+
+    assert_eq!(withdrawn, 150);
+    assert_eq!(
+        token::TokenClient::new(&env, &token).balance(&recipient),
+        150
+    );
+    assert_eq!(client.get_pool_totals(&token), (0, 0));
+    assert_eq!(client.get_contract_balance(&token), 0);
+}
+
+#[test]
+fn test_withdraw_fees_leaves_direct_transfers_behind() {
+    let (env, client, admin, token, merchant) = setup_test();
+    create_and_pay(&env, &client, &merchant, &token, 1000);
+    token::StellarAssetClient::new(&env, &token).mint(&client.address, &500);
+
+    client.withdraw_fees(&admin, &token, &Address::generate(&env));
+
+    assert_eq!(client.get_contract_balance(&token), 500);
+    assert_reconciles(&client, &token);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #60)")]
+fn test_withdraw_fees_with_nothing_accrued_fails() {
+    let (env, client, admin, token, merchant) = setup_test();
+    create_and_pay(&env, &client, &merchant, &token, 1000);
+    client.withdraw_fees(&admin, &token, &Address::generate(&env));
+
+    client.withdraw_fees(&admin, &token, &Address::generate(&env));
+}
+
+#[test]
+fn test_withdraw_fees_from_empty_pool_returns_nothing_to_withdraw() {
+    let (env, client, admin, token, _merchant) = setup_test();
+
+    let result = client.try_withdraw_fees(&admin, &token, &Address::generate(&env));
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            ExtendedError::NothingToWithdraw as u32
+        )))
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_withdraw_fees_requires_admin() {
+    let (env, client, _admin, token, merchant) = setup_test();
+    create_and_pay(&env, &client, &merchant, &token, 1000);
+
+    let stranger = Address::generate(&env);
+    client.withdraw_fees(&stranger, &token, &stranger);
+}
@@ -0,0 +1,130 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::shade::{Shade, ShadeClient};
+use crate::types::DustPolicy;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, vec, Address, Env, String};
+
+const MERCHANT_ID: u64 = 1;
+
+struct Setup {
+    env: Env,
+    client: ShadeClient<'static>,
+    admin: Address,
+    token: Address,
+    merchant: Address,
+    merchant_account: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    // 2.5% fee
+    client.set_fee(&admin, &token, &250);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account = Address::generate(&env);
+    client.set_merchant_account(&merchant, &merchant_account);
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+        merchant,
+        merchant_account,
+    }
+}
+
+// Settles a fresh invoice of `amount` and returns what the merchant received.
+fn settle(s: &Setup, amount: i128) -> i128 {
+    let token_client = token::TokenClient::new(&s.env, &s.token);
+    let before = token_client.balance(&s.merchant_account);
+    let invoice_id = s.client.create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Preview"),
+        &amount,
+        &s.token,
+        &None,
+    );
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&payer, &amount);
+    s.client.pay_invoice(&payer, &invoice_id);
+    token_client.balance(&s.merchant_account) - before
+}
+
+fn assert_preview_matches_settlement(s: &Setup, amount: i128) {
+    let preview = s.client.preview_net(&MERCHANT_ID, &amount, &s.token);
+    assert_eq!(preview, settle(s, amount));
+}
+
+#[test]
+fn test_preview_net_matches_settlement() {
+    let s = setup_test();
+    assert_eq!(s.client.preview_net(&MERCHANT_ID, &1_000, &s.token), 975);
+    assert_preview_matches_settlement(&s, 1_000);
+    assert_preview_matches_settlement(&s, 1_001);
+}
+
+#[test]
+fn test_preview_net_matches_settlement_with_fee_tiers() {
+    let s = setup_test();
+    s.client
+        .set_fee_tiers(&s.admin, &s.token, &vec![&s.env, (10_000, 100)]);
+
+    assert_preview_matches_settlement(&s, 5_000);
+    assert_preview_matches_settlement(&s, 20_000);
+}
+
+#[test]
+fn test_preview_net_matches_settlement_under_each_dust_policy() {
+    let s = setup_test();
+    for policy in [
+        DustPolicy::ToMerchant,
+        DustPolicy::ToFeePool,
+        DustPolicy::ToPayer,
+    ] {
+        s.client.set_dust_policy(&s.admin, &policy);
+        assert_preview_matches_settlement(&s, 1_003);
+    }
+}
+
+#[test]
+fn test_preview_net_accounts_for_holdback() {
+    let s = setup_test();
+    s.client
+        .set_merchant_holdback_bps(&s.admin, &MERCHANT_ID, &1_000);
+
+    assert_eq!(s.client.preview_net(&MERCHANT_ID, &1_000, &s.token), 878);
+    assert_preview_matches_settlement(&s, 1_000);
+}
+
+#[test]
+fn test_preview_net_unknown_merchant_fails() {
+    let s = setup_test();
+    let result = s.client.try_preview_net(&99, &1_000, &s.token);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            ContractError::MerchantNotFound as u32
+        )))
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_preview_net_non_positive_amount_fails() {
+    let s = setup_test();
+    s.client.preview_net(&MERCHANT_ID, &0, &s.token);
+}
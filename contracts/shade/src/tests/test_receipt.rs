@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::DataKey;
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{token, Address, BytesN, Env, Map, String, Symbol, TryIntoVal, Val};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    // 5% fee
+    client.set_fee(&admin, &token, &500);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    (env, client, contract_id, merchant, token)
+}
+
+fn create_invoice(env: &Env, client: &ShadeClient, merchant: &Address, token: &Address) -> u64 {
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Receipt"),
+        &1000,
+        token,
+        &None,
+    )
+}
+
+fn fund(env: &Env, token: &Address, payer: &Address) {
+    token::StellarAssetClient::new(env, token).mint(payer, &1000);
+}
+
+#[test]
+fn test_settlement_stores_receipt_hash() {
+    let (env, client, _contract_id, merchant, token) = setup_test();
+    let invoice_id = create_invoice(&env, &client, &merchant, &token);
+    let payer = Address::generate(&env);
+    fund(&env, &token, &payer);
+
+    env.ledger().set_timestamp(7_000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    let terms = (invoice_id, payer, 1000i128, 50i128, token, 7_000u64);
+    let expected: BytesN<32> = env.crypto().sha256(&terms.to_xdr(&env)).into();
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.fee_paid, 50);
+    assert_eq!(invoice.receipt_hash, Some(expected.clone()));
+    assert!(client.verify_receipt(&invoice_id, &expected));
+    assert!(!client.verify_receipt(&invoice_id, &BytesN::from_array(&env, &[0; 32])));
+}
+
+#[test]
+fn test_payment_event_carries_receipt_hash() {
+    let (env, client, contract_id, merchant, token) = setup_test();
+    let invoice_id = create_invoice(&env, &client, &merchant, &token);
+    let payer = Address::generate(&env);
+    fund(&env, &token, &payer);
+
+    client.pay_invoice(&payer, &invoice_id);
+
+    let events = env.events().all();
+    let mut receipt_in_event: Option<BytesN<32>> = None;
+    for (event_contract_id, topics, data) in events.iter() {
+        if event_contract_id != contract_id || topics.len() != 1 {
+            continue;
+        }
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+        if name == Symbol::new(&env, "invoice_paid_event") {
+            let data_map: Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+            receipt_in_event = data_map
+                .get(Symbol::new(&env, "receipt_hash"))
+                .unwrap()
+                .try_into_val(&env)
+                .unwrap();
+        }
+    }
+
+    assert!(receipt_in_event.is_some());
+    assert_eq!(
+        client.get_invoice(&invoice_id).receipt_hash,
+        receipt_in_event
+    );
+}
+
+#[test]
+fn test_partially_paid_invoice_has_no_receipt() {
+    let (env, client, _contract_id, merchant, token) = setup_test();
+    let invoice_id = create_invoice(&env, &client, &merchant, &token);
+    let payer = Address::generate(&env);
+    fund(&env, &token, &payer);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &400);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.receipt_hash, None);
+    assert!(!client.verify_receipt(&invoice_id, &BytesN::from_array(&env, &[0; 32])));
+
+    client.pay_invoice(&payer, &invoice_id);
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.fee_paid, 50);
+    assert!(client.verify_receipt(&invoice_id, &invoice.receipt_hash.unwrap()));
+}
+
+#[test]
+fn test_tampered_invoice_fails_verification() {
+    let (env, client, contract_id, merchant, token) = setup_test();
+    let invoice_id = create_invoice(&env, &client, &merchant, &token);
+    let payer = Address::generate(&env);
+    fund(&env, &token, &payer);
+    client.pay_invoice(&payer, &invoice_id);
+
+    let mut invoice = client.get_invoice(&invoice_id);
+    let receipt = invoice.receipt_hash.clone().unwrap();
+    invoice.amount = 1;
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Invoice(invoice_id), &invoice);
+    });
+
+    assert!(!client.verify_receipt(&invoice_id, &receipt));
+}
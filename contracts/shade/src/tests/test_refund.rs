@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use crate::shade::{Shade, ShadeClient};
-use crate::types::InvoiceStatus;
+use crate::types::{InvoiceStatus, Role};
 use account::account::{MerchantAccount, MerchantAccountClient};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
 use soroban_sdk::{token, Address, Env, String};
@@ -45,7 +45,7 @@ fn setup_paid_invoice(pay_timestamp: u64) -> RefundTestContext<'static> {
 
     // Register merchant + deploy merchant account contract
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let merchant_account_id = env.register(MerchantAccount, ());
     let merchant_account = MerchantAccountClient::new(&env, &merchant_account_id);
@@ -154,7 +154,7 @@ fn test_refund_one_second_past_boundary_fails() {
 // Expect NotAuthorized (#1).
 // ---------------------------------------------------------------------------
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #6)")]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
 fn test_refund_unauthorized_random_address() {
     let ctx = setup_paid_invoice(1_000);
 
@@ -177,7 +177,7 @@ fn test_refund_unauthorized_different_merchant() {
     ctx.env.ledger().set_timestamp(1_000 + 3_600);
 
     let other_merchant = Address::generate(&ctx.env);
-    ctx.client.register_merchant(&other_merchant);
+    ctx.client.register_merchant(&other_merchant, &None, &None);
 
     ctx.client.refund_invoice(&other_merchant, &ctx.invoice_id);
 }
@@ -208,7 +208,7 @@ fn test_refund_restricted_merchant_account() {
 // Expect InvalidInvoiceStatus (#16).
 // ---------------------------------------------------------------------------
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #29)")]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
 fn test_refund_pending_invoice_fails() {
     let env = Env::default();
     env.mock_all_auths();
@@ -219,7 +219,7 @@ fn test_refund_pending_invoice_fails() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -238,7 +238,7 @@ fn test_refund_pending_invoice_fails() {
 // Expect InvalidInvoiceStatus (#16).
 // ---------------------------------------------------------------------------
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #29)")]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
 fn test_refund_cancelled_invoice_fails() {
     let env = Env::default();
     env.mock_all_auths();
@@ -249,7 +249,7 @@ fn test_refund_cancelled_invoice_fails() {
     client.initialize(&admin);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let token = env
         .register_stellar_asset_contract_v2(Address::generate(&env))
@@ -266,10 +266,10 @@ fn test_refund_cancelled_invoice_fails() {
 
 // ---------------------------------------------------------------------------
 // Test Case 6: Double Refund – attempt to refund an already-refunded invoice
-// The second call hits `amount_to_refund <= 0` → InvalidAmount (#7).
+// The invoice is no longer Paid → InvalidInvoiceStatus (#16).
 // ---------------------------------------------------------------------------
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #28)")]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
 fn test_double_refund_fails() {
     let ctx = setup_paid_invoice(1_000);
 
@@ -333,7 +333,7 @@ fn test_partial_refund_with_fee() {
     client.set_fee(&admin, &token, &500); // 5% fee
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let merchant_account_id = env.register(MerchantAccount, ());
     let merchant_account = MerchantAccountClient::new(&env, &merchant_account_id);
@@ -356,7 +356,7 @@ fn test_partial_refund_with_fee() {
     let merchant_portion = amount - fee; // 950
 
     assert_eq!(tok.balance(&merchant_account_id), merchant_portion);
-    assert_eq!(tok.balance(&shade_id), fee);
+    assert_eq!(tok.balance(&admin), fee);
 
     // Partial refund of exactly the merchant portion
     env.ledger().set_timestamp(1_000 + 3_600);
@@ -368,5 +368,66 @@ fn test_partial_refund_with_fee() {
 
     assert_eq!(tok.balance(&payer), merchant_portion);
     assert_eq!(tok.balance(&merchant_account_id), 0);
-    assert_eq!(tok.balance(&shade_id), fee); // fee stays with shade
+    assert_eq!(tok.balance(&admin), fee); // fee stays with the admin
+}
+
+#[test]
+fn test_refund_by_manager_records_issuer() {
+    let ctx = setup_paid_invoice(1_000);
+    let manager = Address::generate(&ctx.env);
+    ctx.client.grant_role(&ctx.admin, &manager, &Role::Manager);
+
+    ctx.client.refund_invoice(&manager, &ctx.invoice_id);
+
+    let invoice = ctx.client.get_invoice(&ctx.invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Refunded);
+    assert_eq!(invoice.refunded_by, Some(manager));
+    assert_eq!(
+        token::TokenClient::new(&ctx.env, &ctx.token).balance(&ctx.payer),
+        ctx.amount
+    );
+}
+
+#[test]
+fn test_refund_by_admin_and_merchant_record_issuer() {
+    let ctx = setup_paid_invoice(1_000);
+    ctx.client.refund_invoice(&ctx.admin, &ctx.invoice_id);
+    assert_eq!(
+        ctx.client.get_invoice(&ctx.invoice_id).refunded_by,
+        Some(ctx.admin.clone())
+    );
+
+    let ctx = setup_paid_invoice(1_000);
+    ctx.client.refund_invoice(&ctx.merchant, &ctx.invoice_id);
+    assert_eq!(
+        ctx.client.get_invoice(&ctx.invoice_id).refunded_by,
+        Some(ctx.merchant.clone())
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_refund_by_admin_of_pending_invoice_fails() {
+    let ctx = setup_paid_invoice(1_000);
+    let pending_id = ctx.client.create_invoice(
+        &ctx.merchant,
+        &String::from_str(&ctx.env, "Unpaid"),
+        &ctx.amount,
+        &ctx.token,
+        &None,
+    );
+
+    ctx.client.refund_invoice(&ctx.admin, &pending_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_refund_by_operator_without_permission_fails() {
+    let ctx = setup_paid_invoice(1_000);
+    let operator = Address::generate(&ctx.env);
+    ctx.client.register_merchant(&operator, &None, &None);
+    ctx.client
+        .grant_role(&ctx.admin, &operator, &Role::Operator);
+
+    ctx.client.refund_invoice(&operator, &ctx.invoice_id);
 }
@@ -0,0 +1,230 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env, String};
+
+#[contracttype]
+enum LossyKey {
+    Balance(Address),
+}
+
+/// Reports a successful transfer but only delivers `amount - 1`.
+#[contract]
+pub struct LossyToken;
+
+#[contractimpl]
+impl LossyToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .persistent()
+            .set(&LossyKey::Balance(to), &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&LossyKey::Balance(id))
+            .unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let from_balance = Self::balance(env.clone(), from.clone());
+        env.storage()
+            .persistent()
+            .set(&LossyKey::Balance(from), &(from_balance - amount));
+        Self::mint(env, to, amount - 1);
+    }
+
+    pub fn symbol(env: Env) -> String {
+        String::from_str(&env, "LOSS")
+    }
+}
+
+#[contracttype]
+enum FeeTokenKey {
+    Balance(Address),
+}
+
+/// Keeps 1% of every transfer, like a fee-on-transfer token.
+#[contract]
+pub struct FeeOnTransferToken;
+
+#[contractimpl]
+impl FeeOnTransferToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .persistent()
+            .set(&FeeTokenKey::Balance(to), &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&FeeTokenKey::Balance(id))
+            .unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let from_balance = Self::balance(env.clone(), from.clone());
+        env.storage()
+            .persistent()
+            .set(&FeeTokenKey::Balance(from), &(from_balance - amount));
+        Self::mint(env, to, amount - amount / 100);
+    }
+
+    pub fn symbol(env: Env) -> String {
+        String::from_str(&env, "FOT")
+    }
+}
+
+fn setup_test(lossy: bool) -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = if lossy {
+        env.register(LossyToken, ())
+    } else {
+        env.register_stellar_asset_contract_v2(Address::generate(&env))
+            .address()
+    };
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    (env, client, merchant, token)
+}
+
+fn create_invoice(env: &Env, client: &ShadeClient, merchant: &Address, token: &Address) -> u64 {
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Checked transfer"),
+        &1000,
+        token,
+        &None,
+    )
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #49)")]
+fn test_under_delivering_token_fails_settlement() {
+    let (env, client, merchant, token) = setup_test(true);
+    let invoice_id = create_invoice(&env, &client, &merchant, &token);
+
+    let payer = Address::generate(&env);
+    LossyTokenClient::new(&env, &token).mint(&payer, &1000);
+
+    client.pay_invoice(&payer, &invoice_id);
+}
+
+#[test]
+fn test_compliant_token_settles() {
+    let (env, client, merchant, token) = setup_test(false);
+    let invoice_id = create_invoice(&env, &client, &merchant, &token);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    assert_eq!(client.get_invoice(&invoice_id).status, InvoiceStatus::Paid);
+}
+
+fn setup_fee_on_transfer() -> (
+    Env,
+    ShadeClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env.register(FeeOnTransferToken, ());
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    let merchant_account = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &merchant_account);
+
+    (env, client, admin, merchant, merchant_account, token)
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #49)")]
+fn test_undeclared_fee_on_transfer_token_is_rejected() {
+    let (env, client, _admin, merchant, _account, token) = setup_fee_on_transfer();
+    let invoice_id = create_invoice(&env, &client, &merchant, &token);
+
+    let payer = Address::generate(&env);
+    FeeOnTransferTokenClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+}
+
+#[test]
+fn test_declared_transfer_fee_settles_direct_payment() {
+    let (env, client, admin, merchant, merchant_account, token) = setup_fee_on_transfer();
+    client.set_token_transfer_fee_bps(&admin, &token, &100);
+    assert_eq!(client.get_token_transfer_fee_bps(&token), 100);
+
+    let invoice_id = create_invoice(&env, &client, &merchant, &token);
+    let payer = Address::generate(&env);
+    let token_client = FeeOnTransferTokenClient::new(&env, &token);
+    token_client.mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.amount_paid, 1000);
+    assert_eq!(token_client.balance(&merchant_account), 990);
+}
+
+#[test]
+fn test_escrow_holds_net_received_amount() {
+    let (env, client, admin, merchant, merchant_account, token) = setup_fee_on_transfer();
+    client.set_token_transfer_fee_bps(&admin, &token, &100);
+
+    let invoice_id = create_invoice(&env, &client, &merchant, &token);
+    client.set_requires_confirmation(&merchant, &invoice_id, &true);
+    let payer = Address::generate(&env);
+    let token_client = FeeOnTransferTokenClient::new(&env, &token);
+    token_client.mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Funded);
+    assert_eq!(invoice.escrowed_amount, 990);
+    assert_eq!(token_client.balance(&client.address), 990);
+
+    client.confirm_receipt(&payer, &invoice_id);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.amount_paid, 1000);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(token_client.balance(&merchant_account), 981);
+    assert_eq!(client.get_outstanding_liability(&token), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #58)")]
+fn test_transfer_fee_above_10000_bps_rejected() {
+    let (_env, client, admin, _merchant, _account, token) = setup_fee_on_transfer();
+    client.set_token_transfer_fee_bps(&admin, &token, &10_001);
+}
@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{token, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, u64) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    // Payable from 2_000 until 3_000.
+    let invoice_id = client.create_scheduled_invoice(
+        &merchant,
+        &String::from_str(&env, "Event ticket"),
+        &1000,
+        &token,
+        &Some(2_000),
+        &Some(3_000),
+    );
+
+    (env, client, merchant, token, invoice_id)
+}
+
+fn fund_payer(env: &Env, token: &Address) -> Address {
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&payer, &1000);
+    payer
+}
+
+#[test]
+fn test_scheduled_invoice_stores_active_from() {
+    let (_env, client, _merchant, _token, invoice_id) = setup_test();
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.active_from, Some(2_000));
+    assert_eq!(invoice.expires_at, Some(3_000));
+    assert!(!client.is_payable_now(&invoice_id));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #51)")]
+fn test_payment_before_active_from_fails() {
+    let (env, client, _merchant, token, invoice_id) = setup_test();
+    env.ledger().set_timestamp(1_999);
+    client.pay_invoice(&fund_payer(&env, &token), &invoice_id);
+}
+
+#[test]
+fn test_payment_within_window_succeeds() {
+    let (env, client, _merchant, token, invoice_id) = setup_test();
+    env.ledger().set_timestamp(2_000);
+    assert!(client.is_payable_now(&invoice_id));
+
+    client.pay_invoice(&fund_payer(&env, &token), &invoice_id);
+    assert_eq!(client.get_invoice(&invoice_id).status, InvoiceStatus::Paid);
+    assert!(!client.is_payable_now(&invoice_id));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #27)")]
+fn test_payment_after_due_date_fails() {
+    let (env, client, _merchant, token, invoice_id) = setup_test();
+    env.ledger().set_timestamp(3_000);
+    assert!(!client.is_payable_now(&invoice_id));
+    client.pay_invoice(&fund_payer(&env, &token), &invoice_id);
+}
+
+#[test]
+fn test_unscheduled_invoice_is_payable_immediately() {
+    let (env, client, merchant, token, _invoice_id) = setup_test();
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Now"),
+        &1000,
+        &token,
+        &None,
+    );
+    assert_eq!(client.get_invoice(&invoice_id).active_from, None);
+    assert!(client.is_payable_now(&invoice_id));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #47)")]
+fn test_empty_payable_window_is_rejected() {
+    let (env, client, merchant, token, _invoice_id) = setup_test();
+    client.create_scheduled_invoice(
+        &merchant,
+        &String::from_str(&env, "Backwards"),
+        &1000,
+        &token,
+        &Some(3_000),
+        &Some(3_000),
+    );
+}
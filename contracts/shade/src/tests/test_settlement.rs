@@ -0,0 +1,111 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{DataKey, InvoiceStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address, u64) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Settlement"),
+        &1000,
+        &token,
+        &None,
+    );
+
+    (env, client, contract_id, admin, token, invoice_id)
+}
+
+fn force_settling(env: &Env, client: &ShadeClient, contract_id: &Address, invoice_id: u64) {
+    let mut invoice = client.get_invoice(&invoice_id);
+    invoice.status = InvoiceStatus::Settling;
+    env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Invoice(invoice_id), &invoice);
+    });
+}
+
+#[test]
+fn test_failed_transfer_leaves_invoice_payable() {
+    let (env, client, _contract_id, _admin, token, invoice_id) = setup_test();
+    let payer = Address::generate(&env);
+
+    // The payer holds nothing, so the token transfer fails.
+    let res = client.try_pay_invoice(&payer, &invoice_id);
+    assert_eq!(
+        res,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            ContractError::SettlementFailed as u32
+        )))
+    );
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Pending);
+    assert_eq!(invoice.amount_paid, 0);
+
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+    assert_eq!(client.get_invoice(&invoice_id).status, InvoiceStatus::Paid);
+}
+
+#[test]
+fn test_reset_settling_returns_invoice_to_pending() {
+    let (env, client, contract_id, admin, token, invoice_id) = setup_test();
+    force_settling(&env, &client, &contract_id, invoice_id);
+
+    client.reset_settling(&admin, &invoice_id);
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Pending
+    );
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+    assert_eq!(client.get_invoice(&invoice_id).status, InvoiceStatus::Paid);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_settling_invoice_cannot_be_paid() {
+    let (env, client, contract_id, _admin, token, invoice_id) = setup_test();
+    force_settling(&env, &client, &contract_id, invoice_id);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_reset_settling_on_pending_invoice_fails() {
+    let (_env, client, _contract_id, admin, _token, invoice_id) = setup_test();
+    client.reset_settling(&admin, &invoice_id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_reset_settling_non_admin_fails() {
+    let (env, client, contract_id, _admin, _token, invoice_id) = setup_test();
+    force_settling(&env, &client, &contract_id, invoice_id);
+
+    client.reset_settling(&Address::generate(&env), &invoice_id);
+}
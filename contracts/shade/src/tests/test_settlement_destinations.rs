@@ -0,0 +1,212 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::MerchantFilter;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, vec, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    (env, client, contract_id, admin, token)
+}
+
+/// Registers a merchant with its own account and returns `(merchant, merchant_id, account)`.
+fn register(env: &Env, client: &ShadeClient) -> (Address, u64, Address) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant, &None, &None);
+    let account = Address::generate(env);
+    client.set_merchant_account(&merchant, &account);
+    let merchants = client.get_merchants(&MerchantFilter {
+        is_active: None,
+        is_verified: None,
+    });
+    (merchant, merchants.len() as u64, account)
+}
+
+fn create(env: &Env, client: &ShadeClient, merchant: &Address, token: &Address) -> u64 {
+    client.create_invoice(
+        merchant,
+        &String::from_str(env, "Preview"),
+        &1000,
+        token,
+        &None,
+    )
+}
+
+#[test]
+fn test_destinations_without_fee() {
+    let (env, client, _contract_id, _admin, token) = setup_test();
+    let (merchant, _, account) = register(&env, &client);
+    let invoice_id = create(&env, &client, &merchant, &token);
+
+    assert_eq!(
+        client.get_settlement_destinations(&invoice_id),
+        vec![&env, (account, 1000)]
+    );
+}
+
+#[test]
+fn test_destinations_match_actual_settlement() {
+    let (env, client, _contract_id, admin, token) = setup_test();
+    // 5% fee
+    client.set_fee(&admin, &token, &500);
+    let (merchant, _, account) = register(&env, &client);
+    let invoice_id = create(&env, &client, &merchant, &token);
+
+    let destinations = client.get_settlement_destinations(&invoice_id);
+    assert_eq!(
+        destinations,
+        vec![&env, (account.clone(), 950), (admin.clone(), 50)]
+    );
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+    let token_client = token::TokenClient::new(&env, &token);
+    for (destination, amount) in destinations.iter() {
+        assert_eq!(token_client.balance(&destination), amount);
+    }
+}
+
+#[test]
+fn test_destinations_use_fee_recipient() {
+    let (env, client, _contract_id, admin, token) = setup_test();
+    let recipient = Address::generate(&env);
+    client.update_fee_config(&admin, &token, &10, &500, &recipient);
+    let (merchant, _, account) = register(&env, &client);
+    let invoice_id = create(&env, &client, &merchant, &token);
+
+    assert_eq!(
+        client.get_settlement_destinations(&invoice_id),
+        vec![&env, (account, 940), (recipient, 60)]
+    );
+}
+
+#[test]
+fn test_destinations_for_exempt_designated_payer() {
+    let (env, client, _contract_id, admin, token) = setup_test();
+    client.set_fee(&admin, &token, &500);
+    let (merchant, _, account) = register(&env, &client);
+    let invoice_id = create(&env, &client, &merchant, &token);
+    let payer = Address::generate(&env);
+    client.assign_payer(&merchant, &invoice_id, &payer);
+    client.add_fee_exempt_payer(&admin, &payer);
+
+    assert_eq!(
+        client.get_settlement_destinations(&invoice_id),
+        vec![&env, (account, 1000)]
+    );
+}
+
+#[test]
+fn test_destinations_cover_outstanding_balance() {
+    let (env, client, _contract_id, admin, token) = setup_test();
+    client.set_fee(&admin, &token, &500);
+    let (merchant, _, account) = register(&env, &client);
+    let invoice_id = create(&env, &client, &merchant, &token);
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice_partial(&payer, &invoice_id, &600);
+
+    assert_eq!(
+        client.get_settlement_destinations(&invoice_id),
+        vec![&env, (account, 380), (admin, 20)]
+    );
+}
+
+#[test]
+fn test_destinations_for_split_invoice() {
+    let (env, client, _contract_id, admin, token) = setup_test();
+    client.set_fee(&admin, &token, &500);
+    let (creator, _, _) = register(&env, &client);
+    let (_, first_id, first_account) = register(&env, &client);
+    let (_, second_id, second_account) = register(&env, &client);
+    let invoice_id = client.create_split_invoice(
+        &creator,
+        &vec![&env, (first_id, 600), (second_id, 400)],
+        &token,
+        &String::from_str(&env, "Split"),
+    );
+
+    assert_eq!(
+        client.get_settlement_destinations(&invoice_id),
+        vec![
+            &env,
+            (first_account, 570),
+            (second_account, 380),
+            (admin, 50)
+        ]
+    );
+}
+
+#[test]
+fn test_destinations_include_holdback() {
+    let (env, client, contract_id, admin, token) = setup_test();
+    client.set_fee(&admin, &token, &500);
+    let (merchant, merchant_id, account) = register(&env, &client);
+    // hold back 20% of the merchant's net
+    client.set_merchant_holdback_bps(&admin, &merchant_id, &2_000);
+    let invoice_id = create(&env, &client, &merchant, &token);
+
+    let destinations = client.get_settlement_destinations(&invoice_id);
+    assert_eq!(
+        destinations,
+        vec![
+            &env,
+            (account.clone(), 760),
+            (contract_id.clone(), 190),
+            (admin.clone(), 50)
+        ]
+    );
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+    let token_client = token::TokenClient::new(&env, &token);
+    for (destination, amount) in destinations.iter() {
+        assert_eq!(token_client.balance(&destination), amount);
+    }
+}
+
+#[test]
+fn test_destinations_for_two_phase_invoice_are_escrow() {
+    let (env, client, contract_id, admin, token) = setup_test();
+    client.set_fee(&admin, &token, &500);
+    let (merchant, _, account) = register(&env, &client);
+    let invoice_id = create(&env, &client, &merchant, &token);
+    client.set_requires_confirmation(&merchant, &invoice_id, &true);
+
+    let destinations = client.get_settlement_destinations(&invoice_id);
+    assert_eq!(destinations, vec![&env, (contract_id.clone(), 1000)]);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+    let token_client = token::TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&contract_id), 1000);
+    assert_eq!(token_client.balance(&account), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_destinations_for_paid_invoice_fails() {
+    let (env, client, _contract_id, _admin, token) = setup_test();
+    let (merchant, _, _) = register(&env, &client);
+    let invoice_id = create(&env, &client, &merchant, &token);
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    client.get_settlement_destinations(&invoice_id);
+}
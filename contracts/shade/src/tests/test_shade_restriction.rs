@@ -1,5 +1,6 @@
 #![cfg(test)]
 
+use crate::components::access_control::FN_RESTRICT_MERCHANT_ACCOUNT;
 use crate::errors::ContractError;
 use crate::shade::{Shade, ShadeClient};
 use crate::types::Role;
@@ -23,7 +24,7 @@ fn setup() -> (Env, ShadeClient<'static>, Address, Address, Address) {
     client.grant_role(&admin, &manager, &Role::Manager);
 
     // Register Merchant
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     // Deploy MerchantAccount mock (using real contract)
     let acct_id = env.register(account::account::MerchantAccount, ());
@@ -161,3 +162,66 @@ fn test_invalid_merchant_restriction() {
         )))
     );
 }
+
+#[test]
+fn test_revoked_manager_permission_blocks_restriction() {
+    let (_env, client, admin, manager, merchant) = setup();
+
+    assert!(client.get_role_permission(&Role::Manager, &FN_RESTRICT_MERCHANT_ACCOUNT));
+    client.set_role_permission(
+        &admin,
+        &Role::Manager,
+        &FN_RESTRICT_MERCHANT_ACCOUNT,
+        &false,
+    );
+    assert!(!client.get_role_permission(&Role::Manager, &FN_RESTRICT_MERCHANT_ACCOUNT));
+
+    let res = client.try_restrict_merchant_account(&manager, &merchant, &true);
+    assert_eq!(
+        res,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            ContractError::NotAuthorized as u32
+        )))
+    );
+
+    // The admin is never subject to the permission map.
+    client.restrict_merchant_account(&admin, &merchant, &true);
+}
+
+#[test]
+fn test_granted_operator_permission_allows_restriction() {
+    let (env, client, admin, _manager, merchant) = setup();
+
+    let operator = Address::generate(&env);
+    client.grant_role(&admin, &operator, &Role::Operator);
+
+    let res = client.try_restrict_merchant_account(&operator, &merchant, &true);
+    assert!(res.is_err());
+
+    client.set_role_permission(
+        &admin,
+        &Role::Operator,
+        &FN_RESTRICT_MERCHANT_ACCOUNT,
+        &true,
+    );
+    client.restrict_merchant_account(&operator, &merchant, &true);
+    assert_latest_account_restricted_event(&env, &client.address, &merchant, true, &operator);
+}
+
+#[test]
+fn test_set_role_permission_unauthorized() {
+    let (_env, client, _admin, manager, _merchant) = setup();
+
+    let res = client.try_set_role_permission(
+        &manager,
+        &Role::Manager,
+        &FN_RESTRICT_MERCHANT_ACCOUNT,
+        &false,
+    );
+    assert_eq!(
+        res,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            ContractError::NotAuthorized as u32
+        )))
+    );
+}
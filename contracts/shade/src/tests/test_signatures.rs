@@ -69,6 +69,7 @@ fn build_test_message(
     result
 }
 
+#[allow(clippy::too_many_arguments)]
 fn sign_invoice(
     env: &Env,
     contract_id: &Address,
@@ -115,7 +116,7 @@ fn test_valid_signature() {
     client.grant_role(&admin, &manager, &Role::Manager);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let keypair = generate_keypair();
     let pub_key = BytesN::from_array(&env, &keypair.public_key_bytes);
@@ -168,7 +169,7 @@ fn test_invalid_signature_tampered_amount() {
     client.grant_role(&admin, &manager, &Role::Manager);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let keypair = generate_keypair();
     let pub_key = BytesN::from_array(&env, &keypair.public_key_bytes);
@@ -217,7 +218,7 @@ fn test_invalid_signature_tampered_description() {
     client.grant_role(&admin, &manager, &Role::Manager);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let keypair = generate_keypair();
     let pub_key = BytesN::from_array(&env, &keypair.public_key_bytes);
@@ -266,7 +267,7 @@ fn test_replay_attack_same_nonce() {
     client.grant_role(&admin, &manager, &Role::Manager);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
 
     let keypair = generate_keypair();
     let pub_key = BytesN::from_array(&env, &keypair.public_key_bytes);
@@ -325,8 +326,8 @@ fn test_wrong_merchant_signature() {
 
     let merchant_a = Address::generate(&env);
     let merchant_b = Address::generate(&env);
-    client.register_merchant(&merchant_a);
-    client.register_merchant(&merchant_b);
+    client.register_merchant(&merchant_a, &None, &None);
+    client.register_merchant(&merchant_b, &None, &None);
 
     let keypair_a = generate_keypair();
     let keypair_b = generate_keypair();
@@ -382,7 +383,7 @@ fn test_no_public_key() {
     client.grant_role(&admin, &manager, &Role::Manager);
 
     let merchant = Address::generate(&env);
-    client.register_merchant(&merchant);
+    client.register_merchant(&merchant, &None, &None);
     // Deliberately do NOT set a merchant key
 
     let token = env
@@ -419,8 +420,8 @@ fn test_nonce_independence_per_merchant() {
 
     let merchant_a = Address::generate(&env);
     let merchant_b = Address::generate(&env);
-    client.register_merchant(&merchant_a);
-    client.register_merchant(&merchant_b);
+    client.register_merchant(&merchant_a, &None, &None);
+    client.register_merchant(&merchant_b, &None, &None);
 
     let keypair_a = generate_keypair();
     let keypair_b = generate_keypair();
@@ -0,0 +1,167 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceStatus, MerchantFilter};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, vec, Address, Env, String};
+
+struct SplitSetup {
+    env: Env,
+    client: ShadeClient<'static>,
+    admin: Address,
+    token: Address,
+    creator: Address,
+}
+
+fn setup_test() -> SplitSetup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ShadeClient::new(&env, &env.register(Shade, ()));
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    // 5% fee
+    client.set_fee(&admin, &token, &500);
+
+    let creator = Address::generate(&env);
+    client.register_merchant(&creator, &None, &None);
+
+    SplitSetup {
+        env,
+        client,
+        admin,
+        token,
+        creator,
+    }
+}
+
+/// Registers a vendor with its own merchant account and returns `(merchant_id, account)`.
+fn register_vendor(env: &Env, client: &ShadeClient) -> (u64, Address) {
+    let vendor = Address::generate(env);
+    client.register_merchant(&vendor, &None, &None);
+    let account = Address::generate(env);
+    client.set_merchant_account(&vendor, &account);
+    let merchants = client.get_merchants(&MerchantFilter {
+        is_active: None,
+        is_verified: None,
+    });
+    (merchants.len() as u64, account)
+}
+
+#[test]
+fn test_create_split_invoice_records_parts() {
+    let s = setup_test();
+    let (vendor_a, _) = register_vendor(&s.env, &s.client);
+    let (vendor_b, _) = register_vendor(&s.env, &s.client);
+
+    let parts = vec![&s.env, (vendor_a, 600i128), (vendor_b, 400i128)];
+    let invoice_id = s.client.create_split_invoice(
+        &s.creator,
+        &parts,
+        &s.token,
+        &String::from_str(&s.env, "Marketplace order"),
+    );
+
+    let invoice = s.client.get_invoice(&invoice_id);
+    assert_eq!(invoice.amount, 1000);
+    assert_eq!(invoice.parts, parts);
+    assert_eq!(invoice.merchant_id, 1);
+    assert_eq!(invoice.status, InvoiceStatus::Pending);
+}
+
+#[test]
+fn test_split_invoice_payment_distributes_to_each_merchant() {
+    let s = setup_test();
+    let (vendor_a, account_a) = register_vendor(&s.env, &s.client);
+    let (vendor_b, account_b) = register_vendor(&s.env, &s.client);
+
+    let invoice_id = s.client.create_split_invoice(
+        &s.creator,
+        &vec![&s.env, (vendor_a, 600i128), (vendor_b, 400i128)],
+        &s.token,
+        &String::from_str(&s.env, "Marketplace order"),
+    );
+
+    let customer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&customer, &1000);
+    s.client.pay_invoice(&customer, &invoice_id);
+
+    let token_client = token::TokenClient::new(&s.env, &s.token);
+    // Each vendor receives its share minus the 5% fee on that share.
+    assert_eq!(token_client.balance(&account_a), 570);
+    assert_eq!(token_client.balance(&account_b), 380);
+    assert_eq!(token_client.balance(&s.admin), 50);
+    assert_eq!(token_client.balance(&customer), 0);
+
+    let invoice = s.client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.payer, Some(customer));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #32)")]
+fn test_split_invoice_with_inactive_merchant_rejected() {
+    let s = setup_test();
+    let (vendor_a, _) = register_vendor(&s.env, &s.client);
+    let (vendor_b, _) = register_vendor(&s.env, &s.client);
+    s.client.set_merchant_status(&s.admin, &vendor_b, &false);
+
+    s.client.create_split_invoice(
+        &s.creator,
+        &vec![&s.env, (vendor_a, 600i128), (vendor_b, 400i128)],
+        &s.token,
+        &String::from_str(&s.env, "Marketplace order"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_split_invoice_with_non_positive_part_rejected() {
+    let s = setup_test();
+    let (vendor_a, _) = register_vendor(&s.env, &s.client);
+    let (vendor_b, _) = register_vendor(&s.env, &s.client);
+
+    s.client.create_split_invoice(
+        &s.creator,
+        &vec![&s.env, (vendor_a, 600i128), (vendor_b, 0i128)],
+        &s.token,
+        &String::from_str(&s.env, "Marketplace order"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_split_invoice_with_unknown_merchant_rejected() {
+    let s = setup_test();
+    let (vendor_a, _) = register_vendor(&s.env, &s.client);
+
+    s.client.create_split_invoice(
+        &s.creator,
+        &vec![&s.env, (vendor_a, 600i128), (99u64, 400i128)],
+        &s.token,
+        &String::from_str(&s.env, "Marketplace order"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_split_invoice_rejects_partial_payment() {
+    let s = setup_test();
+    let (vendor_a, _) = register_vendor(&s.env, &s.client);
+    let (vendor_b, _) = register_vendor(&s.env, &s.client);
+
+    let invoice_id = s.client.create_split_invoice(
+        &s.creator,
+        &vec![&s.env, (vendor_a, 600i128), (vendor_b, 400i128)],
+        &s.token,
+        &String::from_str(&s.env, "Marketplace order"),
+    );
+
+    let customer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&customer, &1000);
+    s.client.pay_invoice_partial(&customer, &invoice_id, &500);
+}
@@ -0,0 +1,69 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+
+    (env, client, merchant)
+}
+
+#[test]
+fn test_new_merchant_has_no_tip_suggestions() {
+    let (env, client, _merchant) = setup_test();
+    assert_eq!(client.get_merchant(&1).tip_suggestions, Vec::new(&env));
+}
+
+#[test]
+fn test_set_tip_suggestions_surfaces_on_merchant() {
+    let (env, client, merchant) = setup_test();
+    let suggestions = vec![&env, 1_000u32, 1_500, 2_000];
+
+    client.set_tip_suggestions(&merchant, &suggestions);
+
+    assert_eq!(client.get_merchant(&1).tip_suggestions, suggestions);
+}
+
+#[test]
+fn test_tip_suggestion_at_full_amount_accepted() {
+    let (env, client, merchant) = setup_test();
+    client.set_tip_suggestions(&merchant, &vec![&env, 10_000u32]);
+    assert_eq!(
+        client.get_merchant(&1).tip_suggestions,
+        vec![&env, 10_000u32]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_tip_suggestion_over_10000_bps_rejected() {
+    let (env, client, merchant) = setup_test();
+    client.set_tip_suggestions(&merchant, &vec![&env, 1_000u32, 10_001]);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_too_many_tip_suggestions_rejected() {
+    let (env, client, merchant) = setup_test();
+    client.set_tip_suggestions(
+        &merchant,
+        &vec![&env, 500u32, 1_000, 1_500, 2_000, 2_500, 3_000],
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_tip_suggestions_unregistered_merchant_fails() {
+    let (env, client, _merchant) = setup_test();
+    client.set_tip_suggestions(&Address::generate(&env), &vec![&env, 1_000u32]);
+}
@@ -0,0 +1,182 @@
+#![cfg(test)]
+
+use crate::components::health::MAX_TOP_MERCHANTS;
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, vec, Address, Env, String};
+
+struct Setup {
+    env: Env,
+    client: ShadeClient<'static>,
+    admin: Address,
+    token: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+    }
+}
+
+fn add_merchant(s: &Setup) -> Address {
+    let merchant = Address::generate(&s.env);
+    s.client.register_merchant(&merchant, &None, &None);
+    s.client
+        .set_merchant_account(&merchant, &Address::generate(&s.env));
+    merchant
+}
+
+fn settle(s: &Setup, merchant: &Address, token: &Address, amount: i128) {
+    let invoice_id = s.client.create_invoice(
+        merchant,
+        &String::from_str(&s.env, "Leaderboard"),
+        &amount,
+        token,
+        &None,
+    );
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, token).mint(&payer, &amount);
+    s.client.pay_invoice(&payer, &invoice_id);
+}
+
+#[test]
+fn test_top_merchants_sorted_by_total_earned() {
+    let s = setup_test();
+    let first = add_merchant(&s);
+    let second = add_merchant(&s);
+    let third = add_merchant(&s);
+
+    settle(&s, &first, &s.token, 300);
+    settle(&s, &second, &s.token, 500);
+    settle(&s, &third, &s.token, 100);
+
+    assert_eq!(
+        s.client.get_top_merchants(&s.token, &10),
+        vec![&s.env, (2, 500), (1, 300), (3, 100)]
+    );
+    assert_eq!(
+        s.client.get_top_merchants(&s.token, &2),
+        vec![&s.env, (2, 500), (1, 300)]
+    );
+}
+
+#[test]
+fn test_settlements_reorder_leaderboard() {
+    let s = setup_test();
+    let first = add_merchant(&s);
+    let second = add_merchant(&s);
+
+    settle(&s, &first, &s.token, 400);
+    settle(&s, &second, &s.token, 300);
+    assert_eq!(
+        s.client.get_top_merchants(&s.token, &10),
+        vec![&s.env, (1, 400), (2, 300)]
+    );
+
+    settle(&s, &second, &s.token, 200);
+    assert_eq!(
+        s.client.get_top_merchants(&s.token, &10),
+        vec![&s.env, (2, 500), (1, 400)]
+    );
+}
+
+#[test]
+fn test_leaderboard_net_of_fees() {
+    let s = setup_test();
+    s.client.set_fee(&s.admin, &s.token, &500);
+    let merchant = add_merchant(&s);
+
+    settle(&s, &merchant, &s.token, 1_000);
+
+    assert_eq!(
+        s.client.get_top_merchants(&s.token, &1),
+        vec![&s.env, (1, 950)]
+    );
+}
+
+#[test]
+fn test_leaderboard_is_per_token() {
+    let s = setup_test();
+    let other_token = s
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&s.env))
+        .address();
+    s.client.add_accepted_token(&s.admin, &other_token);
+    let first = add_merchant(&s);
+    let second = add_merchant(&s);
+
+    settle(&s, &first, &s.token, 100);
+    settle(&s, &second, &other_token, 900);
+
+    assert_eq!(
+        s.client.get_top_merchants(&s.token, &10),
+        vec![&s.env, (1, 100)]
+    );
+    assert_eq!(
+        s.client.get_top_merchants(&other_token, &10),
+        vec![&s.env, (2, 900)]
+    );
+}
+
+#[test]
+fn test_leaderboard_keeps_only_top_entries() {
+    let s = setup_test();
+    for index in 0..MAX_TOP_MERCHANTS {
+        let merchant = add_merchant(&s);
+        settle(&s, &merchant, &s.token, 100 + index as i128);
+    }
+    let board = s.client.get_top_merchants(&s.token, &MAX_TOP_MERCHANTS);
+    assert_eq!(board.len(), MAX_TOP_MERCHANTS);
+    assert_eq!(board.last().unwrap(), (1, 100));
+
+    // a newcomer below the lowest entry stays off the board
+    let small = add_merchant(&s);
+    settle(&s, &small, &s.token, 50);
+    let board = s.client.get_top_merchants(&s.token, &MAX_TOP_MERCHANTS);
+    assert_eq!(board.last().unwrap(), (1, 100));
+
+    // once its total beats the lowest entry it replaces it
+    settle(&s, &small, &s.token, 60);
+    let board = s.client.get_top_merchants(&s.token, &MAX_TOP_MERCHANTS);
+    assert_eq!(board.len(), MAX_TOP_MERCHANTS);
+    assert_eq!(board.last().unwrap(), (2, 101));
+    assert_eq!(board.get(MAX_TOP_MERCHANTS - 2).unwrap(), (3, 102));
+    assert!(board.contains((MAX_TOP_MERCHANTS as u64 + 1, 110)));
+    assert!(!board.contains((1, 100)));
+}
+
+#[test]
+fn test_empty_leaderboard() {
+    let s = setup_test();
+    assert!(s.client.get_top_merchants(&s.token, &5).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_top_merchants_zero_limit_fails() {
+    let s = setup_test();
+    s.client.get_top_merchants(&s.token, &0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_top_merchants_limit_above_cap_fails() {
+    let s = setup_test();
+    s.client
+        .get_top_merchants(&s.token, &(MAX_TOP_MERCHANTS + 1));
+}
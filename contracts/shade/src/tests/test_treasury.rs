@@ -0,0 +1,169 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, Env, String};
+
+struct Setup {
+    env: Env,
+    client: ShadeClient<'static>,
+    contract_id: Address,
+    admin: Address,
+    token: Address,
+    merchant: Address,
+    merchant_account: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    client.set_fee(&admin, &token, &500);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account = Address::generate(&env);
+    client.set_merchant_account(&merchant, &merchant_account);
+
+    Setup {
+        env,
+        client,
+        contract_id,
+        admin,
+        token,
+        merchant,
+        merchant_account,
+    }
+}
+
+fn pay(s: &Setup, amount: i128) {
+    let invoice_id = s.client.create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Treasury"),
+        &amount,
+        &s.token,
+        &None,
+    );
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token).mint(&payer, &amount);
+    s.client.pay_invoice(&payer, &invoice_id);
+}
+
+fn balance(s: &Setup, holder: &Address) -> i128 {
+    token::TokenClient::new(&s.env, &s.token).balance(holder)
+}
+
+#[test]
+fn test_treasury_defaults_to_admin() {
+    let s = setup_test();
+    assert_eq!(s.client.get_treasury(), s.admin);
+}
+
+#[test]
+fn test_set_treasury_updates_treasury() {
+    let s = setup_test();
+    let treasury = Address::generate(&s.env);
+    s.client.set_treasury(&s.admin, &treasury);
+    assert_eq!(s.client.get_treasury(), treasury);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_treasury_requires_admin() {
+    let s = setup_test();
+    s.client
+        .set_treasury(&Address::generate(&s.env), &Address::generate(&s.env));
+}
+
+#[test]
+fn test_payment_fee_goes_to_treasury() {
+    let s = setup_test();
+    let treasury = Address::generate(&s.env);
+    s.client.set_treasury(&s.admin, &treasury);
+
+    pay(&s, 1_000);
+
+    assert_eq!(balance(&s, &s.merchant_account), 950);
+    assert_eq!(balance(&s, &treasury), 50);
+    assert_eq!(balance(&s, &s.contract_id), 0);
+    assert_eq!(s.client.get_pool_totals(&s.token), (0, 0));
+}
+
+#[test]
+fn test_fee_recipient_takes_precedence_over_treasury() {
+    let s = setup_test();
+    let treasury = Address::generate(&s.env);
+    let recipient = Address::generate(&s.env);
+    s.client.set_treasury(&s.admin, &treasury);
+    s.client
+        .update_fee_config(&s.admin, &s.token, &0, &500, &recipient);
+
+    pay(&s, 1_000);
+
+    assert_eq!(balance(&s, &recipient), 50);
+    assert_eq!(balance(&s, &treasury), 0);
+}
+
+#[test]
+fn test_fees_go_to_admin_without_treasury() {
+    let s = setup_test();
+
+    pay(&s, 1_000);
+
+    assert_eq!(balance(&s, &s.merchant_account), 950);
+    assert_eq!(balance(&s, &s.admin), 50);
+    assert_eq!(balance(&s, &s.contract_id), 0);
+}
+
+#[test]
+fn test_contract_as_treasury_accrues_fee_pool() {
+    let s = setup_test();
+    s.client.set_treasury(&s.admin, &s.contract_id);
+
+    pay(&s, 1_000);
+
+    assert_eq!(balance(&s, &s.contract_id), 50);
+    assert_eq!(s.client.get_pool_totals(&s.token), (0, 50));
+}
+
+#[test]
+fn test_fee_accrual_defaults_off() {
+    let s = setup_test();
+    assert!(!s.client.is_fee_accrual_enabled());
+}
+
+#[test]
+fn test_fee_accrual_keeps_fees_for_bulk_withdrawal() {
+    let s = setup_test();
+    let treasury = Address::generate(&s.env);
+    s.client.set_treasury(&s.admin, &treasury);
+    s.client.set_fee_accrual(&s.admin, &true);
+
+    pay(&s, 1_000);
+    pay(&s, 2_000);
+
+    assert_eq!(balance(&s, &treasury), 0);
+    assert_eq!(balance(&s, &s.contract_id), 150);
+    assert_eq!(s.client.get_pool_totals(&s.token), (0, 150));
+
+    let withdrawn = s.client.withdraw_fees(&s.admin, &s.token, &treasury);
+
+    assert_eq!(withdrawn, 150);
+    assert_eq!(balance(&s, &treasury), 150);
+    assert_eq!(s.client.get_pool_totals(&s.token), (0, 0));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_fee_accrual_requires_admin() {
+    let s = setup_test();
+    s.client.set_fee_accrual(&Address::generate(&s.env), &true);
+}
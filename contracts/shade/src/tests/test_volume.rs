@@ -0,0 +1,94 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use account::account::{MerchantAccount, MerchantAccountClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, Env, String};
+
+struct Setup {
+    env: Env,
+    client: ShadeClient<'static>,
+    admin: Address,
+    token: Address,
+    merchant: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = create_test_token(&env);
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    let merchant_account_id = env.register(MerchantAccount, ());
+    MerchantAccountClient::new(&env, &merchant_account_id).initialize(
+        &merchant,
+        &contract_id,
+        &1_u64,
+    );
+    client.set_merchant_account(&merchant, &merchant_account_id);
+
+    Setup {
+        env,
+        client,
+        admin,
+        token,
+        merchant,
+    }
+}
+
+fn create_test_token(env: &Env) -> Address {
+    env.register_stellar_asset_contract_v2(Address::generate(env))
+        .address()
+}
+
+fn pay(s: &Setup, token: &Address, amount: i128) -> u64 {
+    let invoice_id = s.client.create_invoice(
+        &s.merchant,
+        &String::from_str(&s.env, "Volume"),
+        &amount,
+        token,
+        &None,
+    );
+    let payer = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, token).mint(&payer, &amount);
+    s.client.pay_invoice(&payer, &invoice_id);
+    invoice_id
+}
+
+#[test]
+fn test_volume_starts_at_zero() {
+    let s = setup_test();
+    assert_eq!(s.client.get_volume(&s.token), 0);
+}
+
+#[test]
+fn test_volume_accumulates_payments_per_token() {
+    let s = setup_test();
+    let other_token = create_test_token(&s.env);
+    s.client.add_accepted_token(&s.admin, &other_token);
+
+    pay(&s, &s.token, 1_000);
+    pay(&s, &s.token, 2_500);
+    pay(&s, &s.token, 500);
+
+    assert_eq!(s.client.get_volume(&s.token), 4_000);
+    assert_eq!(s.client.get_volume(&other_token), 0);
+}
+
+#[test]
+fn test_refund_decrements_volume() {
+    let s = setup_test();
+    pay(&s, &s.token, 1_000);
+    let refunded_id = pay(&s, &s.token, 2_000);
+
+    s.client.refund_invoice_partial(&refunded_id, &500);
+
+    assert_eq!(s.client.get_volume(&s.token), 2_500);
+}
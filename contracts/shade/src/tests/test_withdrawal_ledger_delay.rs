@@ -0,0 +1,167 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::shade::{Shade, ShadeClient};
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{token, Address, Env, Error, String};
+
+const FUNDED_AT: u64 = 1_000;
+const FUNDED_LEDGER: u32 = 100;
+const LEDGER_DELAY: u32 = 50;
+
+struct Ctx {
+    env: Env,
+    client: ShadeClient<'static>,
+    admin: Address,
+    merchant: Address,
+    invoice_id: u64,
+}
+
+fn setup_funded(ledger_delay: u32) -> Ctx {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(FUNDED_AT);
+    env.ledger().set_sequence_number(FUNDED_LEDGER);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_withdrawal_ledger_delay(&admin, &ledger_delay);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant, &None, &None);
+    client.set_merchant_account(&merchant, &Address::generate(&env));
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Held goods"),
+        &1000,
+        &token,
+        &None,
+    );
+    client.set_requires_confirmation(&merchant, &invoice_id, &true);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    Ctx {
+        env,
+        client,
+        admin,
+        merchant,
+        invoice_id,
+    }
+}
+
+fn advance_to(ctx: &Ctx, timestamp: u64, sequence: u32) {
+    ctx.env.ledger().set_timestamp(timestamp);
+    ctx.env.ledger().set_sequence_number(sequence);
+}
+
+fn assert_claim_too_early(ctx: &Ctx) {
+    assert_eq!(
+        ctx.client
+            .try_claim_after_timeout(&ctx.merchant, &ctx.invoice_id)
+            .unwrap_err(),
+        Ok(Error::from_contract_error(
+            ContractError::ReleaseTooEarly as u32
+        ))
+    );
+}
+
+#[test]
+fn test_funding_records_withdrawable_ledger() {
+    let ctx = setup_funded(LEDGER_DELAY);
+    assert_eq!(ctx.client.get_withdrawal_ledger_delay(), LEDGER_DELAY);
+    assert_eq!(
+        ctx.client.get_withdrawable_after_ledger(&ctx.invoice_id),
+        Some(FUNDED_LEDGER + LEDGER_DELAY)
+    );
+}
+
+#[test]
+fn test_claim_locked_until_ledger_boundary() {
+    let ctx = setup_funded(LEDGER_DELAY);
+    let timeout = ctx.client.get_receipt_timeout();
+
+    advance_to(&ctx, FUNDED_AT + timeout, FUNDED_LEDGER + LEDGER_DELAY - 1);
+    assert_claim_too_early(&ctx);
+
+    advance_to(&ctx, FUNDED_AT + timeout, FUNDED_LEDGER + LEDGER_DELAY);
+    ctx.client
+        .claim_after_timeout(&ctx.merchant, &ctx.invoice_id);
+
+    assert_eq!(
+        ctx.client.get_invoice(&ctx.invoice_id).status,
+        InvoiceStatus::Paid
+    );
+    assert_eq!(
+        ctx.client.get_withdrawable_after_ledger(&ctx.invoice_id),
+        None
+    );
+}
+
+#[test]
+fn test_claim_waits_for_later_of_time_and_ledger_delay() {
+    let ctx = setup_funded(LEDGER_DELAY);
+    let timeout = ctx.client.get_receipt_timeout();
+
+    // ledger hold over, receipt timeout not yet
+    advance_to(
+        &ctx,
+        FUNDED_AT + timeout - 1,
+        FUNDED_LEDGER + 10 * LEDGER_DELAY,
+    );
+    assert_claim_too_early(&ctx);
+
+    advance_to(&ctx, FUNDED_AT + timeout, FUNDED_LEDGER + 10 * LEDGER_DELAY);
+    ctx.client
+        .claim_after_timeout(&ctx.merchant, &ctx.invoice_id);
+    assert_eq!(
+        ctx.client.get_invoice(&ctx.invoice_id).status,
+        InvoiceStatus::Paid
+    );
+}
+
+#[test]
+fn test_delay_change_does_not_affect_funded_escrow() {
+    let ctx = setup_funded(LEDGER_DELAY);
+    ctx.client.set_withdrawal_ledger_delay(&ctx.admin, &0);
+
+    let timeout = ctx.client.get_receipt_timeout();
+    advance_to(&ctx, FUNDED_AT + timeout, FUNDED_LEDGER + 1);
+    assert_claim_too_early(&ctx);
+}
+
+#[test]
+fn test_no_ledger_hold_by_default() {
+    let ctx = setup_funded(0);
+    assert_eq!(
+        ctx.client.get_withdrawable_after_ledger(&ctx.invoice_id),
+        None
+    );
+
+    let timeout = ctx.client.get_receipt_timeout();
+    advance_to(&ctx, FUNDED_AT + timeout, FUNDED_LEDGER);
+    ctx.client
+        .claim_after_timeout(&ctx.merchant, &ctx.invoice_id);
+    assert_eq!(
+        ctx.client.get_invoice(&ctx.invoice_id).status,
+        InvoiceStatus::Paid
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_set_withdrawal_ledger_delay_requires_admin() {
+    let ctx = setup_funded(0);
+    ctx.client
+        .set_withdrawal_ledger_delay(&Address::generate(&ctx.env), &10);
+}
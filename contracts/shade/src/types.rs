@@ -1,9 +1,16 @@
-use soroban_sdk::{contracttype, Address, BytesN};
+// `DustPolicy`'s `To*` variants; the lint also fires on the copy of the enum
+// that `contracttype` generates, which an item-level allow can't reach.
+#![allow(clippy::enum_variant_names)]
+
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Map, Vec};
 
 #[contracttype]
 pub enum DataKey {
     Admin,
     PendingAdmin,
+    BackupAdmin,
+    AdminInactivityPeriod,
+    LastAdminActivity,
     Paused,
     FeeInBasisPoints(Address),
     FeeAmount(Address),
@@ -13,6 +20,8 @@ pub enum DataKey {
     MerchantKey(Address),
     MerchantCount,
     MerchantId(Address),
+    OpenRegistration,
+    MerchantApplication(Address),
     TokenFee(Address),
     MerchantTokens(Address),
     MerchantBalance(Address),
@@ -22,6 +31,7 @@ pub enum DataKey {
     ReentrancyStatus,
     AccountWasmHash,
     Role(Address, Role),
+    RolePermission(Role, u32),
     UsedNonce(Address, BytesN<32>),
     // --- Subscription engine ---
     SubscriptionPlan(u64),
@@ -30,6 +40,68 @@ pub enum DataKey {
     SubscriptionCount,
     // --- Time-locked fee updates ---
     PendingTokenFee(Address),
+    FeeCeiling(Address),
+    FlatFee(Address),
+    FeeRecipient(Address),
+    FeeExemptPayer(Address),
+    KycRegistry,
+    ConversionFeeBps,
+    FeePool(Address),
+    // --- Designated payers ---
+    PayerInvoices(Address),
+    // --- Health counters ---
+    OpenInvoiceCount,
+    ActiveMerchantCount,
+    // --- Circuit breaker ---
+    CircuitBreaker(Address),
+    MaxDescriptionLen,
+    MerchantCooldown,
+    LastInvoiceAt(u64),
+    CallbackRevertOnFailure,
+    MaxPartials,
+    PartialCount(u64),
+    // --- Keeper maintenance ---
+    MaintenanceCursor,
+}
+
+/// Storage keys added after `DataKey` reached the contract spec's limit on
+/// enum variants.
+#[contracttype]
+pub enum ConfigKey {
+    AutoArchiveDelay,
+    PayerKey(Address),
+    FeeTiers(Address),
+    OutstandingLiability(Address),
+    ReceiptTimeout,
+    MerchantOutcomes(u64),
+    MerchantInvoiceSequence(u64),
+    TransferFeeBps(Address),
+    LogBlockedPayments,
+    BlockedPayments,
+    MerchantInvoices(u64),
+    LargePaymentThreshold(Address),
+    TokenInvoices(Address),
+    DustPolicy,
+    AuditSequence,
+    AuditEntry(u64),
+    Treasury,
+    MaxLateFeeBps,
+    PausedScope(PauseScope),
+    WithdrawalLedgerDelay,
+    WithdrawableAfterLedger(u64),
+    MerchantEarned(u64, Address),
+    TopMerchants(Address),
+    EditWindow,
+    Volume(Address),
+    HoldbackBps(u64),
+    Holdback(u64, Address),
+    EventsEnabled,
+    HeldTotal(Address),
+    FeeAccrual,
+    EscrowedInvoices(Address),
+    HoldbackMerchants(Address),
+    ConversionRate(Address, Address),
+    QuoteValidity,
 }
 
 #[contracttype]
@@ -47,6 +119,31 @@ pub struct Merchant {
     pub active: bool,
     pub verified: bool,
     pub date_registered: u64,
+    /// Suggested tip percentages in basis points, for POS UIs; not enforced.
+    pub tip_suggestions: Vec<u32>,
+    /// Shown before the per-merchant sequence in invoice display numbers.
+    pub invoice_prefix: Option<soroban_sdk::String>,
+    /// Display name; empty when none was given.
+    pub name: soroban_sdk::String,
+    /// Free-form contact details; empty when none were given.
+    pub contact: soroban_sdk::String,
+}
+
+/// A merchant's standing, combining its active flag with whether its
+/// settlement account is restricted.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum MerchantState {
+    /// The address is not a registered merchant.
+    Unknown = 0,
+    Active = 1,
+    /// Deactivated by an admin; its account can still pay out.
+    Inactive = 2,
+    /// Active, but its settlement account is restricted.
+    Paused = 3,
+    /// Deactivated and its settlement account restricted.
+    Closed = 4,
 }
 
 #[contracttype]
@@ -64,6 +161,55 @@ pub struct Invoice {
     pub amount_paid: i128,
     pub amount_refunded: i128,
     pub expires_at: Option<u64>,
+    pub reminder_count: u32,
+    pub last_reminder_at: u64,
+    /// `(merchant_id, amount)` shares of a split invoice; empty for single-merchant invoices.
+    pub parts: Vec<(u64, i128)>,
+    /// Only this address may pay the invoice when set.
+    pub designated_payer: Option<Address>,
+    /// Total protocol fee collected across all payments.
+    pub fee_paid: i128,
+    /// Hash of the settled payment terms, set once the invoice is fully paid.
+    pub receipt_hash: Option<BytesN<32>>,
+    /// Merchant-submitted hash proving off-chain goods were delivered.
+    pub delivery_proof: Option<BytesN<32>>,
+    /// Merchant-quoted amount per alternative token, honored until `quote_valid_until`.
+    pub quoted_amounts: Map<Address, i128>,
+    pub quote_valid_until: u64,
+    /// Payments are rejected before this timestamp when set.
+    pub active_from: Option<u64>,
+    /// Set by maintenance once a settled invoice ages out; hidden from `get_invoices`.
+    pub archived: bool,
+    /// Customer-facing payment instructions, e.g. "Include your order number".
+    pub payer_note: Option<soroban_sdk::String>,
+    /// Ledger sequence at creation, a skew-free ordering reference next to `date_created`.
+    pub ledger_created: u32,
+    /// Ledger sequence at which the invoice became fully paid.
+    pub ledger_paid: Option<u32>,
+    /// Contract notified through `on_payment_settled` once the invoice is fully paid.
+    pub payer_callback: Option<Address>,
+    /// Client-side encrypted line items, stored opaquely; the key is shared off-chain.
+    pub encrypted_data: Option<Bytes>,
+    /// Payments are held in escrow until the payer confirms receipt.
+    pub requires_confirmation: bool,
+    /// When a two-phase invoice was funded into escrow.
+    pub funded_at: Option<u64>,
+    /// What the contract actually received into escrow, net of any token transfer fee.
+    pub escrowed_amount: i128,
+    /// When the merchant wrote the invoice off as uncollectable.
+    pub written_off_at: Option<u64>,
+    /// Who issued the full refund: the merchant or an admin/manager.
+    pub refunded_by: Option<Address>,
+    /// Whether expiry just closes the invoice or also opens a fresh copy.
+    pub on_expiry: ExpiryAction,
+    /// The expired invoice this one was reissued from.
+    pub reissued_from: Option<u64>,
+    /// Late fees accrue for each full day past this timestamp.
+    pub due_date: Option<u64>,
+    /// Late fee per full day overdue, in bps of the amount.
+    pub late_fee_bps_per_day: Option<u32>,
+    /// Position among the merchant's invoices, starting at 1.
+    pub merchant_sequence: u64,
 }
 
 #[contracttype]
@@ -77,6 +223,45 @@ pub enum InvoiceStatus {
     PartiallyRefunded = 4,
     PartiallyPaid = 5,
     Draft = 6,
+    Settling = 7,
+    Expired = 8,
+    Funded = 9,
+    Disputed = 10,
+    WrittenOff = 11,
+}
+
+/// Part of the contract that can be paused on its own. `All` is the global
+/// pause.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum PauseScope {
+    Payments = 0,
+    Invoicing = 1,
+    All = 2,
+}
+
+/// Who keeps the stroop left over when a fee rate does not divide a payment
+/// evenly.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum DustPolicy {
+    /// Fees round down; the merchant receives the remainder.
+    ToMerchant = 0,
+    /// Fees round up; the fee destination receives the remainder.
+    ToFeePool = 1,
+    /// Fees round down and the merchant's share is rounded down too; the
+    /// payer is not charged the remainder.
+    ToPayer = 2,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ExpiryAction {
+    Close = 0,
+    Reissue = 1,
 }
 
 #[contracttype]
@@ -103,6 +288,14 @@ pub enum Role {
     Admin,
     Manager,
     Operator,
+    Onboarder,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CallerPermissions {
+    pub is_admin: bool,
+    pub roles: Vec<Role>,
 }
 
 // ── Time-locked fee update ────────────────────────────────────────────────────
@@ -115,6 +308,21 @@ pub struct PendingFee {
     pub proposed_at: u64,
 }
 
+// ── Circuit breaker ───────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitBreaker {
+    /// Length of the volume window in seconds.
+    pub window_secs: u64,
+    /// Settled volume within one window above which the contract pauses.
+    pub threshold: i128,
+    /// Ledger timestamp at which the current window opened.
+    pub window_start: u64,
+    /// Volume settled so far in the current window.
+    pub window_volume: i128,
+}
+
 // ── Subscription engine ───────────────────────────────────────────────────────
 
 #[contracttype]
@@ -159,3 +367,109 @@ pub enum SubscriptionStatus {
     Active = 0,
     Cancelled = 1,
 }
+
+/// A payment attempted while the contract was paused.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlockedPayment {
+    pub payer: Address,
+    pub invoice_id: u64,
+    pub timestamp: u64,
+}
+
+/// State change kinds recorded in the audit log.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AuditAction {
+    MerchantRegistered = 0,
+    MerchantStatusChanged = 1,
+    InvoiceCreated = 2,
+    InvoiceAmended = 3,
+    InvoicePaid = 4,
+    InvoiceRefunded = 5,
+    InvoicePartiallyRefunded = 6,
+    InvoiceCancelled = 7,
+    InvoiceExpired = 8,
+    InvoiceWrittenOff = 9,
+    Subscribed = 10,
+    SubscriptionCharged = 11,
+    SubscriptionCancelled = 12,
+}
+
+/// One entry of the audit log. `primary_id` is the invoice, merchant or
+/// subscription the action applies to; `secondary_id` is the merchant for
+/// payments and the plan for subscriptions, zero otherwise.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExportedEvent {
+    pub seq: u64,
+    pub action: AuditAction,
+    pub primary_id: u64,
+    pub secondary_id: u64,
+    pub amount: i128,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+/// Terminal-state invoice counts for one merchant.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MerchantOutcomes {
+    pub paid: u64,
+    pub cancelled: u64,
+    pub expired: u64,
+    pub refunded: u64,
+    pub written_off: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Health {
+    pub total_invoices: u64,
+    pub open_invoices: u64,
+    pub total_merchants: u64,
+    pub active_merchants: u64,
+    pub paused: bool,
+    pub accepted_token_count: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceReport {
+    pub expired: u32,
+    pub archived: u32,
+    pub scanned: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentQuote {
+    pub principal: i128,
+    pub base_fee: i128,
+    pub conversion_fee: i128,
+    /// Amount debited from the payer; fees come out of the merchant's share.
+    pub total: i128,
+    pub pay_token: Address,
+}
+
+/// Invoice terms agreed off-chain and signed by both merchant and payer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvoiceTerms {
+    pub description: soroban_sdk::String,
+    pub amount: i128,
+    pub token: Address,
+    pub expires_at: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentReceipt {
+    pub invoice_id: u64,
+    pub amount: i128,
+    pub fee: i128,
+    /// Invoice status after this payment (`Paid` or `PartiallyPaid`).
+    pub status: InvoiceStatus,
+    pub receipt_hash: Option<BytesN<32>>,
+}